@@ -0,0 +1,36 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+// The CLI definition is shared with the game, only the parts build.rs
+// needs to write the files are used here.
+#[allow(dead_code)]
+mod cli {
+    include!("src/cli.rs");
+}
+
+// Writes the shell completions and the man page into OUT_DIR/assets, the
+// only place a build script may write to, every time the command line
+// changes. Cargo prints OUT_DIR with `cargo build -vv`, and packagers copy
+// the files from there.
+fn main() {
+    println!("cargo:rerun-if-changed=src/cli.rs");
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("Cargo sets OUT_DIR"));
+    let assets = out_dir.join("assets");
+    fs::create_dir_all(&assets).expect("Failed to create the assets directory");
+
+    let mut command = cli::Cli::command();
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell, Shell::Elvish] {
+        clap_complete::generate_to(shell, &mut command, "guessing_game", &assets)
+            .expect("Failed to write the completions");
+    }
+
+    let mut page = Vec::new();
+    clap_mangen::Man::new(command)
+        .render(&mut page)
+        .expect("Failed to render the man page");
+    fs::write(assets.join("guessing_game.1"), page).expect("Failed to write the man page");
+}
@@ -0,0 +1,580 @@
+# العربية
+# direction: rtl
+
+## اللعبة الكلاسيكية
+
+guess-the-number = خمّن الرقم!
+guess-between = خمّن رقمًا بين { $low } و{ $high }!
+enter-guess = أدخل تخمينك:
+you-guessed = خمّنت: { $guess }
+bad-guess = تعذرت قراءة التخمين: { $error }.
+parse-empty = لا شيء للقراءة
+parse-bad-number = { $text } ليس عددًا
+parse-unknown-word = { $word } ليست كلمة عدد
+parse-bad-words = "{ $text }" لا تكتب أي عدد
+parse-unexpected = لم يكن { $token } متوقعًا
+parse-missing-operand = ينتهي التعبير قبل أوانه
+parse-unclosed-paren = قوس لا يُغلق أبدًا
+parse-division-by-zero = قسمة على صفر
+parse-out-of-range = النتيجة ليست بين 0 و{ $max }
+parse-too-deep = تتداخل الأقواس أو الإشارات بعدد يتجاوز { $max }
+too-small = أصغر من اللازم!
+too-big = أكبر من اللازم!
+you-win = فزت!
+you-win-in = { $count ->
+    [zero] فزت دون أي محاولة!
+    [one] فزت من محاولة واحدة!
+    [two] فزت من محاولتين!
+    [few] فزت في { $count } محاولات!
+    [many] فزت في { $count } محاولة!
+   *[other] فزت في { $count } محاولة!
+}
+you-lose = خسرت! كان الرقم { $secret }.
+input-ended = نفدت التخمينات قبل نهاية اللعبة.
+batch-cannot-read = تعذرت قراءة { $path }: { $error }
+attempts-available = { $count ->
+    [zero] ليست لديك أي محاولة.
+    [one] لديك محاولة واحدة.
+    [two] لديك محاولتان.
+    [few] لديك { $count } محاولات.
+    [many] لديك { $count } محاولة.
+   *[other] لديك { $count } محاولة.
+}
+attempts-left = { $count ->
+    [zero] لم تبقَ أي محاولة
+    [one] بقيت محاولة واحدة
+    [two] بقيت محاولتان
+    [few] بقيت { $count } محاولات
+    [many] بقيت { $count } محاولة
+   *[other] بقيت { $count } محاولة
+}
+hint-available = اكتب hint (أو ?) لتحصل على تلميح مقابل بعض محاولاتك.
+hint-cost = { $count ->
+    [zero] هذا التلميح مجاني.
+    [one] كلّفك هذا التلميح محاولة واحدة.
+    [two] كلّفك هذا التلميح محاولتين.
+    [few] كلّفك هذا التلميح { $count } محاولات.
+    [many] كلّفك هذا التلميح { $count } محاولة.
+   *[other] كلّفك هذا التلميح { $count } محاولة.
+}
+time-played = مدة اللعب: { $seconds } ث
+adaptive-odds = التصنيف { $rating }: { $count } محاولات، فرصة الفوز نحو { $chance }٪.
+rating-change = التصنيف: { $rating } ({ $change })
+achievement-unlocked = إنجاز جديد: { $name }!
+
+## الألعاب المحفوظة
+
+no-saved-game = لا توجد لعبة محفوظة لاستئنافها.
+resuming = استئناف { $name }: خمّن رقمًا بين { $low } و{ $high }!
+no-save-called = لا يوجد حفظ باسم { $name }
+save-damaged = الحفظ { $name } تالف: { $error }
+game-saved = حُفظت اللعبة، تابعها بالأمر: guessing_game resume { $name }
+
+## التحدي اليومي
+
+daily-already-played = لقد لعبت لغز { $date } بالفعل، عد غدًا!
+daily-intro = تحدي { $date }: خمّن رقمًا بين 1 و{ $high }!
+daily-share = تحدي اليوم { $date } { $range } { $attempts }/{ $max } { $arrows }
+
+## التحليل
+
+analysis-title = كيف جرت تخميناتك (بت واحد لكل تخمين هو الأفضل):
+analysis-guess = { $guess } في { $low }..={ $high }  { $bits } بت
+analysis-wasted = ضائع، خارج المجال
+analysis-information = المعلومات: { $bits } من { $needed } بت، { $per_guess } بت لكل تخمين.
+analysis-wasted-count = { $count ->
+    [zero] لا تخمينات ضائعة.
+    [one] تخمين ضائع واحد.
+    [two] تخمينان ضائعان.
+    [few] { $count } تخمينات ضائعة.
+    [many] { $count } تخمينًا ضائعًا.
+   *[other] { $count } تخمين ضائع.
+}
+analysis-efficiency = الكفاءة: { $percent }٪ من البحث الثنائي، الذي يحتاج { $count } تخمينات على الأكثر.
+
+## الشبكة
+
+grid-intro = جد الخانة المخفية في الشبكة { $width }x{ $height }!
+grid-enter-guess = أدخل تخمينك بالشكل x y:
+grid-bad-point = أدخل عددين صحيحين، x من 1 إلى { $width } و y من 1 إلى { $height }.
+grid-bad-size = يكون عرض الشبكة وارتفاعها من 1 إلى { $max } خانة، لا { $width }x{ $height }
+grid-cells-left = { $count ->
+    [zero] لم تبقَ أي خانة
+    [one] بقيت خانة واحدة
+    [two] بقيت خانتان
+    [few] بقيت { $count } خانات
+    [many] بقيت { $count } خانة
+   *[other] بقيت { $count } خانة
+}
+go-north = اتجه شمالًا!
+go-south = اتجه جنوبًا!
+go-east = اتجه شرقًا!
+go-west = اتجه غربًا!
+go-north-east = اتجه نحو الشمال الشرقي!
+go-north-west = اتجه نحو الشمال الغربي!
+go-south-east = اتجه نحو الجنوب الشرقي!
+go-south-west = اتجه نحو الجنوب الغربي!
+temperature-burning = ملتهب! (على بعد { $distance })
+temperature-hot = ساخن! (على بعد { $distance })
+temperature-warm = دافئ. (على بعد { $distance })
+temperature-cold = بارد. (على بعد { $distance })
+temperature-freezing = متجمد! (على بعد { $distance })
+grid-report = شبكة { $width }x{ $height }: { $worst } تخمينات في أسوأ حال، { $average } في المتوسط
+grid-bisection = { $count ->
+    [zero] تنصيف كل محور يتطلب صفر تخمينات
+    [one] تنصيف كل محور يتطلب تخمينًا واحدًا
+    [two] تنصيف كل محور يتطلب تخمينين
+    [few] تنصيف كل محور يتطلب { $count } تخمينات
+    [many] تنصيف كل محور يتطلب { $count } تخمينًا
+   *[other] تنصيف كل محور يتطلب { $count } تخمين
+}
+grid-bisection-met = الحلّال لا يحتاج أكثر من ذلك
+grid-bisection-missed = الحلّال يحتاج { $count } أكثر
+
+## العقل المدبر
+
+mastermind-too-few-symbols = رمز طوله { $count } دون تكرار يحتاج عددًا من الرموز قدره { $count } على الأقل
+mastermind-bad-length = يكون طول الرمز من 1 إلى { $max }، لا { $length }
+mastermind-bad-symbols = يستعمل الرمز من { $min } إلى { $max } من الرموز، لا { $symbols }
+mastermind-intro = اكسر الرمز! { $count } رموز من { $alphabet }
+mastermind-unique = لا يتكرر أي رمز.
+mastermind-bad-code = { $count ->
+    [zero] أدخل رموزًا من { $alphabet }.
+    [one] أدخل رمزًا واحدًا من { $alphabet }.
+    [two] أدخل رمزين من { $alphabet }.
+    [few] أدخل { $count } رموز من { $alphabet }.
+    [many] أدخل { $count } رمزًا من { $alphabet }.
+   *[other] أدخل { $count } رمز من { $alphabet }.
+}
+mastermind-you-lose = خسرت! كان الرمز { $code }.
+mastermind-score = { $exact } في مكانه، { $partial } في غير مكانه
+mastermind-hint = الموضع { $position } هو { $symbol }.
+mastermind-secret = الرمز السري: { $code }
+mastermind-candidates = ({ $count } احتمالات)
+
+## وردل
+
+wordle-intro = خمّن الكلمة المكونة من { $count } أحرف!
+wordle-hard-mode = الوضع الصعب: يجب استخدام التلميحات المكشوفة في التخمينات التالية.
+wordle-not-a-word = الكلمة ليست في القائمة.
+wordle-letter-must-be = الحرف رقم { $position } يجب أن يكون { $letter }.
+wordle-must-contain = يجب أن يحتوي التخمين على { $letter }.
+wordle-hint = الحرف رقم { $position } هو { $letter }.
+wordle-no-hint = كل الحروف في أماكنها، فلا شيء بقي للتلميح.
+wordle-you-lose = خسرت! كانت الكلمة { $word }.
+wordle-share = وردل { $attempts }/{ $max }{ $star }
+
+## سباق مع الزمن
+
+time-up = انتهى الوقت!
+too-slow = بطيء جدًا! خسرت هذا الدور.
+forfeited = ضائع
+countdown-intro = لديك { $seconds } ث لكل تخمين.
+total-intro = جده في أقل من { $seconds } ث!
+marathon-intro = ماراثون! جد أكبر عدد ممكن من الأرقام في { $seconds } ث.
+marathon-solved = { $count ->
+    [zero] لم تحل أي رقم.
+    [one] حللت رقمًا واحدًا.
+    [two] حللت رقمين.
+    [few] حللت { $count } أرقام.
+    [many] حللت { $count } رقمًا.
+   *[other] حللت { $count } رقم.
+}
+
+## اللعبة المعكوسة
+
+reverse-intro = فكّر في رقم بين { $low } و{ $high }، وسأخمّنه!
+reverse-answers = أجب بـ أكبر (h) أو أصغر (l) أو نعم (y).
+reverse-guess = هل هو { $guess }؟
+reverse-found = { $count ->
+    [zero] وجدته دون أي تخمين!
+    [one] وجدته من تخمين واحد!
+    [two] وجدته من تخمينين!
+    [few] وجدته في { $count } تخمينات!
+    [many] وجدته في { $count } تخمينًا!
+   *[other] وجدته في { $count } تخمين!
+}
+reverse-cheating = لا يوجد رقم بين { $low } و{ $high } يوافق إجاباتك، هل غيّرت رأيك؟
+
+## اللعب الجماعي
+
+coop-intro = { $count ->
+    [zero] لا أحد يبحث عن رقم بين { $low } و{ $high }.
+    [one] لاعب واحد يبحث عن رقم بين { $low } و{ $high }.
+    [two] لاعبان يتشاركان رقمًا بين { $low } و{ $high } ورصيدًا واحدًا من المحاولات.
+    [few] { $count } لاعبين يتشاركون رقمًا بين { $low } و{ $high } ورصيدًا واحدًا من المحاولات.
+    [many] { $count } لاعبًا يتشاركون رقمًا بين { $low } و{ $high } ورصيدًا واحدًا من المحاولات.
+   *[other] { $count } لاعب يتشاركون رقمًا بين { $low } و{ $high } ورصيدًا واحدًا من المحاولات.
+}
+coop-range = { $player } يخمّن بين { $low } و{ $high }.
+coop-turn = { $count ->
+    [zero] دورك يا { $player } ({ $low }-{ $high }، لم تبق أي محاولة). خمّن، أو مرّر، أو ابدأ بـ > لترك ملاحظة:
+    [one] دورك يا { $player } ({ $low }-{ $high }، بقيت محاولة واحدة). خمّن، أو مرّر، أو ابدأ بـ > لترك ملاحظة:
+    [two] دورك يا { $player } ({ $low }-{ $high }، بقيت محاولتان). خمّن، أو مرّر، أو ابدأ بـ > لترك ملاحظة:
+    [few] دورك يا { $player } ({ $low }-{ $high }، بقيت { $count } محاولات). خمّن، أو مرّر، أو ابدأ بـ > لترك ملاحظة:
+    [many] دورك يا { $player } ({ $low }-{ $high }، بقيت { $count } محاولة). خمّن، أو مرّر، أو ابدأ بـ > لترك ملاحظة:
+   *[other] دورك يا { $player } ({ $low }-{ $high }، بقيت { $count } محاولة). خمّن، أو مرّر، أو ابدأ بـ > لترك ملاحظة:
+}
+coop-note = { $player } يقول: { $note }
+coop-pass = { $player } يمرّر دوره.
+coop-outside = { $guess } ليس من نصيبك، اختر بين { $low } و{ $high } أو مرّر.
+coop-everyone-passed = مرّر الجميع أدوارهم. الرقم في نطاق أحدكم، فليخمّن أحد!
+coop-win = { $count ->
+    [zero] وجده { $player }، فاز الفريق دون أي محاولة!
+    [one] وجده { $player }، فاز الفريق من محاولة واحدة!
+    [two] وجده { $player }، فاز الفريق من محاولتين!
+    [few] وجده { $player }، فاز الفريق في { $count } محاولات!
+    [many] وجده { $player }، فاز الفريق في { $count } محاولة!
+   *[other] وجده { $player }، فاز الفريق في { $count } محاولة!
+}
+
+## الهدف المتحرك
+
+drift-intro = خمّن الرقم بين { $low } و{ $high }! يتحرك بمقدار { $step } على الأكثر بعد كل تخمين خاطئ.
+drift-report = { $count ->
+    [zero] { $low }..={ $high }، خطوات حتى { $step }، دون ألعاب، والاستسلام بعد { $max } تخمين
+    [one] { $low }..={ $high }، خطوات حتى { $step }، لعبة واحدة، والاستسلام بعد { $max } تخمين
+    [two] { $low }..={ $high }، خطوات حتى { $step }، لعبتان، والاستسلام بعد { $max } تخمين
+    [few] { $low }..={ $high }، خطوات حتى { $step }، { $count } ألعاب، والاستسلام بعد { $max } تخمين
+    [many] { $low }..={ $high }، خطوات حتى { $step }، { $count } لعبة، والاستسلام بعد { $max } تخمين
+   *[other] { $low }..={ $high }، خطوات حتى { $step }، { $count } لعبة، والاستسلام بعد { $max } تخمين
+}
+
+## عدة أرقام سرية
+
+multi-intro = { $count ->
+    [zero] لا يوجد أي رقم سري بين { $low } و{ $high }.
+    [one] رقم سري واحد مخبأ بين { $low } و{ $high }. جده!
+    [two] رقمان سريان مختلفان مخبآن بين { $low } و{ $high }. جدهما!
+    [few] { $count } أرقام سرية مختلفة مخبأة بين { $low } و{ $high }. جدها كلها!
+    [many] { $count } رقمًا سريًا مختلفًا مخبأة بين { $low } و{ $high }. جدها كلها!
+   *[other] { $count } رقم سري مختلف مخبأة بين { $low } و{ $high }. جدها كلها!
+}
+multi-answer = تحته: { $below }، عنده: { $equal }، فوقه: { $above }
+multi-found-all = { $count ->
+    [zero] وجدتها كلها دون أي تخمين!
+    [one] وجدتها كلها من تخمين واحد!
+    [two] وجدتها كلها من تخمينين!
+    [few] وجدتها كلها في { $count } تخمينات!
+    [many] وجدتها كلها في { $count } تخمينًا!
+   *[other] وجدتها كلها في { $count } تخمين!
+}
+multi-you-lose = خسرت! كانت الأرقام السرية { $secrets }.
+
+## عشرون سؤالًا
+
+twenty-intro = { $count ->
+    [zero] أفكر في رقم بين { $low } و{ $high }. ليس لديك أي سؤال.
+    [one] أفكر في رقم بين { $low } و{ $high }. لديك سؤال واحد بنعم أو لا لتجده.
+    [two] أفكر في رقم بين { $low } و{ $high }. لديك سؤالان بنعم أو لا لتجده.
+    [few] أفكر في رقم بين { $low } و{ $high }. لديك { $count } أسئلة بنعم أو لا لتجده.
+    [many] أفكر في رقم بين { $low } و{ $high }. لديك { $count } سؤالًا بنعم أو لا لتجده.
+   *[other] أفكر في رقم بين { $low } و{ $high }. لديك { $count } سؤال بنعم أو لا لتجده.
+}
+twenty-examples = تُكتب الأسئلة بالإنجليزية: is it in 20..40? is it even? is it prime? is it divisible by 7? is its digit sum > 10? وللفوز: is it 42?
+twenty-ask = { $count ->
+    [zero] لم يبق لك أي سؤال:
+    [one] سؤالك الأخير:
+    [two] سؤالك (بقي سؤالان):
+    [few] سؤالك (بقيت { $count } أسئلة):
+    [many] سؤالك (بقي { $count } سؤالًا):
+   *[other] سؤالك (بقي { $count } سؤال):
+}
+twenty-bad-question = لم أفهم هذا السؤال: { $error }.
+query-empty = لم يُطرح أي سؤال
+query-unknown-word = { $word } ليست جزءًا من سؤال
+query-unexpected = لم يكن { $token } متوقعًا
+query-missing-word = ينقص { $word } في النهاية
+query-missing-number = ينقص عدد في النهاية
+query-missing-comparison = تنقص مقارنة في النهاية
+query-missing-question = ينقص سؤال في النهاية
+query-missing-range-end = تنقص نهاية المجال
+query-bad-number = { $text } أكبر من اللازم
+query-empty-range = لا يحوي { $low }..{ $high } أي عدد
+query-division-by-zero = لا يقبل أي عدد القسمة على 0
+query-too-deep = يتداخل السؤال بعدد مستويات يتجاوز { $max }
+twenty-yes = نعم.
+twenty-no = لا.
+twenty-win = { $count ->
+    [zero] وجدته دون أي سؤال!
+    [one] وجدته من سؤال واحد!
+    [two] وجدته من سؤالين!
+    [few] وجدته في { $count } أسئلة!
+    [many] وجدته في { $count } سؤالًا!
+   *[other] وجدته في { $count } سؤال!
+}
+twenty-candidates = { $count ->
+    [zero] (لم يبق أي رقم)
+    [one] (بقي رقم واحد)
+    [two] (بقي رقمان)
+    [few] (بقيت { $count } أرقام)
+    [many] (بقي { $count } رقمًا)
+   *[other] (بقي { $count } رقم)
+}
+
+## التخمين بتكلفة
+
+cost-unit = كل تخمين يكلف 1.
+cost-value = كل تخمين يكلف قيمته، فالأعداد الصغيرة رخيصة.
+cost-over = كل تخمين يكلف 1، و{ $penalty } إضافية إذا كان كبيرًا جدًا.
+cost-paid = التكلفة { $cost }، والمجموع حتى الآن { $total }.
+cost-win = { $count ->
+    [zero] وجدته بدون أي تخمين بتكلفة إجمالية { $total }.
+    [one] وجدته بتخمين واحد بتكلفة إجمالية { $total }.
+    [two] وجدته بتخمينين بتكلفة إجمالية { $total }.
+    [few] وجدته بـ { $count } تخمينات بتكلفة إجمالية { $total }.
+    [many] وجدته بـ { $count } تخمينًا بتكلفة إجمالية { $total }.
+   *[other] وجدته بـ { $count } تخمين بتكلفة إجمالية { $total }.
+}
+cost-optimal = أفضل استراتيجية تدفع { $optimal } لهذا العدد، ولا تدفع أكثر من { $worst } لأي عدد.
+
+## الترتيب المخفي
+
+sort-intro = هناك { $count } عناصر، من A إلى { $last }، في ترتيب مخفي. اكتشفه بأقل عدد ممكن من المقارنات.
+sort-how = اكتب عنصرين لمقارنتهما، مثل "A C". عندما تعرف الترتيب، اكتبه من الأصغر إلى الأكبر، مثل "C < A < B".
+sort-ask = { $count ->
+    [zero] لا مقارنات حتى الآن:
+    [one] مقارنة واحدة حتى الآن:
+    [two] مقارنتان حتى الآن:
+    [few] { $count } مقارنات حتى الآن:
+    [many] { $count } مقارنة حتى الآن:
+   *[other] { $count } مقارنة حتى الآن:
+}
+sort-bad-move = اكتب عنصرين من A إلى { $last }، أو كل العناصر مرة واحدة بينها "<".
+sort-win = { $count ->
+    [zero] هذا هو الترتيب! دون أي مقارنة.
+    [one] هذا هو الترتيب! استخدمت مقارنة واحدة.
+    [two] هذا هو الترتيب! استخدمت مقارنتين.
+    [few] هذا هو الترتيب! استخدمت { $count } مقارنات.
+    [many] هذا هو الترتيب! استخدمت { $count } مقارنة.
+   *[other] هذا هو الترتيب! استخدمت { $count } مقارنة.
+}
+sort-wrong = خطأ! كان الترتيب { $order }.
+sort-bot-done = وجد { $bot } الترتيب { $order } بـ { $count } مقارنة.
+sort-bounds = ترتيب { $count } عناصر يحتاج على الأقل { $bound } مقارنة في أسوأ الحالات. استخدم الفرز بالدمج والإدراج { $best } مقارنة على هذا الترتيب.
+sort-report = { $count ->
+    [zero] { $items } عناصر، دون ألعاب، { $bound } مقارنة على الأقل في أسوأ الحالات (⌈log2 { $items }!⌉)
+    [one] { $items } عناصر، لعبة واحدة، { $bound } مقارنة على الأقل في أسوأ الحالات (⌈log2 { $items }!⌉)
+    [two] { $items } عناصر، لعبتان، { $bound } مقارنة على الأقل في أسوأ الحالات (⌈log2 { $items }!⌉)
+    [few] { $items } عناصر، { $count } ألعاب، { $bound } مقارنة على الأقل في أسوأ الحالات (⌈log2 { $items }!⌉)
+    [many] { $items } عناصر، { $count } لعبة، { $bound } مقارنة على الأقل في أسوأ الحالات (⌈log2 { $items }!⌉)
+   *[other] { $items } عناصر، { $count } لعبة، { $bound } مقارنة على الأقل في أسوأ الحالات (⌈log2 { $items }!⌉)
+}
+
+## أسرار منحازة
+
+skewed-prior = ليست كل الأعداد محتملة بالقدر نفسه. هكذا تتوزع الفرص:
+skewed-best = { $count ->
+    [zero] أفضل استراتيجية لهذا التوزيع تجد هذا العدد دون أي تخمين، و{ $expected } في المتوسط.
+    [one] أفضل استراتيجية لهذا التوزيع تجد هذا العدد بتخمين واحد، و{ $expected } في المتوسط.
+    [two] أفضل استراتيجية لهذا التوزيع تجد هذا العدد بتخمينين، و{ $expected } في المتوسط.
+    [few] أفضل استراتيجية لهذا التوزيع تجد هذا العدد بـ { $count } تخمينات، و{ $expected } في المتوسط.
+    [many] أفضل استراتيجية لهذا التوزيع تجد هذا العدد بـ { $count } تخمينًا، و{ $expected } في المتوسط.
+   *[other] أفضل استراتيجية لهذا التوزيع تجد هذا العدد بـ { $count } تخمين، و{ $expected } في المتوسط.
+}
+skewed-report = { $low }..={ $high }، { $bits } بت من الإنتروبيا
+
+## خمّن التوزيع
+
+infer-intro = تُسحب أسرار بين { $low } و{ $high } من أحد هذه التوزيعات: { $shapes }. اضغط Enter لترى سحبة، { $count } على الأكثر، وسمِّ التوزيع عندما تعرفه.
+infer-ask = { $count ->
+    [zero] لا سحبات حتى الآن. Enter لسحبة، أو اسم:
+    [one] سحبة واحدة حتى الآن. Enter لأخرى، أو اسم:
+    [two] سحبتان حتى الآن. Enter لأخرى، أو اسم:
+    [few] { $count } سحبات حتى الآن. Enter لأخرى، أو اسم:
+    [many] { $count } سحبة حتى الآن. Enter لأخرى، أو اسم:
+   *[other] { $count } سحبة حتى الآن. Enter لأخرى، أو اسم:
+}
+infer-unknown = هذا ليس أحد: { $shapes }.
+infer-right = { $count ->
+    [zero] صحيح، إنه { $shape }! دون أي سحبة.
+    [one] صحيح، إنه { $shape }! احتجت سحبة واحدة.
+    [two] صحيح، إنه { $shape }! احتجت سحبتين.
+    [few] صحيح، إنه { $shape }! احتجت { $count } سحبات.
+    [many] صحيح، إنه { $shape }! احتجت { $count } سحبة.
+   *[other] صحيح، إنه { $shape }! احتجت { $count } سحبة.
+}
+infer-wrong = خطأ، كان { $shape }.
+infer-out-of-rounds = لم تبقَ سحبات! كان { $shape }.
+infer-posterior = بحسب هذه السحبات: { $chances }
+
+## النرد
+
+rng-announce = المولّد { $rng }، البذرة { $seed }
+host-secret = أيها المضيف، اكتب العدد السري بين { $low } و{ $high }، وليُشِح اللاعبون بنظرهم:
+host-bad-secret = يجب أن يكون السر عددًا بين { $low } و{ $high }.
+host-no-secret = لم يُكتب أي سر.
+
+## النتائج
+
+scores-none = لم تُسجَّل أي نتيجة بعد.
+scores-unverifiable = جاء السر من { $rng }، لذا سُجّلت هذه اللعبة على أنها غير قابلة للتحقق ولن تظهر في صدارة اللوحة.
+scores-line-ok = السطر { $line }: سليم
+scores-line-unverifiable = السطر { $line }: غير قابل للتحقق، جاء السر من { $rng }
+scores-line-flagged = السطر { $line }: مُعلَّم، { $problem }
+scores-flagged = { $count ->
+    [zero] لم يُعلَّم أي إدخال.
+    [one] أُعلِم إدخال واحد.
+    [two] أُعلِم إدخالان.
+    [few] أُعلِمت { $count } إدخالات.
+    [many] أُعلِم { $count } إدخالًا.
+   *[other] أُعلِم { $count } إدخال.
+}
+scores-all-ok = جميع الإدخالات سليمة.
+scores-unreadable = تتعذر قراءته
+scores-bad-signature = توقيع غير صالح، عُدِّل الإدخال
+scores-after-win = تستمر التخمينات بعد الفوز في التخمين { $guess }
+scores-attempts-mismatch = { $count ->
+    [zero] يدّعي صفر محاولات، والسجل فيه { $actual }
+    [one] يدّعي محاولة واحدة، والسجل فيه { $actual }
+    [two] يدّعي محاولتين، والسجل فيه { $actual }
+    [few] يدّعي { $count } محاولات، والسجل فيه { $actual }
+    [many] يدّعي { $count } محاولة، والسجل فيه { $actual }
+   *[other] يدّعي { $count } محاولة، والسجل فيه { $actual }
+}
+scores-claims-win = يدّعي فوزًا، والسجل يقول غير ذلك
+scores-claims-loss = يدّعي خسارة، والسجل يقول غير ذلك
+scores-loss-with-attempts-left = يدّعي خسارة مع بقاء محاولات
+scores-too-many-attempts = يستخدم محاولات أكثر من المسموح
+
+## لوحة الصدارة
+
+leaderboard-cannot-open = تعذر فتح لوحة الصدارة: { $error }
+leaderboard-update-failed = تعذر تحديث لوحة الصدارة: { $error }
+leaderboard-error = خطأ في لوحة الصدارة: { $error }
+leaderboard-top = أفضل { $limit } في { $difficulty } ({ $window }):
+leaderboard-top-row = { $count ->
+    [zero] { $rank }. { $player } دون محاولات
+    [one] { $rank }. { $player } محاولة واحدة
+    [two] { $rank }. { $player } محاولتان
+    [few] { $rank }. { $player } { $count } محاولات
+    [many] { $rank }. { $player } { $count } محاولة
+   *[other] { $rank }. { $player } { $count } محاولة
+}
+leaderboard-history = ألعاب { $player }:
+leaderboard-history-won = { $count ->
+    [zero] { $day }  { $difficulty } فوز دون محاولات
+    [one] { $day }  { $difficulty } فوز في محاولة واحدة
+    [two] { $day }  { $difficulty } فوز في محاولتين
+    [few] { $day }  { $difficulty } فوز في { $count } محاولات
+    [many] { $day }  { $difficulty } فوز في { $count } محاولة
+   *[other] { $day }  { $difficulty } فوز في { $count } محاولة
+}
+leaderboard-history-lost = { $count ->
+    [zero] { $day }  { $difficulty } خسارة دون محاولات
+    [one] { $day }  { $difficulty } خسارة في محاولة واحدة
+    [two] { $day }  { $difficulty } خسارة في محاولتين
+    [few] { $day }  { $difficulty } خسارة في { $count } محاولات
+    [many] { $day }  { $difficulty } خسارة في { $count } محاولة
+   *[other] { $day }  { $difficulty } خسارة في { $count } محاولة
+}
+leaderboard-unverifiable = (غير قابل للتحقق، { $rng })
+leaderboard-cannot-read = تعذرت قراءة { $path }.
+leaderboard-cannot-write = تعذرت كتابة { $path }: { $error }
+leaderboard-imported = { $count ->
+    [zero] لم تُستورد أي لعبة،
+    [one] استُوردت لعبة واحدة،
+    [two] استُوردت لعبتان،
+    [few] استُوردت { $count } ألعاب،
+    [many] استُوردت { $count } لعبة،
+   *[other] استُوردت { $count } لعبة،
+}
+leaderboard-skipped = { $count ->
+    [zero] ولم يُتجاهل أي سطر غير صالح.
+    [one] وتُجوهل سطر واحد غير صالح.
+    [two] وتُجوهل سطران غير صالحين.
+    [few] وتُجوهلت { $count } أسطر غير صالحة.
+    [many] وتُجوهل { $count } سطرًا غير صالح.
+   *[other] وتُجوهل { $count } سطر غير صالح.
+}
+
+## الإحصاءات وإعادة العرض
+
+stats-none = لم تُسجَّل أي لعبة بعد.
+stats-games = الألعاب: { $count }
+stats-wins = مرات الفوز: { $count } ({ $percent }٪)
+stats-attempts = المحاولات: { $average } في المتوسط، و{ $best } في أفضل حال
+replay-no-game = { $count ->
+    [zero] لا توجد لعبة مسجلة برقم { $game }، ولا توجد أي لعبة مسجلة.
+    [one] لا توجد لعبة مسجلة برقم { $game }، توجد لعبة واحدة مسجلة.
+    [two] لا توجد لعبة مسجلة برقم { $game }، توجد لعبتان مسجلتان.
+    [few] لا توجد لعبة مسجلة برقم { $game }، توجد { $count } ألعاب مسجلة.
+    [many] لا توجد لعبة مسجلة برقم { $game }، توجد { $count } لعبة مسجلة.
+   *[other] لا توجد لعبة مسجلة برقم { $game }، توجد { $count } لعبة مسجلة.
+}
+replay-title = { $player } في { $date }، البذرة { $seed }
+replay-secret-unknown = كان السر من اختيار المضيف ولم يُعثر عليه قط، لذا لا يمكن عرض اللعبة.
+replay-does-not-add-up = هذا الإدخال غير متسق: { $problem }.
+
+## الخادم
+
+serve-cannot-listen = تعذر الاستماع على { $address }: { $error }
+serve-listening = تُقدَّم اللعبة الكلاسيكية على { $address }
+serve-won = { $count ->
+    [zero] { $peer }: فاز دون محاولات
+    [one] { $peer }: فاز في محاولة واحدة
+    [two] { $peer }: فاز في محاولتين
+    [few] { $peer }: فاز في { $count } محاولات
+    [many] { $peer }: فاز في { $count } محاولة
+   *[other] { $peer }: فاز في { $count } محاولة
+}
+serve-left = { $peer }: غادر دون فوز
+serve-error = { $peer }: { $error }
+serve-connection-failed = فشل الاتصال: { $error }
+serve-line-too-long = تجاوز سطر { $limit } بايت
+
+## التقارير
+
+column-solver = الحلّال
+column-games = الألعاب
+column-average = المتوسط
+column-bot = الروبوت
+column-chi-square = كاي مربع
+column-ends = الطرفان
+column-expected = المتوقع
+column-freedom = د.ح
+column-median = الوسيط
+column-solved = المحلولة
+column-worst = الأسوأ
+column-per-game = لكل لعبة
+column-range = المجال
+column-rng = المولّد
+
+## العدالة
+
+fairness-report = { $count ->
+    [zero] لا أسرار لكل مجال ومولّد، والرسوب تحت p = { $alpha }
+    [one] سر واحد لكل مجال ومولّد، والرسوب تحت p = { $alpha }
+    [two] سرّان لكل مجال ومولّد، والرسوب تحت p = { $alpha }
+    [few] { $count } أسرار لكل مجال ومولّد، والرسوب تحت p = { $alpha }
+    [many] { $count } سرًّا لكل مجال ومولّد، والرسوب تحت p = { $alpha }
+   *[other] { $count } سر لكل مجال ومولّد، والرسوب تحت p = { $alpha }
+}
+fairness-ends-seen = نعم
+fairness-ends-missing = لا
+fairness-passed = سليم
+fairness-failed = راسب
+
+## الأخطاء
+
+config-invalid = إعدادات غير صالحة: { $error }
+config-cannot-read = تعذرت قراءة { $path }: { $error }
+config-bad-value = { $value } ليست قيمة صالحة لـ{ $flag }
+config-unknown-flag = خيار غير معروف { $flag }
+config-unknown-rng = مولّد غير معروف { $rng }، المتوقع chacha أو pcg أو xoshiro أو dice أو fixed:N,N,...
+config-rng-not-a-number = { $value } ليس عددًا في { $rng }
+range-empty = المجال { $low }-{ $high } فارغ، يجب أن يكون الحد الأعلى فوق الحد الأدنى
+range-single = المجال { $low }-{ $high } لا يضم إلا عددًا واحدًا، يجب أن يكون الحد الأعلى فوق الحد الأدنى
+range-too-wide = المجال { $low }-{ $high } واسع جدًا، لا يمكن أن يضم أكثر من { $max } عدد
+usage-error = خطأ: { $error }
+rng-not-for-mode = { $mode } لا يسحب عددًا سريًا واحدًا في كل مرة، لذا لا يمكنه استخدام --rng { $rng }
+serve-no-dice = لا يمكن لـserve استخدام --rng dice، فالمضيف لا يستطيع كتابة سر لكل اتصال
+twenty-too-wide = لا يتابع السائل أكثر من { $max } عدد، والمجال { $low }-{ $high } واسع جدًا
+drift-too-wide = لا يتابع الحلّال أكثر من { $max } عدد، والمجال { $low }-{ $high } واسع جدًا
+drift-step-too-wide = خطوة قدرها { $step } لا تتسع في المجال { $low }-{ $high }، ولا يجوز أن تكون أوسع منه
+cost-too-wide = لا تُحسب أفضل استراتيجية لأكثر من { $max } عدد، والمجال { $low }-{ $high } واسع جدًا
+skewed-too-wide = لا تُحسب التوزيعات المسبقة لأكثر من { $max } عدد، والمجال { $low }-{ $high } واسع جدًا
+multi-too-wide = لا يتعامل الحلّال مع أكثر من { $max } عدد، والمجال { $low }-{ $high } واسع جدًا
+multi-bad-count = لا يمكن إخفاء عدد من الأسرار المختلفة قدره { $count } بين { $low } و{ $high }
+sort-bad-count = يحتاج sort إلى ما بين 2 و{ $max } عنصرًا، لا { $count }
+coop-too-many = لا يمكن لعدد من اللاعبين قدره { $count } أن يتقاسموا الأعداد من { $low } إلى { $high }
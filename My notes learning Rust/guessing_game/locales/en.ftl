@@ -0,0 +1,437 @@
+# English, also the fallback for keys missing from the other catalogs.
+
+## Classic game
+
+guess-the-number = Guess the number!
+guess-between = Guess the number between { $low } and { $high }!
+enter-guess = Please enter your guess :
+you-guessed = You guessed: { $guess }
+bad-guess = Could not read that guess: { $error }.
+parse-empty = nothing to read
+parse-bad-number = { $text } is not a number
+parse-unknown-word = { $word } is not a number word
+parse-bad-words = "{ $text }" does not spell a number
+parse-unexpected = did not expect { $token }
+parse-missing-operand = the expression stops too early
+parse-unclosed-paren = a bracket is never closed
+parse-division-by-zero = division by zero
+parse-out-of-range = the result is not between 0 and { $max }
+parse-too-deep = more than { $max } brackets or signs inside each other
+too-small = Too small!
+too-big = Too big!
+you-win = You win!
+you-win-in = { $count ->
+    [one] You win in { $count } attempt!
+   *[other] You win in { $count } attempts!
+}
+you-lose = You lose! The number was { $secret }.
+input-ended = The guesses ran out before the game was over.
+batch-cannot-read = Could not read { $path }: { $error }
+attempts-available = { $count ->
+    [one] You have { $count } attempt.
+   *[other] You have { $count } attempts.
+}
+attempts-left = { $count ->
+    [one] { $count } attempt left
+   *[other] { $count } attempts left
+}
+hint-available = Type hint (or ?) to get a hint for some of your attempts.
+hint-cost = { $count ->
+    [one] That hint cost { $count } attempt.
+   *[other] That hint cost { $count } attempts.
+}
+time-played = Time played: { $seconds }s
+adaptive-odds = { $count ->
+    [one] Rating { $rating }: { $count } attempt, about { $chance }% chance to win.
+   *[other] Rating { $rating }: { $count } attempts, about { $chance }% chance to win.
+}
+rating-change = Rating: { $rating } ({ $change })
+achievement-unlocked = Achievement unlocked: { $name }!
+
+## Saved games
+
+no-saved-game = There is no saved game to resume.
+resuming = Resuming { $name }: guess the number between { $low } and { $high }!
+no-save-called = no save called { $name }
+save-damaged = { $name } is damaged: { $error }
+game-saved = Game saved, continue it with: guessing_game resume { $name }
+
+## Daily challenge
+
+daily-already-played = You already played the { $date } puzzle, come back tomorrow!
+daily-intro = Daily challenge { $date }: guess the number between 1 and { $high }!
+daily-share = Daily { $date } { $range } { $attempts }/{ $max } { $arrows }
+
+## Analysis
+
+analysis-title = How your guesses went (1 bit per guess is as good as it gets):
+analysis-guess = { $guess } in { $low }..={ $high }  { $bits } bits
+analysis-wasted = wasted, outside the interval
+analysis-information = Information: { $bits } of { $needed } bits, { $per_guess } bits per guess.
+analysis-wasted-count = { $count ->
+    [one] { $count } wasted guess.
+   *[other] { $count } wasted guesses.
+}
+analysis-efficiency = { $count ->
+    [one] Efficiency: { $percent }% of binary search, which needs at most { $count } guess.
+   *[other] Efficiency: { $percent }% of binary search, which needs at most { $count } guesses.
+}
+
+## Grid
+
+grid-intro = Find the hidden cell on the { $width }x{ $height } grid!
+grid-enter-guess = Please enter your guess as x y :
+grid-bad-point = Please enter two whole numbers, x from 1 to { $width } and y from 1 to { $height }.
+grid-bad-size = the grid can be 1 to { $max } cells wide and high, not { $width }x{ $height }
+grid-cells-left = { $count ->
+    [one] { $count } cell left
+   *[other] { $count } cells left
+}
+go-north = Go north!
+go-south = Go south!
+go-east = Go east!
+go-west = Go west!
+go-north-east = Go north-east!
+go-north-west = Go north-west!
+go-south-east = Go south-east!
+go-south-west = Go south-west!
+temperature-burning = Burning! ({ $distance } away)
+temperature-hot = Hot! ({ $distance } away)
+temperature-warm = Warm. ({ $distance } away)
+temperature-cold = Cold. ({ $distance } away)
+temperature-freezing = Freezing! ({ $distance } away)
+grid-report = { $width }x{ $height } grid: worst case { $worst } guesses, average { $average }
+grid-bisection = { $count ->
+    [one] bisecting each axis takes { $count } guess
+   *[other] bisecting each axis takes { $count } guesses
+}
+grid-bisection-met = the solver needs no more than that
+grid-bisection-missed = the solver needs { $count } more
+
+## Mastermind
+
+mastermind-too-few-symbols = a code of { $count } without repeats needs at least { $count } symbols
+mastermind-bad-length = a code is 1 to { $max } symbols long, not { $length }
+mastermind-bad-symbols = a code uses { $min } to { $max } symbols, not { $symbols }
+mastermind-intro = Break the code! { $count } symbols from { $alphabet }
+mastermind-unique = No symbol appears twice.
+mastermind-bad-code = { $count ->
+    [one] Please enter { $count } symbol from { $alphabet }.
+   *[other] Please enter { $count } symbols from { $alphabet }.
+}
+mastermind-you-lose = You lose! The code was { $code }.
+mastermind-score = { $exact } exact, { $partial } partial
+mastermind-hint = Position { $position } is { $symbol }.
+mastermind-secret = Secret: { $code }
+mastermind-candidates = { $count ->
+    [one] ({ $count } candidate)
+   *[other] ({ $count } candidates)
+}
+
+## Wordle
+
+wordle-intro = Guess the { $count } letter word!
+wordle-hard-mode = Hard mode: revealed hints must be used in later guesses.
+wordle-not-a-word = Not in the word list.
+wordle-letter-must-be = Letter { $position } must be { $letter }.
+wordle-must-contain = Guess must contain { $letter }.
+wordle-hint = Letter { $position } is { $letter }.
+wordle-no-hint = Every letter is already placed, so there is nothing to hint.
+wordle-you-lose = You lose! The word was { $word }.
+wordle-share = Wordle { $attempts }/{ $max }{ $star }
+
+## Time attack
+
+time-up = Time's up!
+too-slow = Too slow! That turn is forfeited.
+forfeited = Forfeited
+countdown-intro = You have { $seconds }s per guess.
+total-intro = Find it in under { $seconds }s!
+marathon-intro = Marathon! Find as many numbers as you can in { $seconds }s.
+marathon-solved = { $count ->
+    [one] You solved { $count } secret.
+   *[other] You solved { $count } secrets.
+}
+
+## Reverse game
+
+reverse-intro = Think of a number between { $low } and { $high }, I will guess it!
+reverse-answers = Answer higher (h), lower (l) or yes (y).
+reverse-guess = Is it { $guess }?
+reverse-found = { $count ->
+    [one] Found it in { $count } guess!
+   *[other] Found it in { $count } guesses!
+}
+reverse-cheating = No number between { $low } and { $high } fits your answers, did you change your mind?
+
+## Team game
+
+coop-intro = { $count ->
+    [one] One player looks for a number between { $low } and { $high }.
+   *[other] { $count } players share one number between { $low } and { $high } and one pool of attempts.
+}
+coop-range = { $player } guesses between { $low } and { $high }.
+coop-turn = { $count ->
+    [one] { $player }, your turn ({ $low }-{ $high }, { $count } attempt left). Guess, pass, or start with > to leave a note:
+   *[other] { $player }, your turn ({ $low }-{ $high }, { $count } attempts left). Guess, pass, or start with > to leave a note:
+}
+coop-note = { $player } says: { $note }
+coop-pass = { $player } passes.
+coop-outside = { $guess } is not yours to guess, pick between { $low } and { $high } or pass.
+coop-everyone-passed = Everybody passed. The number is in someone's range, so someone has to guess!
+coop-win = { $count ->
+    [one] { $player } found it, the team wins in { $count } attempt!
+   *[other] { $player } found it, the team wins in { $count } attempts!
+}
+
+## Moving target
+
+drift-intro = Guess the number between { $low } and { $high }! It moves by up to { $step } after every wrong guess.
+drift-report = { $count ->
+    [one] { $low }..={ $high }, steps up to { $step }, { $count } game, given up after { $max } guesses
+   *[other] { $low }..={ $high }, steps up to { $step }, { $count } games, given up after { $max } guesses
+}
+
+## Several secrets
+
+multi-intro = { $count ->
+    [one] One secret is hidden between { $low } and { $high }. Find it!
+   *[other] { $count } different secrets are hidden between { $low } and { $high }. Find them all!
+}
+multi-answer = Below: { $below }, here: { $equal }, above: { $above }
+multi-found-all = { $count ->
+    [one] All found in { $count } guess!
+   *[other] All found in { $count } guesses!
+}
+multi-you-lose = You lose! The secrets were { $secrets }.
+
+## Twenty questions
+
+twenty-intro = { $count ->
+    [one] I am thinking of a number between { $low } and { $high }. You have { $count } yes/no question to find it.
+   *[other] I am thinking of a number between { $low } and { $high }. You have { $count } yes/no questions to find it.
+}
+twenty-examples = Ask things like: is it in 20..40? is it even? is it prime? is it divisible by 7? is its digit sum > 10? Win by asking: is it 42?
+twenty-ask = { $count ->
+    [one] Your last question:
+   *[other] Your question ({ $count } left):
+}
+twenty-bad-question = I do not understand that question: { $error }.
+query-empty = nothing was asked
+query-unknown-word = { $word } is not part of a question
+query-unexpected = did not expect { $token }
+query-missing-word = { $word } is missing at the end
+query-missing-number = a number is missing at the end
+query-missing-comparison = a comparison is missing at the end
+query-missing-question = a question is missing at the end
+query-missing-range-end = the end of the range is missing
+query-bad-number = { $text } is too big
+query-empty-range = { $low }..{ $high } holds no number
+query-division-by-zero = nothing is divisible by 0
+query-too-deep = the question nests more than { $max } levels deep
+twenty-yes = Yes.
+twenty-no = No.
+twenty-win = { $count ->
+    [one] Got it with { $count } question!
+   *[other] Got it with { $count } questions!
+}
+twenty-candidates = { $count ->
+    [one] ({ $count } number left)
+   *[other] ({ $count } numbers left)
+}
+
+## Cost-weighted guessing
+
+cost-unit = Every guess costs 1.
+cost-value = Every guess costs its own value, low guesses are cheap.
+cost-over = Every guess costs 1, and { $penalty } more when it is too high.
+cost-paid = Cost { $cost }, { $total } so far.
+cost-win = { $count ->
+    [one] You got it with { $count } guess for a total cost of { $total }.
+   *[other] You got it with { $count } guesses for a total cost of { $total }.
+}
+cost-optimal = The best strategy pays { $optimal } for this number, and never more than { $worst } for any.
+
+## Hidden order
+
+sort-intro = { $count } items, A to { $last }, are in a hidden order. Find it with as few comparisons as you can.
+sort-how = Type two items to compare them, like "A C". Once you know the order, type it smallest first, like "C < A < B".
+sort-ask = { $count ->
+    [one] { $count } comparison so far:
+   *[other] { $count } comparisons so far:
+}
+sort-bad-move = Type two items from A to { $last }, or every item once with "<" between them.
+sort-win = { $count ->
+    [one] That is the order! You used { $count } comparison.
+   *[other] That is the order! You used { $count } comparisons.
+}
+sort-wrong = Wrong! The order was { $order }.
+sort-bot-done = { $bot } found { $order } with { $count } comparisons.
+sort-bounds = Sorting { $count } items takes at least { $bound } comparisons in the worst case. Merge-insertion used { $best } on this order.
+sort-report = { $count ->
+    [one] { $items } items, { $count } game, at least { $bound } comparisons in the worst case (⌈log2 { $items }!⌉)
+   *[other] { $items } items, { $count } games, at least { $bound } comparisons in the worst case (⌈log2 { $items }!⌉)
+}
+
+## Skewed secrets
+
+skewed-prior = Not every number is as likely. How the chance is spread:
+skewed-best = { $count ->
+    [one] The best strategy for this spread finds this number with { $count } guess, { $expected } on average.
+   *[other] The best strategy for this spread finds this number with { $count } guesses, { $expected } on average.
+}
+skewed-report = { $low }..={ $high }, { $bits } bits of entropy
+
+## Guess the distribution
+
+infer-intro = Secrets between { $low } and { $high } are drawn from one of: { $shapes }. Press Enter to see a draw, { $count } at most, and name the distribution when you know it.
+infer-ask = { $count ->
+    [one] { $count } draw so far. Enter for another, or a name:
+   *[other] { $count } draws so far. Enter for another, or a name:
+}
+infer-unknown = That is not one of: { $shapes }.
+infer-right = { $count ->
+    [one] Right, it is { $shape }! You needed { $count } draw.
+   *[other] Right, it is { $shape }! You needed { $count } draws.
+}
+infer-wrong = Wrong, it was { $shape }.
+infer-out-of-rounds = No draws left! It was { $shape }.
+infer-posterior = From these draws: { $chances }
+
+## Dice
+
+rng-announce = rng { $rng }, seed { $seed }
+host-secret = Host, type the secret number between { $low } and { $high }, the players should look away:
+host-bad-secret = The secret has to be a number between { $low } and { $high }.
+host-no-secret = No secret was typed in.
+
+## Scores
+
+scores-none = No scores recorded yet.
+scores-unverifiable = The secret came from { $rng }, so this game is recorded as unverifiable and stays off the top of the leaderboard.
+scores-line-ok = line { $line }: ok
+scores-line-unverifiable = line { $line }: unverifiable, the secret came from { $rng }
+scores-line-flagged = line { $line }: FLAGGED, { $problem }
+scores-flagged = { $count ->
+    [one] { $count } entry flagged.
+   *[other] { $count } entries flagged.
+}
+scores-all-ok = All entries check out.
+scores-unreadable = cannot be read
+scores-bad-signature = bad signature, the entry was edited
+scores-after-win = guesses continue after the win at guess { $guess }
+scores-attempts-mismatch = { $count ->
+    [one] claims { $count } attempt, the transcript has { $actual }
+   *[other] claims { $count } attempts, the transcript has { $actual }
+}
+scores-claims-win = claims a win, the transcript says otherwise
+scores-claims-loss = claims a loss, the transcript says otherwise
+scores-loss-with-attempts-left = claims a loss with attempts left
+scores-too-many-attempts = uses more attempts than allowed
+
+## Leaderboard
+
+leaderboard-cannot-open = Cannot open the leaderboard: { $error }
+leaderboard-update-failed = Could not update the leaderboard: { $error }
+leaderboard-error = Leaderboard error: { $error }
+leaderboard-top = Top { $limit } on { $difficulty } ({ $window }):
+leaderboard-top-row = { $count ->
+    [one] { $rank }. { $player } { $count } attempt
+   *[other] { $rank }. { $player } { $count } attempts
+}
+leaderboard-history = Games of { $player }:
+leaderboard-history-won = { $count ->
+    [one] { $day }  { $difficulty } won  in { $count } attempt
+   *[other] { $day }  { $difficulty } won  in { $count } attempts
+}
+leaderboard-history-lost = { $count ->
+    [one] { $day }  { $difficulty } lost in { $count } attempt
+   *[other] { $day }  { $difficulty } lost in { $count } attempts
+}
+leaderboard-unverifiable = (unverifiable, { $rng })
+leaderboard-cannot-read = Cannot read { $path }.
+leaderboard-cannot-write = Cannot write { $path }: { $error }
+leaderboard-imported = { $count ->
+    [one] Imported { $count } game,
+   *[other] Imported { $count } games,
+}
+leaderboard-skipped = { $count ->
+    [one] skipped { $count } invalid line.
+   *[other] skipped { $count } invalid lines.
+}
+
+## Stats and replays
+
+stats-none = No games recorded yet.
+stats-games = Games:    { $count }
+stats-wins = Wins:     { $count } ({ $percent }%)
+stats-attempts = Attempts: { $average } on average, { $best } at best
+replay-no-game = There is no recorded game number { $game }, { $count } recorded.
+replay-title = { $player } on { $date }, seed { $seed }
+replay-secret-unknown = The secret was the host's and it was never found, so the game cannot be shown.
+replay-does-not-add-up = This entry does not add up: { $problem }.
+
+## Server
+
+serve-cannot-listen = Cannot listen on { $address }: { $error }
+serve-listening = Serving the classic game on { $address }
+serve-won = { $count ->
+    [one] { $peer }: won in { $count } attempt
+   *[other] { $peer }: won in { $count } attempts
+}
+serve-left = { $peer }: left without winning
+serve-error = { $peer }: { $error }
+serve-connection-failed = Connection failed: { $error }
+serve-line-too-long = a line was longer than { $limit } bytes
+
+## Reports
+
+column-solver = solver
+column-games = games
+column-average = average
+column-bot = bot
+column-chi-square = chi-square
+column-ends = ends
+column-expected = expected
+column-freedom = df
+column-median = median
+column-solved = solved
+column-worst = worst
+column-per-game = per game
+column-range = range
+column-rng = rng
+
+## Fairness
+
+fairness-report = { $count ->
+    [one] { $count } secret per range and backend, failing below p = { $alpha }
+   *[other] { $count } secrets per range and backend, failing below p = { $alpha }
+}
+fairness-ends-seen = yes
+fairness-ends-missing = NO
+fairness-passed = ok
+fairness-failed = FAILED
+
+## Errors
+
+config-invalid = Invalid configuration: { $error }
+config-cannot-read = cannot read { $path }: { $error }
+config-bad-value = { $value } is not a valid value for { $flag }
+config-unknown-flag = unknown flag { $flag }
+config-unknown-rng = unknown rng { $rng }, expected chacha, pcg, xoshiro, dice or fixed:N,N,...
+config-rng-not-a-number = { $value } is not a number in { $rng }
+range-empty = the range { $low }-{ $high } is empty, the high end must be above the low one
+range-single = the range { $low }-{ $high } holds a single number, the high end must be above the low one
+range-too-wide = the range { $low }-{ $high } is too wide, it can hold at most { $max } numbers
+usage-error = error: { $error }
+rng-not-for-mode = { $mode } does not draw one secret number at a time, so it cannot use --rng { $rng }
+serve-no-dice = serve cannot use --rng dice, the host cannot type a secret for every connection
+twenty-too-wide = the questioner follows at most { $max } numbers, { $low }-{ $high } is too wide
+drift-too-wide = the tracking solver follows at most { $max } numbers, { $low }-{ $high } is too wide
+drift-step-too-wide = a step of { $step } does not fit the range { $low }-{ $high }, it can be at most as wide as the range
+cost-too-wide = the best strategy is worked out for at most { $max } numbers, { $low }-{ $high } is too wide
+skewed-too-wide = priors are worked out for at most { $max } numbers, { $low }-{ $high } is too wide
+multi-too-wide = the solver handles at most { $max } numbers, { $low }-{ $high } is too wide
+multi-bad-count = cannot hide { $count } different secrets between { $low } and { $high }
+sort-bad-count = sort needs between 2 and { $max } items, not { $count }
+coop-too-many = { $count } players cannot share the numbers { $low } to { $high }
@@ -0,0 +1,440 @@
+# Français
+
+## Partie classique
+
+guess-the-number = Devinez le nombre !
+guess-between = Devinez le nombre entre { $low } et { $high } !
+enter-guess = Entrez votre proposition :
+you-guessed = Vous avez proposé : { $guess }
+bad-guess = Proposition illisible : { $error }.
+parse-empty = rien à lire
+parse-bad-number = { $text } n'est pas un nombre
+parse-unknown-word = { $word } n'est pas un nombre en lettres
+parse-bad-words = « { $text } » n'écrit aucun nombre
+parse-unexpected = { $token } n'était pas attendu
+parse-missing-operand = l'expression s'arrête trop tôt
+parse-unclosed-paren = une parenthèse n'est jamais fermée
+parse-division-by-zero = division par zéro
+parse-out-of-range = le résultat n'est pas entre 0 et { $max }
+parse-too-deep = plus de { $max } parenthèses ou signes les uns dans les autres
+too-small = Trop petit !
+too-big = Trop grand !
+you-win = Gagné !
+you-win-in = { $count ->
+    [one] Gagné en { $count } essai !
+   *[other] Gagné en { $count } essais !
+}
+you-lose = Perdu ! Le nombre était { $secret }.
+input-ended = Plus de propositions avant la fin de la partie.
+batch-cannot-read = Impossible de lire { $path } : { $error }
+attempts-available = { $count ->
+    [one] Vous avez { $count } essai.
+   *[other] Vous avez { $count } essais.
+}
+attempts-left = { $count ->
+    [one] { $count } essai restant
+   *[other] { $count } essais restants
+}
+hint-available = Tapez hint (ou ?) pour un indice, payé en essais.
+hint-cost = { $count ->
+    [one] Cet indice vous a coûté { $count } essai.
+   *[other] Cet indice vous a coûté { $count } essais.
+}
+time-played = Temps de jeu : { $seconds } s
+adaptive-odds = { $count ->
+    [one] Classement { $rating } : { $count } essai, environ { $chance } % de chances de gagner.
+   *[other] Classement { $rating } : { $count } essais, environ { $chance } % de chances de gagner.
+}
+rating-change = Classement : { $rating } ({ $change })
+achievement-unlocked = Succès débloqué : { $name } !
+
+## Parties sauvegardées
+
+no-saved-game = Aucune partie sauvegardée à reprendre.
+resuming = Reprise de { $name } : devinez le nombre entre { $low } et { $high } !
+no-save-called = aucune sauvegarde nommée { $name }
+save-damaged = { $name } est endommagée : { $error }
+game-saved = Partie sauvegardée, reprenez-la avec : guessing_game resume { $name }
+
+## Défi du jour
+
+daily-already-played = Vous avez déjà joué le défi du { $date }, revenez demain !
+daily-intro = Défi du { $date } : devinez le nombre entre 1 et { $high } !
+daily-share = Défi du jour { $date } { $range } { $attempts }/{ $max } { $arrows }
+
+## Analyse
+
+analysis-title = Le déroulé de vos propositions (1 bit par proposition, c'est le mieux possible) :
+analysis-guess = { $guess } dans { $low }..={ $high }  { $bits } bits
+analysis-wasted = perdue, hors de l'intervalle
+analysis-information = Information : { $bits } bits sur { $needed }, { $per_guess } bits par proposition.
+analysis-wasted-count = { $count ->
+    [one] { $count } proposition perdue.
+   *[other] { $count } propositions perdues.
+}
+analysis-efficiency = { $count ->
+    [one] Efficacité : { $percent } % de la recherche dichotomique, qui demande au plus { $count } proposition.
+   *[other] Efficacité : { $percent } % de la recherche dichotomique, qui demande au plus { $count } propositions.
+}
+
+## Grille
+
+grid-intro = Trouvez la case cachée sur la grille de { $width }x{ $height } !
+grid-enter-guess = Entrez votre proposition sous la forme x y :
+grid-bad-point = Entrez deux nombres entiers, x de 1 à { $width } et y de 1 à { $height }.
+grid-bad-size = la grille fait de 1 à { $max } cases de large et de haut, pas { $width }x{ $height }
+grid-cells-left = { $count ->
+    [one] { $count } case restante
+   *[other] { $count } cases restantes
+}
+go-north = Allez au nord !
+go-south = Allez au sud !
+go-east = Allez à l'est !
+go-west = Allez à l'ouest !
+go-north-east = Allez au nord-est !
+go-north-west = Allez au nord-ouest !
+go-south-east = Allez au sud-est !
+go-south-west = Allez au sud-ouest !
+temperature-burning = Brûlant ! (à { $distance })
+temperature-hot = Chaud ! (à { $distance })
+temperature-warm = Tiède. (à { $distance })
+temperature-cold = Froid. (à { $distance })
+temperature-freezing = Glacial ! (à { $distance })
+grid-report = Grille de { $width }x{ $height } : { $worst } propositions au pire, { $average } en moyenne
+grid-bisection = { $count ->
+    [one] couper chaque axe en deux demande { $count } proposition
+   *[other] couper chaque axe en deux demande { $count } propositions
+}
+grid-bisection-met = le solveur n'en demande pas plus
+grid-bisection-missed = le solveur en demande { $count } de plus
+
+## Mastermind
+
+mastermind-too-few-symbols = un code de { $count } sans répétition demande au moins { $count } symboles
+mastermind-bad-length = un code fait de 1 à { $max } symboles, pas { $length }
+mastermind-bad-symbols = un code utilise de { $min } à { $max } symboles, pas { $symbols }
+mastermind-intro = Cassez le code ! { $count } symboles parmi { $alphabet }
+mastermind-unique = Aucun symbole n'apparaît deux fois.
+mastermind-bad-code = { $count ->
+    [one] Entrez { $count } symbole parmi { $alphabet }.
+   *[other] Entrez { $count } symboles parmi { $alphabet }.
+}
+mastermind-you-lose = Perdu ! Le code était { $code }.
+mastermind-score = { $exact } bien placés, { $partial } mal placés
+mastermind-hint = La position { $position } est { $symbol }.
+mastermind-secret = Code secret : { $code }
+mastermind-candidates = { $count ->
+    [one] ({ $count } candidat)
+   *[other] ({ $count } candidats)
+}
+
+## Wordle
+
+wordle-intro = Devinez le mot de { $count } lettres !
+wordle-hard-mode = Mode difficile : les indices révélés doivent être réutilisés.
+wordle-not-a-word = Ce mot n'est pas dans la liste.
+wordle-letter-must-be = La lettre { $position } doit être { $letter }.
+wordle-must-contain = La proposition doit contenir { $letter }.
+wordle-hint = La lettre { $position } est { $letter }.
+wordle-no-hint = Toutes les lettres sont déjà placées, il n'y a plus rien à révéler.
+wordle-you-lose = Perdu ! Le mot était { $word }.
+wordle-share = Wordle { $attempts }/{ $max }{ $star }
+
+## Contre la montre
+
+time-up = Temps écoulé !
+too-slow = Trop lent ! Ce tour est perdu.
+forfeited = Perdu
+countdown-intro = Vous avez { $seconds } s par proposition.
+total-intro = Trouvez-le en moins de { $seconds } s !
+marathon-intro = Marathon ! Trouvez autant de nombres que possible en { $seconds } s.
+marathon-solved = { $count ->
+    [one] Vous avez trouvé { $count } nombre.
+   *[other] Vous avez trouvé { $count } nombres.
+}
+
+## Partie inversée
+
+reverse-intro = Pensez à un nombre entre { $low } et { $high }, je vais le deviner !
+reverse-answers = Répondez plus (+), moins (-) ou oui (o).
+reverse-guess = Est-ce { $guess } ?
+reverse-found = { $count ->
+    [one] Trouvé en { $count } proposition !
+   *[other] Trouvé en { $count } propositions !
+}
+reverse-cheating = Aucun nombre entre { $low } et { $high } ne correspond à vos réponses, avez-vous changé d'avis ?
+
+## Partie en équipe
+
+coop-intro = { $count ->
+    [one] Un joueur cherche un nombre entre { $low } et { $high }.
+   *[other] { $count } joueurs partagent un nombre entre { $low } et { $high } et une réserve d'essais.
+}
+coop-range = { $player } propose entre { $low } et { $high }.
+coop-turn = { $count ->
+    [one] { $player }, à vous ({ $low }-{ $high }, { $count } essai restant). Proposez, passez, ou commencez par > pour laisser un mot :
+   *[other] { $player }, à vous ({ $low }-{ $high }, { $count } essais restants). Proposez, passez, ou commencez par > pour laisser un mot :
+}
+coop-note = { $player } dit : { $note }
+coop-pass = { $player } passe.
+coop-outside = { $guess } n'est pas à vous, choisissez entre { $low } et { $high } ou passez.
+coop-everyone-passed = Tout le monde a passé. Le nombre est forcément chez quelqu'un, il faut proposer !
+coop-win = { $count ->
+    [one] { $player } l'a trouvé, l'équipe gagne en { $count } essai !
+   *[other] { $player } l'a trouvé, l'équipe gagne en { $count } essais !
+}
+
+## Cible mouvante
+
+drift-intro = Devinez le nombre entre { $low } et { $high } ! Il se déplace d'au plus { $step } après chaque mauvaise proposition.
+drift-report = { $count ->
+    [one] { $low }..={ $high }, pas jusqu'à { $step }, { $count } partie, abandon après { $max } propositions
+   *[other] { $low }..={ $high }, pas jusqu'à { $step }, { $count } parties, abandon après { $max } propositions
+}
+
+## Plusieurs secrets
+
+multi-intro = { $count ->
+    [one] Un secret est caché entre { $low } et { $high }. Trouvez-le !
+   *[other] { $count } secrets différents sont cachés entre { $low } et { $high }. Trouvez-les tous !
+}
+multi-answer = En dessous : { $below }, ici : { $equal }, au-dessus : { $above }
+multi-found-all = { $count ->
+    [one] Tous trouvés en { $count } proposition !
+   *[other] Tous trouvés en { $count } propositions !
+}
+multi-you-lose = Perdu ! Les secrets étaient { $secrets }.
+
+## Vingt questions
+
+twenty-intro = { $count ->
+    [one] Je pense à un nombre entre { $low } et { $high }. Vous avez { $count } question oui/non pour le trouver.
+   *[other] Je pense à un nombre entre { $low } et { $high }. Vous avez { $count } questions oui/non pour le trouver.
+}
+twenty-examples = Les questions s'écrivent en anglais : is it in 20..40? is it even? is it prime? is it divisible by 7? is its digit sum > 10? Pour gagner : is it 42?
+twenty-ask = { $count ->
+    [one] Votre dernière question :
+   *[other] Votre question ({ $count } restantes) :
+}
+twenty-bad-question = Je ne comprends pas cette question : { $error }.
+query-empty = rien n'a été demandé
+query-unknown-word = { $word } ne fait pas partie d'une question
+query-unexpected = { $token } n'était pas attendu
+query-missing-word = il manque { $word } à la fin
+query-missing-number = il manque un nombre à la fin
+query-missing-comparison = il manque une comparaison à la fin
+query-missing-question = il manque une question à la fin
+query-missing-range-end = il manque la fin de l'intervalle
+query-bad-number = { $text } est trop grand
+query-empty-range = { $low }..{ $high } ne contient aucun nombre
+query-division-by-zero = aucun nombre n'est divisible par 0
+query-too-deep = la question s'imbrique sur plus de { $max } niveaux
+twenty-yes = Oui.
+twenty-no = Non.
+twenty-win = { $count ->
+    [one] Trouvé en { $count } question !
+   *[other] Trouvé en { $count } questions !
+}
+twenty-candidates = { $count ->
+    [one] ({ $count } nombre restant)
+   *[other] ({ $count } nombres restants)
+}
+
+## Deviner avec un coût
+
+cost-unit = Chaque essai coûte 1.
+cost-value = Chaque essai coûte sa propre valeur, les petits nombres sont bon marché.
+cost-over = Chaque essai coûte 1, et { $penalty } de plus s'il est trop grand.
+cost-paid = Coût { $cost }, { $total } jusqu'ici.
+cost-win = { $count ->
+    [one] Trouvé en { $count } essai pour un coût total de { $total }.
+   *[other] Trouvé en { $count } essais pour un coût total de { $total }.
+}
+cost-optimal = La meilleure stratégie paie { $optimal } pour ce nombre, et jamais plus de { $worst } pour aucun.
+
+## Ordre caché
+
+sort-intro = { $count } éléments, de A à { $last }, sont dans un ordre caché. Trouvez-le avec le moins de comparaisons possible.
+sort-how = Tapez deux éléments pour les comparer, comme « A C ». Quand vous connaissez l'ordre, tapez-le du plus petit au plus grand, comme « C < A < B ».
+sort-ask = { $count ->
+    [one] { $count } comparaison jusqu'ici :
+   *[other] { $count } comparaisons jusqu'ici :
+}
+sort-bad-move = Tapez deux éléments de A à { $last }, ou chaque élément une fois avec « < » entre eux.
+sort-win = { $count ->
+    [one] C'est le bon ordre ! Vous avez utilisé { $count } comparaison.
+   *[other] C'est le bon ordre ! Vous avez utilisé { $count } comparaisons.
+}
+sort-wrong = Perdu ! L'ordre était { $order }.
+sort-bot-done = { $bot } a trouvé { $order } en { $count } comparaisons.
+sort-bounds = Trier { $count } éléments demande au moins { $bound } comparaisons dans le pire cas. Le tri par fusion-insertion en a utilisé { $best } sur cet ordre.
+sort-report = { $count ->
+    [one] { $items } éléments, { $count } partie, au moins { $bound } comparaisons dans le pire cas (⌈log2 { $items }!⌉)
+   *[other] { $items } éléments, { $count } parties, au moins { $bound } comparaisons dans le pire cas (⌈log2 { $items }!⌉)
+}
+
+## Secrets biaisés
+
+skewed-prior = Tous les nombres ne sont pas aussi probables. Voici comment la chance se répartit :
+skewed-best = { $count ->
+    [one] La meilleure stratégie pour cette répartition trouve ce nombre en { $count } essai, { $expected } en moyenne.
+   *[other] La meilleure stratégie pour cette répartition trouve ce nombre en { $count } essais, { $expected } en moyenne.
+}
+skewed-report = { $low }..={ $high }, { $bits } bits d'entropie
+
+## Deviner la distribution
+
+infer-intro = Des secrets entre { $low } et { $high } sont tirés d'une de ces distributions : { $shapes }. Appuyez sur Entrée pour voir un tirage, { $count } au plus, et nommez la distribution quand vous la connaissez.
+infer-ask = { $count ->
+    [one] { $count } tirage jusqu'ici. Entrée pour un autre, ou un nom :
+   *[other] { $count } tirages jusqu'ici. Entrée pour un autre, ou un nom :
+}
+infer-unknown = Ce n'est pas l'une de : { $shapes }.
+infer-right = { $count ->
+    [one] Exact, c'est { $shape } ! Il vous a fallu { $count } tirage.
+   *[other] Exact, c'est { $shape } ! Il vous a fallu { $count } tirages.
+}
+infer-wrong = Perdu, c'était { $shape }.
+infer-out-of-rounds = Plus de tirages ! C'était { $shape }.
+infer-posterior = D'après ces tirages : { $chances }
+
+## Dés
+
+rng-announce = générateur { $rng }, graine { $seed }
+host-secret = Hôte, tapez le nombre secret entre { $low } et { $high }, les joueurs doivent regarder ailleurs :
+host-bad-secret = Le secret doit être un nombre entre { $low } et { $high }.
+host-no-secret = Aucun secret n'a été tapé.
+
+## Scores
+
+scores-none = Aucun score enregistré pour l'instant.
+scores-unverifiable = Le secret venait de { $rng }, cette partie est donc enregistrée comme invérifiable et n'entre pas dans le haut du classement.
+scores-line-ok = ligne { $line } : ok
+scores-line-unverifiable = ligne { $line } : invérifiable, le secret venait de { $rng }
+scores-line-flagged = ligne { $line } : SIGNALÉE, { $problem }
+scores-flagged = { $count ->
+    [one] { $count } entrée signalée.
+   *[other] { $count } entrées signalées.
+}
+scores-all-ok = Toutes les entrées sont valables.
+scores-unreadable = illisible
+scores-bad-signature = signature invalide, l'entrée a été modifiée
+scores-after-win = des propositions suivent la victoire à la proposition { $guess }
+scores-attempts-mismatch = { $count ->
+    [one] annonce { $count } essai, la transcription en compte { $actual }
+   *[other] annonce { $count } essais, la transcription en compte { $actual }
+}
+scores-claims-win = annonce une victoire, la transcription dit le contraire
+scores-claims-loss = annonce une défaite, la transcription dit le contraire
+scores-loss-with-attempts-left = annonce une défaite alors qu'il restait des essais
+scores-too-many-attempts = utilise plus d'essais que permis
+
+## Classement
+
+leaderboard-cannot-open = Impossible d'ouvrir le classement : { $error }
+leaderboard-update-failed = Impossible de mettre à jour le classement : { $error }
+leaderboard-error = Erreur du classement : { $error }
+leaderboard-top = Les { $limit } meilleurs en { $difficulty } ({ $window }) :
+leaderboard-top-row = { $count ->
+    [one] { $rank }. { $player } { $count } essai
+   *[other] { $rank }. { $player } { $count } essais
+}
+leaderboard-history = Parties de { $player } :
+leaderboard-history-won = { $count ->
+    [one] { $day }  { $difficulty } gagnée en { $count } essai
+   *[other] { $day }  { $difficulty } gagnée en { $count } essais
+}
+leaderboard-history-lost = { $count ->
+    [one] { $day }  { $difficulty } perdue en { $count } essai
+   *[other] { $day }  { $difficulty } perdue en { $count } essais
+}
+leaderboard-unverifiable = (invérifiable, { $rng })
+leaderboard-cannot-read = Impossible de lire { $path }.
+leaderboard-cannot-write = Impossible d'écrire { $path } : { $error }
+leaderboard-imported = { $count ->
+    [one] { $count } partie importée,
+   *[other] { $count } parties importées,
+}
+leaderboard-skipped = { $count ->
+    [one] { $count } ligne invalide ignorée.
+   *[other] { $count } lignes invalides ignorées.
+}
+
+## Statistiques et rejeux
+
+stats-none = Aucune partie enregistrée pour l'instant.
+stats-games = Parties :    { $count }
+stats-wins = Victoires :  { $count } ({ $percent } %)
+stats-attempts = Essais :     { $average } en moyenne, { $best } au mieux
+replay-no-game = { $count ->
+    [one] Il n'y a pas de partie enregistrée numéro { $game }, { $count } enregistrée.
+   *[other] Il n'y a pas de partie enregistrée numéro { $game }, { $count } enregistrées.
+}
+replay-title = { $player } le { $date }, graine { $seed }
+replay-secret-unknown = Le secret venait de l'hôte et n'a jamais été trouvé, la partie ne peut donc pas être montrée.
+replay-does-not-add-up = Cette entrée n'est pas cohérente : { $problem }.
+
+## Serveur
+
+serve-cannot-listen = Impossible d'écouter sur { $address } : { $error }
+serve-listening = La partie classique est servie sur { $address }
+serve-won = { $count ->
+    [one] { $peer } : gagné en { $count } essai
+   *[other] { $peer } : gagné en { $count } essais
+}
+serve-left = { $peer } : parti sans gagner
+serve-error = { $peer } : { $error }
+serve-connection-failed = La connexion a échoué : { $error }
+serve-line-too-long = une ligne dépassait { $limit } octets
+
+## Rapports
+
+column-solver = solveur
+column-games = parties
+column-average = moyenne
+column-bot = robot
+column-chi-square = khi-deux
+column-ends = bouts
+column-expected = espérance
+column-freedom = ddl
+column-median = médiane
+column-solved = résolues
+column-worst = pire
+column-per-game = par partie
+column-range = intervalle
+column-rng = générateur
+
+## Équité
+
+fairness-report = { $count ->
+    [one] { $count } secret par intervalle et générateur, échec sous p = { $alpha }
+   *[other] { $count } secrets par intervalle et générateur, échec sous p = { $alpha }
+}
+fairness-ends-seen = oui
+fairness-ends-missing = NON
+fairness-passed = ok
+fairness-failed = ÉCHEC
+
+## Erreurs
+
+config-invalid = Configuration invalide : { $error }
+config-cannot-read = impossible de lire { $path } : { $error }
+config-bad-value = { $value } n'est pas une valeur valide pour { $flag }
+config-unknown-flag = option inconnue { $flag }
+config-unknown-rng = générateur inconnu { $rng }, attendu chacha, pcg, xoshiro, dice ou fixed:N,N,...
+config-rng-not-a-number = { $value } n'est pas un nombre dans { $rng }
+range-empty = l'intervalle { $low }-{ $high } est vide, la borne haute doit être au-dessus de la borne basse
+range-single = l'intervalle { $low }-{ $high } ne contient qu'un nombre, la borne haute doit être au-dessus de la borne basse
+range-too-wide = l'intervalle { $low }-{ $high } est trop large, il peut contenir au plus { $max } nombres
+usage-error = erreur : { $error }
+rng-not-for-mode = { $mode } ne tire pas un seul nombre secret à la fois, il ne peut donc pas utiliser --rng { $rng }
+serve-no-dice = serve ne peut pas utiliser --rng dice, l'hôte ne peut pas taper un secret pour chaque connexion
+twenty-too-wide = le questionneur suit au plus { $max } nombres, { $low }-{ $high } est trop large
+drift-too-wide = le solveur qui suit le nombre gère au plus { $max } nombres, { $low }-{ $high } est trop large
+drift-step-too-wide = un pas de { $step } ne tient pas dans l'intervalle { $low }-{ $high }, il peut être au plus aussi large que l'intervalle
+cost-too-wide = la meilleure stratégie se calcule pour au plus { $max } nombres, { $low }-{ $high } est trop large
+skewed-too-wide = les lois a priori se calculent pour au plus { $max } nombres, { $low }-{ $high } est trop large
+multi-too-wide = le solveur gère au plus { $max } nombres, { $low }-{ $high } est trop large
+multi-bad-count = impossible de cacher { $count } secrets différents entre { $low } et { $high }
+sort-bad-count = sort demande entre 2 et { $max } éléments, pas { $count }
+coop-too-many = { $count } joueurs ne peuvent pas se partager les nombres de { $low } à { $high }
@@ -0,0 +1,197 @@
+use std::fs;
+
+use crate::i18n::t;
+use crate::{bits, storage};
+
+// Used when the player has no achievements.txt of their own.
+const DEFAULT_ACHIEVEMENTS: &str = include_str!("achievements.txt");
+const CONFIG_FILE: &str = "achievements.txt";
+const PROGRESS_FILE: &str = "achievements_progress.txt";
+
+// What a mode reports once a round is over.
+pub struct GameResult {
+    pub mode: &'static str,
+    pub won: bool,
+    pub attempts: u32,
+    pub range: u32,
+    // the day number, for daily challenges
+    pub day: Option<u64>,
+}
+
+pub struct Achievement {
+    pub id: String,
+    pub name: String,
+    pub condition: String,
+}
+
+// Counters kept between games, saved as key=value lines.
+#[derive(Default)]
+struct Progress {
+    games: u32,
+    streak: u32,
+    daily_streak: u32,
+    last_daily: Option<u64>,
+    unlocked: Vec<String>,
+}
+
+#[derive(PartialEq, PartialOrd)]
+enum Value {
+    Number(i64),
+    Word(String),
+}
+
+pub fn definitions() -> Vec<Achievement> {
+    let text = fs::read_to_string(storage::config_dir().join(CONFIG_FILE))
+        .unwrap_or_else(|_| DEFAULT_ACHIEVEMENTS.to_string());
+
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '|').map(str::trim);
+            Some(Achievement {
+                id: parts.next()?.to_string(),
+                name: parts.next()?.to_string(),
+                condition: parts.next()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+impl Progress {
+    fn load() -> Progress {
+        let mut progress = Progress::default();
+        let Ok(text) = fs::read_to_string(storage::data_dir().join(PROGRESS_FILE)) else {
+            return progress;
+        };
+
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once('=') else { continue };
+            match key {
+                "games" => progress.games = value.parse().unwrap_or(0),
+                "streak" => progress.streak = value.parse().unwrap_or(0),
+                "daily_streak" => progress.daily_streak = value.parse().unwrap_or(0),
+                "last_daily" => progress.last_daily = value.parse().ok(),
+                "unlocked" => progress.unlocked.push(value.to_string()),
+                _ => {}
+            }
+        }
+        progress
+    }
+
+    fn save(&self) {
+        let mut text = format!(
+            "games={}\nstreak={}\ndaily_streak={}\n",
+            self.games, self.streak, self.daily_streak
+        );
+        if let Some(day) = self.last_daily {
+            text.push_str(&format!("last_daily={day}\n"));
+        }
+        for id in &self.unlocked {
+            text.push_str(&format!("unlocked={id}\n"));
+        }
+
+        fs::write(storage::data_dir().join(PROGRESS_FILE), text).expect("Failed to save achievements");
+    }
+
+    fn update(&mut self, result: &GameResult) {
+        self.games += 1;
+        self.streak = if result.won { self.streak + 1 } else { 0 };
+
+        if let Some(day) = result.day {
+            self.daily_streak = match self.last_daily {
+                Some(last) if last + 1 == day => self.daily_streak + 1,
+                Some(last) if last == day => self.daily_streak,
+                _ => 1,
+            };
+            self.last_daily = Some(day);
+        }
+    }
+}
+
+// ceil(log2(range)), the bits it takes to pick one number out of range:
+// 6 for 64 numbers, 7 for 65.
+fn optimal(range: u32) -> u32 {
+    bits(range)
+}
+
+fn field(name: &str, result: &GameResult, progress: &Progress) -> Option<Value> {
+    let number = |n: u32| Some(Value::Number(n as i64));
+
+    match name {
+        "mode" => Some(Value::Word(result.mode.to_string())),
+        "won" => number(result.won as u32),
+        "attempts" => number(result.attempts),
+        "range" => number(result.range),
+        "optimal" => number(optimal(result.range)),
+        "games" => number(progress.games),
+        "streak" => number(progress.streak),
+        "daily_streak" => number(progress.daily_streak),
+        _ => None,
+    }
+}
+
+// A field, a number, or any other word taken literally.
+fn value(token: &str, result: &GameResult, progress: &Progress) -> Value {
+    if let Ok(n) = token.parse() {
+        return Value::Number(n);
+    }
+    field(token, result, progress).unwrap_or_else(|| Value::Word(token.to_string()))
+}
+
+// Conditions that do not parse are simply never met.
+fn holds(condition: &str, result: &GameResult, progress: &Progress) -> bool {
+    condition.split("&&").all(|check| {
+        let tokens: Vec<&str> = check.split_whitespace().collect();
+
+        match tokens[..] {
+            [name] => field(name, result, progress).is_some_and(|value| value != Value::Number(0)),
+            [left, op, right] => {
+                let left = value(left, result, progress);
+                let right = value(right, result, progress);
+                let comparable = matches!(
+                    (&left, &right),
+                    (Value::Number(_), Value::Number(_)) | (Value::Word(_), Value::Word(_))
+                );
+                match op {
+                    "==" => left == right,
+                    "!=" => left != right,
+                    "<" => comparable && left < right,
+                    "<=" => comparable && left <= right,
+                    ">" => comparable && left > right,
+                    ">=" => comparable && left >= right,
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    })
+}
+
+// Updates the counters with the game that just ended and prints every
+// achievement it unlocked.
+pub fn announce(result: &GameResult) {
+    let mut progress = Progress::load();
+    progress.update(result);
+
+    for achievement in definitions() {
+        if progress.unlocked.contains(&achievement.id) {
+            continue;
+        }
+        if holds(&achievement.condition, result, &progress) {
+            println!("{}", t!("achievement-unlocked", name = achievement.name));
+            progress.unlocked.push(achievement.id);
+        }
+    }
+
+    progress.save();
+}
+
+pub fn list() {
+    let progress = Progress::load();
+
+    for achievement in definitions() {
+        let mark = if progress.unlocked.contains(&achievement.id) { "x" } else { " " };
+        println!("[{mark}] {:<24} {}", achievement.name, achievement.condition);
+    }
+}
@@ -0,0 +1,51 @@
+use std::cmp::Ordering;
+
+use crate::i18n::t;
+use crate::information;
+
+pub fn print(low: u32, high: u32, secret: u32, guesses: &[u32]) {
+    if guesses.is_empty() {
+        return;
+    }
+
+    let summary = information::summarise(low, high, secret, guesses);
+    let reports = &summary.reports;
+
+    println!();
+    println!("{}", t!("analysis-title"));
+    for (i, report) in reports.iter().enumerate() {
+        let answer = match report.outcome {
+            Ordering::Less => t!("too-small"),
+            Ordering::Greater => t!("too-big"),
+            Ordering::Equal => t!("you-win"),
+        };
+        let line = t!(
+            "analysis-guess",
+            guess = report.guess,
+            low = report.low,
+            high = report.high,
+            bits = format!("{:.2}", report.bits)
+        );
+        let note = if report.wasted { format!("  {}", t!("analysis-wasted")) } else { String::new() };
+        println!("{:>3}. {line}  {answer}{note}", i + 1);
+    }
+
+    println!(
+        "{}",
+        t!(
+            "analysis-information",
+            bits = format!("{:.2}", summary.total_bits),
+            needed = format!("{:.2}", summary.needed_bits),
+            per_guess = format!("{:.2}", summary.total_bits / reports.len() as f64)
+        )
+    );
+    println!("{}", t!("analysis-wasted-count", count = summary.wasted));
+    println!(
+        "{}",
+        t!(
+            "analysis-efficiency",
+            percent = format!("{:.0}", summary.efficiency * 100.0),
+            count = summary.binary_search
+        )
+    );
+}
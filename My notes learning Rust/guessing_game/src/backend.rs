@@ -0,0 +1,177 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_pcg::Pcg64;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use serde::{Deserialize, Serialize};
+
+// Where secrets come from: one of three seeded generators, a fixed list
+// for tests and demos, or dice, where the host types the secret in. Modes
+// that draw anything other than one secret number at a time (an order,
+// several secrets, a word) refuse the last two.
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Backend {
+    // the one recorded scores are checked against
+    #[default]
+    ChaCha,
+    Pcg,
+    Xoshiro,
+    // fixed:15,40,99 plays 15, then 40, then 99, then 15 again
+    Fixed(Vec<u64>),
+    // the host picks the secret number
+    Dice,
+}
+
+impl Backend {
+    // A generator for one game. Dice has none of its own, the host is
+    // asked for the secret before it is needed.
+    pub fn source(&self, seed: u64) -> Source {
+        match self {
+            Backend::ChaCha | Backend::Dice => Source::ChaCha(ChaCha8Rng::seed_from_u64(seed)),
+            Backend::Pcg => Source::Pcg(Pcg64::seed_from_u64(seed)),
+            Backend::Xoshiro => Source::Xoshiro(Xoshiro256PlusPlus::seed_from_u64(seed)),
+            Backend::Fixed(values) => {
+                Source::Fixed { values: values.clone(), next: 0, rest: ChaCha8Rng::seed_from_u64(seed) }
+            }
+        }
+    }
+
+    // The same seed gives the same game.
+    pub fn is_seeded(&self) -> bool {
+        matches!(self, Backend::ChaCha | Backend::Pcg | Backend::Xoshiro)
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Backend::ChaCha => write!(f, "chacha"),
+            Backend::Pcg => write!(f, "pcg"),
+            Backend::Xoshiro => write!(f, "xoshiro"),
+            Backend::Dice => write!(f, "dice"),
+            Backend::Fixed(values) => {
+                let values: Vec<String> = values.iter().map(u64::to_string).collect();
+                write!(f, "fixed:{}", values.join(","))
+            }
+        }
+    }
+}
+
+// Why a backend name could not be read.
+#[derive(Debug, PartialEq)]
+pub enum BackendError {
+    Unknown(String),
+    // a value of a fixed:N,N,... list
+    NotANumber(String),
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BackendError::Unknown(text) => write!(f, "unknown rng {text}, expected chacha, pcg, xoshiro, dice or fixed:N,N,..."),
+            BackendError::NotANumber(value) => write!(f, "{value} is not a number"),
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = BackendError;
+
+    fn from_str(text: &str) -> Result<Backend, BackendError> {
+        match text {
+            "chacha" => Ok(Backend::ChaCha),
+            "pcg" => Ok(Backend::Pcg),
+            "xoshiro" => Ok(Backend::Xoshiro),
+            "dice" => Ok(Backend::Dice),
+            _ => {
+                let Some(values) = text.strip_prefix("fixed:") else {
+                    return Err(BackendError::Unknown(text.to_string()));
+                };
+                let values: Vec<u64> = values
+                    .split(',')
+                    .map(|value| value.trim().parse().map_err(|_| BackendError::NotANumber(value.trim().to_string())))
+                    .collect::<Result<_, _>>()?;
+                Ok(Backend::Fixed(values))
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for Backend {
+    type Error = BackendError;
+
+    fn try_from(text: String) -> Result<Backend, BackendError> {
+        text.parse()
+    }
+}
+
+impl From<Backend> for String {
+    fn from(backend: Backend) -> String {
+        backend.to_string()
+    }
+}
+
+pub enum Source {
+    ChaCha(ChaCha8Rng),
+    Pcg(Pcg64),
+    Xoshiro(Xoshiro256PlusPlus),
+    // secrets are the values in turn, over and over
+    Fixed { values: Vec<u64>, next: usize, rest: ChaCha8Rng },
+}
+
+impl Source {
+    // A secret in low..=high. A fixed list gives its values as they are
+    // when they fit the range, and wraps the others into it.
+    pub fn secret(&mut self, low: u32, high: u32) -> u32 {
+        match self {
+            Source::Fixed { values, next, .. } => {
+                let value = values[*next % values.len()];
+                *next += 1;
+                if (u64::from(low)..=u64::from(high)).contains(&value) {
+                    value as u32
+                } else {
+                    low + (value % (u64::from(high - low) + 1)) as u32
+                }
+            }
+            _ => self.gen_range(low..=high),
+        }
+    }
+}
+
+impl RngCore for Source {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Source::ChaCha(rng) => rng.next_u32(),
+            Source::Pcg(rng) => rng.next_u32(),
+            Source::Xoshiro(rng) => rng.next_u32(),
+            Source::Fixed { rest, .. } => rest.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Source::ChaCha(rng) => rng.next_u64(),
+            Source::Pcg(rng) => rng.next_u64(),
+            Source::Xoshiro(rng) => rng.next_u64(),
+            Source::Fixed { rest, .. } => rest.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Source::ChaCha(rng) => rng.fill_bytes(dest),
+            Source::Pcg(rng) => rng.fill_bytes(dest),
+            Source::Xoshiro(rng) => rng.fill_bytes(dest),
+            Source::Fixed { rest, .. } => rest.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::process;
+
+use crate::config;
+use crate::i18n::t;
+use crate::session::Session;
+use crate::{guess_number, seeded_secret};
+
+// Without --seed a batch still plays a known secret, so the same script
+// always prints the same transcript.
+const DEFAULT_SEED: u64 = 0;
+
+// batch [--input FILE] [ATTEMPTS]: the classic game fed from a script of
+// guesses, one per line, or from stdin. Nothing is prompted, saved or
+// recorded; the transcript goes to stdout and the exit status tells how
+// the round ended: 0 won, 1 lost, 3 the guesses ran out first.
+pub fn run(input: Option<&Path>, attempts: Option<u32>) {
+    let file = input.map(|path| match File::open(path) {
+        Ok(file) => BufReader::new(file),
+        Err(error) => {
+            eprintln!("{}", t!("batch-cannot-read", path = path.display(), error = error));
+            process::exit(2);
+        }
+    });
+
+    let settings = config::get();
+    let (low, high) = (settings.range.low, settings.range.high);
+    let seed = settings.seed.unwrap_or(DEFAULT_SEED);
+    crate::announce(seed);
+    // with dice the host types the secret on stdin, before stdin is held
+    // for the guesses
+    let secret = seeded_secret(seed, low, high);
+
+    let lines: Box<dyn BufRead> = match file {
+        Some(file) => Box::new(file),
+        None => Box::new(io::stdin().lock()),
+    };
+    let mut lines = lines.lines();
+
+    println!("{}", t!("guess-between", low = low, high = high));
+
+    let mut session = Session::new(attempts.or(settings.attempts));
+    let mut next_input = || lines.next().map(|line| line.expect("Failed to read line"));
+    let outcomes = guess_number(secret, &mut session, &mut next_input, &mut |_| {});
+
+    if outcomes.last() == Some(&Ordering::Equal) {
+        println!("{}", t!("you-win-in", count = session.attempts()));
+        process::exit(0);
+    }
+    if session.is_over() {
+        process::exit(1);
+    }
+    println!("{}", t!("input-ended"));
+    process::exit(3);
+}
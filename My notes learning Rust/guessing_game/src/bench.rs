@@ -0,0 +1,135 @@
+use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+use rand::Rng;
+use rand::seq::index;
+use serde_json::json;
+
+use crate::config::{self, Format};
+use crate::grid::{self, Feedback};
+use crate::i18n::t;
+use crate::code::{self, Solver};
+use crate::{hidden, judge, reverse};
+
+// Knuth's solver looks at every code for every guess, so it only plays a
+// handful of games however many were asked for.
+const MASTERMIND_GAMES: u32 = 5;
+// How many secrets the multi solver looks for among 1..=100.
+const MULTI_SECRETS: usize = 3;
+
+struct Run {
+    solver: &'static str,
+    games: u32,
+    guesses: Vec<u32>,
+    time: Duration,
+}
+
+// Bisects the configured range, the way reverse mode guesses.
+fn classic(low: u32, high: u32, secret: u32) -> u32 {
+    let (mut low, mut high) = (low, high);
+    let mut guesses = 0;
+
+    loop {
+        let guess = reverse::next_guess(low, high);
+        guesses += 1;
+        match judge(guess, secret) {
+            Ordering::Less => low = guess + 1,
+            Ordering::Greater => high = guess - 1,
+            Ordering::Equal => return guesses,
+        }
+    }
+}
+
+fn mastermind_game(config: &code::Config, secret: &[u8]) -> u32 {
+    let mut solver = Solver::new(config);
+
+    for attempt in 1.. {
+        let guess = solver.next_guess();
+        let result = code::score(&guess, secret);
+        if result.0 as usize == config.length {
+            return attempt;
+        }
+        solver.record(&guess, result);
+    }
+    unreachable!()
+}
+
+fn time(solver: &'static str, games: u32, mut play: impl FnMut() -> u32) -> Run {
+    let started = Instant::now();
+    let guesses = (0..games).map(|_| play()).collect();
+    Run { solver, games, guesses, time: started.elapsed() }
+}
+
+// bench [--games N]: plays every solver on random secrets drawn from one
+// seeded generator, so --seed gives the same games again.
+pub fn run(games: u32) {
+    let games = games.max(1);
+    let range = config::get().range;
+    let mut rng = crate::rng_for("bench");
+
+    let classic_secrets: Vec<u32> = (0..games).map(|_| rng.gen_range(range.low..=range.high)).collect();
+    let cells: Vec<(u32, u32)> = (0..games).map(|_| (rng.gen_range(1..=10), rng.gen_range(1..=10))).collect();
+    let hidden_sets: Vec<Vec<u32>> = (0..games)
+        .map(|_| {
+            let mut secrets: Vec<u32> = index::sample(&mut rng, 100, MULTI_SECRETS).into_iter().map(|i| i as u32 + 1).collect();
+            secrets.sort_unstable();
+            secrets
+        })
+        .collect();
+    let codes = code::Config { length: 4, symbols: 6, repeats: true, colours: false };
+
+    let mut secrets = classic_secrets.iter();
+    let mut cell = cells.iter();
+    let mut sets = hidden_sets.iter();
+    let results = [
+        time("classic", games, || classic(range.low, range.high, *secrets.next().unwrap())),
+        time("grid", games, || grid::solve(10, 10, Feedback::Direction, *cell.next().unwrap())),
+        time("multi", games, || hidden::solve(sets.next().unwrap(), 1, 100)),
+        time("mastermind", games.min(MASTERMIND_GAMES), || {
+            mastermind_game(&codes, &codes.random_code(&mut rng))
+        }),
+    ];
+
+    if config::get().format == Format::Json {
+        let results: Vec<_> = results
+            .iter()
+            .map(|result| {
+                json!({
+                    "solver": result.solver,
+                    "games": result.games,
+                    "average_guesses": average(&result.guesses),
+                    "worst_guesses": result.guesses.iter().max(),
+                    "microseconds_per_game": per_game(result),
+                })
+            })
+            .collect();
+        println!("{}", json!(results));
+        return;
+    }
+
+    println!(
+        "{:<12} {:>6} {:>8} {:>6} {:>12}",
+        t!("column-solver"),
+        t!("column-games"),
+        t!("column-average"),
+        t!("column-worst"),
+        t!("column-per-game")
+    );
+    for result in &results {
+        println!(
+            "{:<12} {:>6} {:>8.2} {:>6} {:>10.1}µs",
+            result.solver,
+            result.games,
+            average(&result.guesses),
+            result.guesses.iter().max().unwrap_or(&0),
+            per_game(result)
+        );
+    }
+}
+
+fn average(guesses: &[u32]) -> f64 {
+    guesses.iter().sum::<u32>() as f64 / guesses.len().max(1) as f64
+}
+
+fn per_game(result: &Run) -> f64 {
+    result.time.as_secs_f64() * 1e6 / result.games as f64
+}
@@ -0,0 +1,414 @@
+use std::path::PathBuf;
+use std::time::Duration;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+// The whole command line. It uses nothing else from the crate so build.rs
+// can include it to write the shell completions and the man page.
+#[derive(Parser)]
+#[command(
+    name = "guessing_game",
+    version,
+    about = "Guess the number, and a few other guessing games.",
+    after_help = "Without a command, plays the classic game."
+)]
+pub struct Cli {
+    #[command(flatten)]
+    pub settings: Settings,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+// Flags every command takes. They win over the config file.
+#[derive(Args)]
+pub struct Settings {
+    #[arg(long, global = true, value_name = "FILE", help = "Read this config file instead of the default one")]
+    pub config: Option<PathBuf>,
+
+    #[arg(long, global = true, help = "Seed the secrets, the same seed plays the same game")]
+    pub seed: Option<u64>,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "BACKEND",
+        help = "Where secrets come from: chacha, pcg, xoshiro, dice (the host types it) or fixed:N,N,..."
+    )]
+    pub rng: Option<String>,
+
+    #[arg(long, global = true, value_name = "LOW-HIGH", help = "Range of the secret number, like 1-100")]
+    pub range: Option<String>,
+
+    #[arg(long, global = true, value_parser = ["text", "json"], help = "How reports are printed")]
+    pub format: Option<String>,
+
+    #[arg(long, global = true, value_name = "LANG", help = "Language of the messages: en, fr or ar")]
+    pub lang: Option<String>,
+
+    #[arg(long, global = true, value_name = "NAME", help = "Name scores and ratings are kept under")]
+    pub player: Option<String>,
+
+    #[arg(
+        long,
+        visible_alias = "color",
+        global = true,
+        value_parser = ["auto", "always", "never"],
+        help = "When to paint Wordle letters"
+    )]
+    pub colour: Option<String>,
+}
+
+impl Settings {
+    // The flags that were given, as config::Config::apply takes them.
+    pub fn flags(&self) -> Vec<(&'static str, String)> {
+        let mut flags = Vec::new();
+        let text = [
+            ("--range", &self.range),
+            ("--rng", &self.rng),
+            ("--format", &self.format),
+            ("--lang", &self.lang),
+            ("--player", &self.player),
+            ("--colour", &self.colour),
+        ];
+        for (flag, value) in text {
+            if let Some(value) = value {
+                flags.push((flag, value.clone()));
+            }
+        }
+        if let Some(seed) = self.seed {
+            flags.push(("--seed", seed.to_string()));
+        }
+        flags
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    #[command(about = "Play the classic game")]
+    Play {
+        #[arg(help = "Attempts before the game is lost, no limit by default")]
+        attempts: Option<u32>,
+
+        #[arg(long, help = "Keep the secret readable in the save file")]
+        unsealed: bool,
+    },
+
+    #[command(
+        about = "Play the classic game from a script of guesses, without prompts",
+        after_help = "Exits with 0 when the script wins, 1 when it loses and 3 when the guesses run out first. \
+                      Without --seed the secret comes from seed 0."
+    )]
+    Batch {
+        #[arg(long, value_name = "FILE", help = "Guesses to play, one per line, stdin when left out")]
+        input: Option<PathBuf>,
+
+        #[arg(help = "Attempts before the game is lost, no limit by default")]
+        attempts: Option<u32>,
+    },
+
+    #[command(about = "Team game: one secret, one pool of attempts, a slice of the range each")]
+    Coop {
+        #[arg(required = true, num_args = 2.., help = "Players, in turn order")]
+        players: Vec<String>,
+
+        #[arg(long, help = "Attempts the whole team gets, enough for a binary search plus one per player by default")]
+        attempts: Option<u32>,
+    },
+
+    #[command(about = "Every guess has a price, get the number for as little as you can")]
+    Cost {
+        #[arg(long, value_enum, default_value_t = CostKind::Value, help = "What a guess costs")]
+        cost: CostKind,
+
+        #[arg(long, default_value_t = 3, help = "Extra cost of a guess that is too high, with --cost over")]
+        penalty: u32,
+
+        #[arg(long, help = "Watch the best strategy play")]
+        solve: bool,
+    },
+
+    #[command(about = "The number moves a little after every wrong guess")]
+    Drift {
+        #[arg(long, default_value_t = 3, help = "Largest step the number takes after a wrong guess")]
+        step: u32,
+
+        #[arg(long, value_enum, default_value_t = DriftKind::Uniform, help = "How the steps are drawn")]
+        drift: DriftKind,
+
+        #[arg(long, help = "Attempts before the game is lost, no limit by default")]
+        attempts: Option<u32>,
+
+        #[arg(long, help = "Compare the tracking solver with plain bisection instead of playing")]
+        simulate: bool,
+
+        #[arg(long, default_value_t = 1000, help = "Games per solver with --simulate")]
+        games: u32,
+    },
+
+    #[command(about = "Name the distribution the secrets are drawn from, one draw at a time")]
+    Infer {
+        #[arg(long, default_value_t = 50, help = "Draws you may see")]
+        rounds: u32,
+
+        #[arg(long, help = "Watch the likelihoods decide")]
+        solve: bool,
+    },
+
+    #[command(about = "Several different secrets at once, each guess counts those below and above")]
+    Multi {
+        #[arg(long, default_value_t = 3, help = "How many secrets are hidden")]
+        count: usize,
+
+        #[arg(long, help = "Watch the solver find them")]
+        solve: bool,
+
+        #[arg(long, help = "Attempts before the game is lost, no limit by default")]
+        attempts: Option<u32>,
+    },
+
+    #[command(about = "The secret follows a distribution you are shown, not all numbers are as likely")]
+    Skewed {
+        #[arg(long, value_enum, default_value_t = Shape::Normal, help = "Shape the secret is drawn from")]
+        distribution: Shape,
+
+        #[arg(long, value_name = "FILE", help = "Draw from weights instead, one \"number weight\" pair per line")]
+        weights: Option<PathBuf>,
+
+        #[arg(long, value_enum, value_name = "SOLVER", help = "Watch a solver play")]
+        solve: Option<SkewedSolver>,
+
+        #[arg(long, help = "Attempts before the game is lost, no limit by default")]
+        attempts: Option<u32>,
+
+        #[arg(long, help = "Compare the solvers' average guesses instead of playing")]
+        simulate: bool,
+    },
+
+    #[command(about = "Find the hidden order of a few items by comparing them two at a time")]
+    Sort {
+        #[arg(long, default_value_t = 5, help = "Items to sort, up to 26")]
+        items: usize,
+
+        #[arg(long, value_enum, help = "Watch a sorting bot do it")]
+        bot: Option<SortBot>,
+
+        #[arg(long, help = "Compare the bots with the lower bound instead of playing")]
+        simulate: bool,
+
+        #[arg(long, default_value_t = 1000, help = "Hidden orders per bot with --simulate")]
+        games: u32,
+    },
+
+    #[command(about = "Twenty questions: ask yes/no questions about the number instead of guessing")]
+    Twenty {
+        #[arg(long, default_value_t = 20, help = "Questions you may ask, the winning one included")]
+        questions: u32,
+
+        #[arg(long, help = "Watch the questioner pick the most telling questions")]
+        solve: bool,
+    },
+
+    #[command(about = "You pick the number, the computer guesses it")]
+    Reverse,
+
+    #[command(about = "Today's puzzle, the same for everyone")]
+    Daily,
+
+    #[command(about = "Wins and attempts over your recorded games")]
+    Stats,
+
+    #[command(about = "Play a recorded game again, guess by guess")]
+    Replay {
+        #[arg(default_value_t = 1, help = "Which game, 1 being the most recent")]
+        game: usize,
+    },
+
+    #[command(about = "Host the classic game over TCP, one game per connection")]
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:7878", help = "Address to listen on")]
+        address: String,
+    },
+
+    #[command(about = "Run the solvers on random secrets and time them")]
+    Bench {
+        #[arg(long, default_value_t = 1000, help = "Games per solver")]
+        games: u32,
+    },
+
+    #[command(
+        about = "Check that secrets are drawn uniformly, with chi-square and Kolmogorov-Smirnov tests",
+        after_help = "Exits with 1 when any range fails. A fair generator still fails now and then, \
+                      pass --seed for a repeatable run."
+    )]
+    Fairness {
+        #[arg(long, default_value_t = 100_000, help = "Secrets drawn per range")]
+        samples: usize,
+    },
+
+    #[command(about = "Continue a saved game")]
+    Resume {
+        #[arg(help = "Name of the save, the most recent one by default")]
+        name: Option<String>,
+    },
+
+    #[command(about = "Range and attempts picked from your rating")]
+    Adaptive,
+
+    #[command(about = "Find the hidden cell on a grid")]
+    Grid {
+        #[arg(long, help = "Let the solver play every cell and compare it with bisecting each axis")]
+        solve: bool,
+
+        #[arg(default_value_t = 10)]
+        width: u32,
+
+        #[arg(default_value_t = 10)]
+        height: u32,
+
+        #[arg(long, value_enum, default_value_t = GridHint::Direction, help = "How the host answers")]
+        hint: GridHint,
+    },
+
+    #[command(about = "Break a code of digits or colours")]
+    Mastermind {
+        #[arg(long, help = "Watch the solver crack a random code")]
+        solve: bool,
+
+        #[arg(long, default_value_t = 4)]
+        length: usize,
+
+        #[arg(long, default_value_t = 6)]
+        symbols: usize,
+
+        #[arg(long, default_value_t = 10)]
+        attempts: u32,
+
+        #[arg(long, help = "No symbol appears twice")]
+        unique: bool,
+
+        #[arg(long, visible_alias = "colors", help = "Colours instead of digits")]
+        colours: bool,
+    },
+
+    #[command(about = "Guess the five letter word")]
+    Wordle {
+        #[arg(long, help = "Revealed hints must be used in later guesses")]
+        hard: bool,
+    },
+
+    #[command(about = "Play against the clock")]
+    Timed {
+        #[arg(value_enum, default_value_t = TimedMode::Countdown)]
+        mode: TimedMode,
+
+        #[arg(value_parser = seconds, help = "Seconds per guess, per game or for the marathon")]
+        seconds: Option<Duration>,
+    },
+
+    #[command(about = "Check the signatures and transcripts of recorded scores")]
+    Verify,
+
+    #[command(about = "Best results and history from the local leaderboard")]
+    Leaderboard {
+        #[command(subcommand)]
+        command: Option<LeaderboardCommand>,
+    },
+
+    #[command(about = "List the achievements and which ones are unlocked")]
+    Achievements,
+
+    #[command(about = "Inspect the configuration")]
+    Config {
+        #[command(subcommand)]
+        command: Option<ConfigCommand>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum GridHint {
+    Direction,
+    Distance,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum CostKind {
+    Unit,
+    Value,
+    Over,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum DriftKind {
+    Uniform,
+    Triangular,
+    Lazy,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Shape {
+    Uniform,
+    Normal,
+    Geometric,
+    Zipf,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum SkewedSolver {
+    Bisection,
+    Median,
+    Tree,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum SortBot {
+    MergeInsertion,
+    Merge,
+    Insertion,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum TimedMode {
+    Countdown,
+    Total,
+    Marathon,
+}
+
+#[derive(Subcommand)]
+pub enum LeaderboardCommand {
+    #[command(about = "Fewest attempts on one difficulty")]
+    Top {
+        #[arg(default_value = "normal")]
+        difficulty: String,
+
+        #[arg(default_value_t = 10)]
+        limit: u32,
+
+        #[arg(default_value = "all", value_parser = ["daily", "weekly", "all"])]
+        window: String,
+    },
+
+    #[command(about = "Every game of one player")]
+    History { player: Option<String> },
+
+    #[command(about = "Add the games of a score file")]
+    Import { file: Option<PathBuf> },
+
+    #[command(about = "Write every game as CSV")]
+    Export { file: Option<PathBuf> },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    #[command(about = "Print the configuration in effect, file and flags merged")]
+    Show,
+}
+
+// A time limit in seconds, like 10 or 2.5. Zero, negative and endless
+// limits are refused before a game starts.
+fn seconds(text: &str) -> Result<Duration, String> {
+    let seconds: f64 = text.parse().map_err(|_| format!("{text} is not a number of seconds"))?;
+    if seconds.is_nan() || seconds <= 0.0 {
+        return Err(format!("the time limit has to be more than 0 seconds, not {text}"));
+    }
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("{text} seconds is too long"))
+}
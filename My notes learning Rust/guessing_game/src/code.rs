@@ -0,0 +1,169 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+// Codes for mastermind: reading and showing them, scoring a guess against
+// the secret, and Knuth's solver.
+
+const DIGITS: [char; 9] = ['1', '2', '3', '4', '5', '6', '7', '8', '9'];
+const COLOURS: [char; 9] = ['R', 'G', 'B', 'Y', 'O', 'P', 'C', 'W', 'K'];
+
+// Above this many possible codes the minimax search gets too slow, so the
+// solver just plays the first code that is still consistent.
+pub const MINIMAX_LIMIT: usize = 2000;
+
+// Codes are 1 to 6 symbols long, drawn from 2 to 9 digits or colours; past
+// that the solver has too many codes to look through.
+pub const MAX_LENGTH: usize = 6;
+pub const MIN_SYMBOLS: usize = 2;
+pub const MAX_SYMBOLS: usize = DIGITS.len();
+
+// A code is a list of symbol numbers, 0 being the first digit or colour.
+pub type Code = Vec<u8>;
+
+pub struct Config {
+    pub length: usize,
+    pub symbols: usize,
+    pub repeats: bool,
+    pub colours: bool,
+}
+
+impl Config {
+    pub fn alphabet(&self) -> &'static [char] {
+        if self.colours { &COLOURS } else { &DIGITS }
+    }
+
+    pub fn show(&self, code: &[u8]) -> String {
+        code.iter().map(|&symbol| self.alphabet()[symbol as usize]).collect()
+    }
+
+    pub fn parse(&self, input: &str) -> Option<Code> {
+        let code: Code = input
+            .trim()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| {
+                let c = c.to_ascii_uppercase();
+                self.alphabet()[..self.symbols]
+                    .iter()
+                    .position(|&symbol| symbol == c)
+                    .map(|symbol| symbol as u8)
+            })
+            .collect::<Option<Code>>()?;
+
+        if code.len() != self.length || (!self.repeats && has_repeats(&code)) {
+            return None;
+        }
+        Some(code)
+    }
+
+    pub fn random_code(&self, rng: &mut impl Rng) -> Code {
+        if self.repeats {
+            (0..self.length).map(|_| rng.gen_range(0..self.symbols) as u8).collect()
+        } else {
+            let mut symbols: Code = (0..self.symbols as u8).collect();
+            symbols.shuffle(rng);
+            symbols.truncate(self.length);
+            symbols
+        }
+    }
+
+    // Every code the host could have picked, in counting order.
+    pub fn all_codes(&self) -> Vec<Code> {
+        let total = self.symbols.pow(self.length as u32);
+
+        (0..total)
+            .map(|mut n| {
+                let mut code = vec![0; self.length];
+                for slot in code.iter_mut().rev() {
+                    *slot = (n % self.symbols) as u8;
+                    n /= self.symbols;
+                }
+                code
+            })
+            .filter(|code| self.repeats || !has_repeats(code))
+            .collect()
+    }
+}
+
+fn has_repeats(code: &[u8]) -> bool {
+    code.iter()
+        .enumerate()
+        .any(|(i, symbol)| code[i + 1..].contains(symbol))
+}
+
+// (exact, partial): right symbol in the right place, right symbol elsewhere.
+// Repeated symbols only count as many times as they appear in both codes.
+pub fn score(guess: &[u8], secret: &[u8]) -> (u32, u32) {
+    let exact = guess.iter().zip(secret).filter(|(g, s)| g == s).count() as u32;
+
+    let mut guess_counts = [0u32; 9];
+    let mut secret_counts = [0u32; 9];
+    for (&g, &s) in guess.iter().zip(secret) {
+        guess_counts[g as usize] += 1;
+        secret_counts[s as usize] += 1;
+    }
+    let common: u32 = guess_counts
+        .iter()
+        .zip(secret_counts)
+        .map(|(&g, s)| g.min(s))
+        .sum();
+
+    (exact, common - exact)
+}
+
+// Knuth's minimax strategy: play the code whose worst score leaves the
+// fewest candidates, preferring codes that could still be the secret.
+// With more than MINIMAX_LIMIT codes it plays the first candidate left
+// instead, which always wins but may take many more guesses.
+#[derive(Clone)]
+pub struct Solver {
+    all: Vec<Code>,
+    candidates: Vec<Code>,
+}
+
+impl Solver {
+    pub fn new(config: &Config) -> Solver {
+        let all = config.all_codes();
+        Solver { candidates: all.clone(), all }
+    }
+
+    pub fn candidates(&self) -> usize {
+        self.candidates.len()
+    }
+
+    pub fn next_guess(&self) -> Code {
+        if self.candidates.len() == 1 || self.all.len() > MINIMAX_LIMIT {
+            return self.candidates[0].clone();
+        }
+
+        let mut best: Option<(&Code, usize, bool)> = None;
+        for guess in &self.all {
+            let worst = self.worst_split(guess);
+            let is_candidate = self.candidates.contains(guess);
+            let better = match best {
+                None => true,
+                Some((_, best_worst, best_is_candidate)) => {
+                    worst < best_worst || (worst == best_worst && is_candidate && !best_is_candidate)
+                }
+            };
+            if better {
+                best = Some((guess, worst, is_candidate));
+            }
+        }
+        best.map(|(guess, _, _)| guess.clone()).unwrap_or_default()
+    }
+
+    fn worst_split(&self, guess: &[u8]) -> usize {
+        let length = guess.len();
+        let mut groups = vec![0usize; (length + 1) * (length + 1)];
+        for candidate in &self.candidates {
+            let (exact, partial) = score(guess, candidate);
+            groups[exact as usize * (length + 1) + partial as usize] += 1;
+        }
+        groups.into_iter().max().unwrap_or(0)
+    }
+
+    pub fn record(&mut self, guess: &[u8], result: (u32, u32)) {
+        self.candidates.retain(|candidate| score(guess, candidate) == result);
+    }
+}
@@ -0,0 +1,261 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::OnceLock;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::backend::{Backend, BackendError};
+use crate::i18n::t;
+use crate::{i18n, scores, storage};
+
+const CONFIG_FILE: &str = "config.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// When to paint Wordle letters. Auto paints only on a terminal and
+// leaves NO_COLOR alone.
+#[derive(Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Colour {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+// How reports like the leaderboard are printed.
+#[derive(Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+// range = [1, 100] in the file. Checked while reading, so a bad range is
+// reported at its line and column like any other mistake.
+#[derive(Clone, Copy, Deserialize, Serialize)]
+#[serde(try_from = "[u32; 2]", into = "[u32; 2]")]
+pub struct Range {
+    pub low: u32,
+    pub high: u32,
+}
+
+impl TryFrom<[u32; 2]> for Range {
+    type Error = String;
+
+    fn try_from([low, high]: [u32; 2]) -> Result<Range, String> {
+        if low > high {
+            return Err(t!("range-empty", low = low, high = high));
+        }
+        if low == high {
+            return Err(t!("range-single", low = low, high = high));
+        }
+        // the modes count the numbers of the range in a u32
+        if high - low == u32::MAX {
+            return Err(t!("range-too-wide", low = low, high = high, max = u32::MAX));
+        }
+        Ok(Range { low, high })
+    }
+}
+
+impl From<Range> for [u32; 2] {
+    fn from(range: Range) -> [u32; 2] {
+        [range.low, range.high]
+    }
+}
+
+// How many attempts a hint takes away, per mode.
+#[derive(Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Hints {
+    pub wordle: u32,
+    pub mastermind: u32,
+}
+
+impl Default for Hints {
+    fn default() -> Hints {
+        Hints { wordle: 1, mastermind: 1 }
+    }
+}
+
+// Everything a config.toml can set. Missing keys keep their default.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub range: Range,
+    // no limit when left out
+    pub attempts: Option<u32>,
+    pub colour: Colour,
+    // from LANG when left out
+    pub language: Option<String>,
+    // from USER when left out
+    pub player: Option<String>,
+    pub format: Format,
+    pub hints: Hints,
+    // the same seed plays the same secrets, random when left out
+    pub seed: Option<u64>,
+    // chacha, pcg, xoshiro, dice or fixed:N,N,...
+    #[serde(deserialize_with = "backend")]
+    pub rng: Backend,
+    // the file this was read from, None for the built-in defaults
+    #[serde(skip)]
+    pub source: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            range: Range { low: 1, high: 100 },
+            attempts: None,
+            colour: Colour::Auto,
+            language: None,
+            player: None,
+            format: Format::Text,
+            hints: Hints::default(),
+            seed: None,
+            rng: Backend::ChaCha,
+            source: None,
+        }
+    }
+}
+
+// Reads rng = "..." with the reason a name is refused in the player's
+// language.
+fn backend<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Backend, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    text.parse().map_err(|error| serde::de::Error::custom(backend_error(&text, &error)))
+}
+
+fn backend_error(text: &str, error: &BackendError) -> String {
+    match error {
+        BackendError::Unknown(_) => t!("config-unknown-rng", rng = text),
+        BackendError::NotANumber(value) => t!("config-rng-not-a-number", value = value, rng = text),
+    }
+}
+
+pub fn path() -> PathBuf {
+    storage::config_dir().join(CONFIG_FILE)
+}
+
+// 1-based line and column of a byte offset, the way editors count them.
+fn position(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+    (line, column)
+}
+
+pub fn parse(text: &str) -> Result<Config, String> {
+    toml::from_str(text).map_err(|error| {
+        let message = error.message().trim_end();
+        match error.span() {
+            Some(span) => {
+                let (line, column) = position(text, span.start);
+                format!("{line}:{column}: {message}")
+            }
+            None => message.to_string(),
+        }
+    })
+}
+
+// The file given with --config, or config.toml in the config directory.
+// Only a file the player named has to exist.
+pub fn load(given: Option<&Path>) -> Result<Config, String> {
+    let path = given.map(Path::to_path_buf).unwrap_or_else(path);
+
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(_) if given.is_none() => return Ok(Config::default()),
+        Err(error) => return Err(t!("config-cannot-read", path = path.display(), error = error)),
+    };
+    let mut config = parse(&text).map_err(|error| format!("{}:{error}", path.display()))?;
+    config.source = Some(path);
+    Ok(config)
+}
+
+// Only the language the config file asks for, read before the rest so
+// that the mistakes in it are reported in that language. None when there
+// is no such file or it cannot be read at all.
+pub fn language(given: Option<&Path>) -> Option<String> {
+    #[derive(Deserialize)]
+    struct Language {
+        language: Option<String>,
+    }
+
+    let text = fs::read_to_string(given.map(Path::to_path_buf).unwrap_or_else(path)).ok()?;
+    toml::from_str::<Language>(&text).ok()?.language
+}
+
+impl Config {
+    // Command line flags win over the file.
+    pub fn apply(&mut self, flag: &str, value: &str) -> Result<(), String> {
+        let bad = || t!("config-bad-value", value = value, flag = flag);
+
+        match flag {
+            "--lang" => self.language = Some(value.to_string()),
+            "--player" => self.player = Some(value.to_string()),
+            "--seed" => self.seed = Some(value.parse().map_err(|_| bad())?),
+            "--rng" => self.rng = value.parse().map_err(|error| backend_error(value, &error))?,
+            "--format" => {
+                self.format = match value {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(bad()),
+                }
+            }
+            "--colour" => {
+                self.colour = match value {
+                    "auto" => Colour::Auto,
+                    "always" => Colour::Always,
+                    "never" => Colour::Never,
+                    _ => return Err(bad()),
+                }
+            }
+            "--range" => {
+                let (low, high) = value.split_once('-').ok_or_else(bad)?;
+                let low = low.trim().parse().map_err(|_| bad())?;
+                let high = high.trim().parse().map_err(|_| bad())?;
+                self.range = Range::try_from([low, high])?;
+            }
+            _ => return Err(t!("config-unknown-flag", flag = flag)),
+        }
+        Ok(())
+    }
+}
+
+pub fn init(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+// Prints a config problem and stops, nothing sensible can run without it.
+// A command that cannot run with a valid config uses usage_error instead.
+pub fn fail(error: &str) -> ! {
+    eprintln!("{}", t!("config-invalid", error = error));
+    process::exit(2);
+}
+
+// config show: the file merged with the flags, with the language and
+// player filled in the way the game resolved them.
+pub fn show() {
+    let mut config = get().clone();
+    config.language = Some(i18n::language().to_string());
+    config.player = Some(scores::player_name());
+
+    match config.format {
+        Format::Text => {
+            match &config.source {
+                Some(path) => println!("# {}", path.display()),
+                None => println!("# defaults, {} does not exist", path().display()),
+            }
+            print!("{}", toml::to_string(&config).expect("Failed to write the configuration"));
+        }
+        Format::Json => {
+            println!("{}", serde_json::to_string_pretty(&config).expect("Failed to write the configuration"));
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+
+use crate::i18n::t;
+use crate::scores::{self, Score};
+use crate::session::Session;
+use crate::{bits, config, judge, leaderboard, new_seed, parse, read_input, seeded_secret};
+
+// One member of the team and the part of the range only they may guess in.
+struct Player {
+    name: String,
+    low: u32,
+    high: u32,
+}
+
+// Cuts low..=high into one slice per player, in turn order. The first
+// slices take the leftover numbers, so sizes differ by one at most.
+fn split(names: &[String], low: u32, high: u32) -> Vec<Player> {
+    // in u64, as 0..=u32::MAX holds one number more than u32 counts
+    let size = u64::from(high - low) + 1;
+    let count = names.len() as u64;
+    let mut start = u64::from(low);
+
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let width = size / count + u64::from((i as u64) < size % count);
+            let player = Player { name: name.clone(), low: start as u32, high: (start + width - 1) as u32 };
+            start += width;
+            player
+        })
+        .collect()
+}
+
+// What a player typed on their turn.
+enum Move {
+    Guess(u32),
+    Pass,
+    // a line for the team, like "> try 40?", that does not end the turn
+    Note(String),
+}
+
+fn read_move(input: &str) -> Result<Move, parse::ParseError> {
+    let input = input.trim();
+    if let Some(note) = input.strip_prefix('>') {
+        return Ok(Move::Note(note.trim().to_string()));
+    }
+    match input.to_lowercase().as_str() {
+        "pass" | "passe" | "تمرير" => Ok(Move::Pass),
+        _ => parse::guess(input).map(Move::Guess),
+    }
+}
+
+// Enough for a binary search over the range plus one slip per player.
+fn default_attempts(low: u32, high: u32, players: usize) -> u32 {
+    let numbers = u64::from(high - low) + 1;
+    bits(numbers + 1) + players as u32
+}
+
+// coop PLAYER PLAYER... [--attempts N]: the team shares one secret and
+// one pool of attempts. Turns go round the table; each player may only
+// guess in their own slice of the range or pass, and may leave notes for
+// the others first. The result is recorded once, under the team's name.
+pub fn run(names: &[String], attempts: Option<u32>) {
+    let settings = config::get();
+    let (low, high) = (settings.range.low, settings.range.high);
+    if names.len() as u64 > u64::from(high - low) + 1 {
+        crate::usage_error(&t!("coop-too-many", count = names.len(), low = low, high = high));
+    }
+
+    let players = split(names, low, high);
+    let team = names.join("+");
+    let seed = new_seed();
+    let secret = seeded_secret(seed, low, high);
+    let max_attempts = attempts.or(settings.attempts).unwrap_or_else(|| default_attempts(low, high, names.len()));
+
+    println!("{}", t!("coop-intro", count = players.len(), low = low, high = high));
+    for player in &players {
+        println!("{}", t!("coop-range", player = player.name, low = player.low, high = player.high));
+    }
+    println!("{}", t!("attempts-available", count = max_attempts));
+
+    let mut session = Session::new(Some(max_attempts));
+    let mut guesses = Vec::new();
+    let mut passes = 0;
+    let mut won = false;
+
+    'game: for player in players.iter().cycle() {
+        if session.is_over() {
+            println!("{}", t!("you-lose", secret = secret));
+            break;
+        }
+        if passes == players.len() {
+            println!("{}", t!("coop-everyone-passed"));
+            passes = 0;
+        }
+
+        let left = session.left().unwrap_or_default();
+        println!("{}", t!("coop-turn", player = player.name, low = player.low, high = player.high, count = left));
+
+        let guess = loop {
+            let Some(input) = read_input() else { break 'game };
+            match read_move(&input) {
+                Ok(Move::Note(note)) => println!("{}", t!("coop-note", player = player.name, note = note)),
+                Ok(Move::Pass) => {
+                    println!("{}", t!("coop-pass", player = player.name));
+                    passes += 1;
+                    continue 'game;
+                }
+                Ok(Move::Guess(guess)) if guess < player.low || guess > player.high => {
+                    println!("{}", t!("coop-outside", guess = guess, low = player.low, high = player.high));
+                }
+                Ok(Move::Guess(guess)) => break guess,
+                Err(error) => println!("{}", crate::bad_guess(&error)),
+            }
+        };
+
+        passes = 0;
+        session.attempt();
+        guesses.push(guess);
+        println!("{}", t!("you-guessed", guess = guess));
+
+        match judge(guess, secret) {
+            Ordering::Less => println!("{}", t!("too-small")),
+            Ordering::Greater => println!("{}", t!("too-big")),
+            Ordering::Equal => {
+                println!("{}", t!("coop-win", player = player.name, count = session.attempts()));
+                won = true;
+                break;
+            }
+        }
+    }
+
+    // a team that walked away has nothing to record
+    if !won && !session.is_over() {
+        return;
+    }
+
+    let mut score = Score::new(&settings.rng, seed, low, high, Some(max_attempts), &guesses, won);
+    score.player = team;
+    scores::record(&score);
+    leaderboard::record(&score);
+}
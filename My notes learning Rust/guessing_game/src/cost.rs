@@ -0,0 +1,76 @@
+use std::cmp::Ordering;
+
+use crate::config;
+use crate::i18n::t;
+use crate::strategy::{Cost, Strategy};
+use crate::{ask, judge, parse};
+
+// The best strategy is worked out for every part of the range up front,
+// which takes a table of size²/2 entries.
+const MAX_SOLVED_RANGE: u32 = 5_000;
+
+fn describe(cost: Cost) -> String {
+    match cost {
+        Cost::Unit => t!("cost-unit"),
+        Cost::Value => t!("cost-value"),
+        Cost::Over(penalty) => t!("cost-over", penalty = penalty),
+    }
+}
+
+// cost [--cost unit|value|over] [--penalty N] [--solve]: guesses have a
+// price and the score is what they add up to, not how many there were. At
+// the end the player's total is set against the best strategy's.
+pub fn run(cost: Cost, solving: bool) {
+    let range = config::get().range;
+    let (low, high) = (range.low, range.high);
+    if high - low >= MAX_SOLVED_RANGE {
+        crate::usage_error(&t!("cost-too-wide", max = MAX_SOLVED_RANGE, low = low, high = high));
+    }
+    let secret = crate::draw_secret(&mut crate::rng(), low, high);
+    let strategy = Strategy::new(low, high, cost);
+
+    println!("{}", t!("guess-between", low = low, high = high));
+    println!("{}", describe(cost));
+
+    let (guesses, total) = if solving {
+        let (guesses, total) = strategy.play(secret);
+        let mut paid = 0;
+        for (i, &guess) in guesses.iter().enumerate() {
+            let price = cost.of(guess, judge(guess, secret));
+            paid += price;
+            println!("{}: {guess}   {}", i + 1, t!("cost-paid", cost = price, total = paid));
+        }
+        (guesses.len(), total)
+    } else {
+        let mut guesses = 0;
+        let mut total = 0;
+        loop {
+            let Some(input) = ask() else { return };
+            let guess = match parse::guess(&input) {
+                Ok(num) => num,
+                Err(error) => {
+                    println!("{}", crate::bad_guess(&error));
+                    continue;
+                }
+            };
+
+            guesses += 1;
+            let outcome = judge(guess, secret);
+            let price = cost.of(guess, outcome);
+            total += price;
+            println!("{}", t!("you-guessed", guess = guess));
+            match outcome {
+                Ordering::Less => println!("{}", t!("too-small")),
+                Ordering::Greater => println!("{}", t!("too-big")),
+                Ordering::Equal => {}
+            }
+            println!("{}", t!("cost-paid", cost = price, total = total));
+            if outcome == Ordering::Equal {
+                break (guesses, total);
+            }
+        }
+    };
+
+    println!("{}", t!("cost-win", count = guesses, total = total));
+    println!("{}", t!("cost-optimal", optimal = strategy.play(secret).1, worst = strategy.worst()));
+}
@@ -0,0 +1,155 @@
+use std::cmp::Ordering;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::achievements::{self, GameResult};
+use crate::analysis;
+use crate::{ask, bits, guess_number};
+use crate::i18n::t;
+use crate::scores;
+use crate::session::Session;
+use crate::storage;
+
+const UPPER_BOUNDS: [u32; 5] = [50, 100, 200, 500, 1000];
+const HISTORY_FILE: &str = "daily.txt";
+
+pub struct Puzzle {
+    pub date: String,
+    pub upper: u32,
+    pub secret: u32,
+    pub max_attempts: u32,
+}
+
+// Days since 1970-01-01 in UTC, the same number everywhere on Earth.
+pub fn today() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("The clock is set before 1970");
+    now.as_secs() / 86_400
+}
+
+// Turns a day number back into YYYY-MM-DD (Howard Hinnant's civil_from_days).
+pub fn date(day: u64) -> String {
+    let z = day as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let d = day_of_year - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = year_of_era + era * 400 + if m <= 2 { 1 } else { 0 };
+
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+// ChaCha8 gives the same numbers on every machine and rand version,
+// so seeding it with the day number gives everyone the same puzzle.
+pub fn puzzle(day: u64) -> Puzzle {
+    let mut rng = ChaCha8Rng::seed_from_u64(day);
+    let upper = UPPER_BOUNDS[rng.gen_range(0..UPPER_BOUNDS.len())];
+    let secret = rng.gen_range(1..=upper);
+    // enough for a careful binary search plus two mistakes
+    let max_attempts = bits(upper + 1) + 2;
+
+    Puzzle { date: date(day), upper, secret, max_attempts }
+}
+
+// Spoiler free: the arrows say where each guess pointed, never the numbers.
+pub fn share(puzzle: &Puzzle, outcomes: &[Ordering]) -> String {
+    let won = outcomes.last() == Some(&Ordering::Equal);
+    let attempts = if won { outcomes.len().to_string() } else { "X".to_string() };
+    let arrows: String = outcomes
+        .iter()
+        .map(|outcome| match outcome {
+            Ordering::Less => '⬆',
+            Ordering::Greater => '⬇',
+            Ordering::Equal => '✅',
+        })
+        .collect();
+
+    t!(
+        "daily-share",
+        date = puzzle.date,
+        range = format!("1-{}", puzzle.upper),
+        attempts = attempts,
+        max = puzzle.max_attempts,
+        arrows = arrows
+    )
+}
+
+// The share string of the day if the player already played it. Every
+// line is date, player and share; lines from before players were kept
+// have no player and count for everyone. The last line of a day wins.
+fn played(date: &str, player: &str) -> Option<String> {
+    let history = fs::read_to_string(storage::data_dir().join(HISTORY_FILE)).ok()?;
+
+    history
+        .lines()
+        .rev()
+        .filter_map(|line| line.strip_prefix(date)?.strip_prefix('\t'))
+        .filter_map(|rest| match rest.split_once('\t') {
+            Some((name, share)) => (name == player).then_some(share),
+            None => Some(rest),
+        })
+        .next()
+        .map(str::to_string)
+}
+
+fn record(date: &str, player: &str, share: &str) {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(storage::data_dir().join(HISTORY_FILE))
+        .expect("Failed to open the daily history");
+
+    writeln!(file, "{date}\t{player}\t{share}").expect("Failed to save the daily result");
+}
+
+pub fn run() {
+    let day = today();
+    let puzzle = puzzle(day);
+
+    let player = scores::player_name();
+    if let Some(share) = played(&puzzle.date, &player) {
+        println!("{}", t!("daily-already-played", date = puzzle.date));
+        println!("{share}");
+        return;
+    }
+
+    println!("{}", t!("daily-intro", date = puzzle.date, high = puzzle.upper));
+    println!("{}", t!("attempts-available", count = puzzle.max_attempts));
+
+    let mut session = Session::new(Some(puzzle.max_attempts));
+    let mut guesses = Vec::new();
+    let outcomes = guess_number(puzzle.secret, &mut session, &mut ask, &mut |guess| {
+        // the first guess uses up the day, so quitting and coming back
+        // cannot peek at the secret; until the game ends it counts as lost
+        if guesses.is_empty() {
+            record(&puzzle.date, &player, &share(&puzzle, &[]));
+        }
+        guesses.push(guess);
+    });
+
+    // no guess at all leaves the day open
+    if guesses.is_empty() {
+        return;
+    }
+
+    let share = share(&puzzle, &outcomes);
+    record(&puzzle.date, &player, &share);
+    println!();
+    println!("{share}");
+    analysis::print(1, puzzle.upper, puzzle.secret, &guesses);
+
+    achievements::announce(&GameResult {
+        mode: "daily",
+        won: outcomes.last() == Some(&Ordering::Equal),
+        attempts: session.attempts(),
+        range: puzzle.upper,
+        day: Some(day),
+    });
+}
@@ -0,0 +1,316 @@
+use std::cmp::Ordering;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde_json::json;
+
+use crate::config::{self, Format};
+use crate::i18n::t;
+use crate::session::Session;
+use crate::{judge, parse, read_input, reverse};
+
+// A simulated game that has not found the number by then counts as lost.
+const MAX_GUESSES: u32 = 200;
+// The tracker keeps one chance per number, so the range has to fit.
+const MAX_TRACKED: u32 = 1_000_000;
+
+// How the step the number takes after a wrong guess is drawn.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Distribution {
+    // every step from -step to +step as likely
+    Uniform,
+    // small steps more likely than big ones, the difference of two dice
+    Triangular,
+    // stays put half the time, otherwise any step up to the limit
+    Lazy,
+}
+
+#[derive(Clone, Copy)]
+pub struct Drift {
+    pub distribution: Distribution,
+    pub step: u32,
+}
+
+impl Drift {
+    // The chance of every step from -step to +step, in that order.
+    pub fn weights(&self) -> Vec<f64> {
+        let step = self.step as i64;
+        let count = (2 * step + 1) as f64;
+
+        (-step..=step)
+            .map(|k| match self.distribution {
+                Distribution::Uniform => 1.0 / count,
+                Distribution::Triangular => (step + 1 - k.abs()) as f64 / ((step + 1) * (step + 1)) as f64,
+                Distribution::Lazy if step == 0 => 1.0,
+                Distribution::Lazy if k == 0 => 0.5,
+                Distribution::Lazy => 0.5 / (count - 1.0),
+            })
+            .collect()
+    }
+
+    // Moves the number one step, kept inside low..=high. Drawn straight
+    // from the distribution, a step as wide as the range needs no table.
+    pub fn apply(&self, secret: u32, low: u32, high: u32, rng: &mut impl Rng) -> u32 {
+        let step = self.step as i64;
+        let moved = match self.distribution {
+            Distribution::Uniform => rng.gen_range(-step..=step),
+            Distribution::Triangular => rng.gen_range(0..=step) - rng.gen_range(0..=step),
+            Distribution::Lazy if step == 0 || rng.gen_bool(0.5) => 0,
+            // any step but 0
+            Distribution::Lazy => match rng.gen_range(-step..step) {
+                k if k >= 0 => k + 1,
+                k => k,
+            },
+        };
+        (secret as i64 + moved).clamp(low as i64, high as i64) as u32
+    }
+
+    // A step wider than the range would only pile the number up at its
+    // ends.
+    pub fn fits(&self, low: u32, high: u32) -> bool {
+        self.step <= high - low
+    }
+}
+
+// What a simulated game needs from a solver.
+pub trait Guesser {
+    fn next_guess(&self) -> u32;
+    // outcome is how the guess compared with the number before it moved.
+    fn record(&mut self, guess: u32, outcome: Ordering);
+}
+
+// Keeps a probability for every number and moves it the way the number
+// moves: answers rule numbers out, then the drift smears what is left.
+// Guessing the median splits the chances in two, bisection for beliefs.
+#[derive(Clone)]
+pub struct Tracker {
+    low: u32,
+    belief: Vec<f64>,
+    weights: Vec<f64>,
+    step: i64,
+}
+
+impl Tracker {
+    // None past MAX_TRACKED numbers or for a step that does not fit the
+    // range, the chances and the weights would not fit in memory.
+    pub fn new(low: u32, high: u32, drift: &Drift) -> Option<Tracker> {
+        if high - low >= MAX_TRACKED || !drift.fits(low, high) {
+            return None;
+        }
+        let size = (high - low + 1) as usize;
+        Some(Tracker { low, belief: vec![1.0 / size as f64; size], weights: drift.weights(), step: drift.step as i64 })
+    }
+}
+
+impl Guesser for Tracker {
+    fn next_guess(&self) -> u32 {
+        let mut seen = 0.0;
+        for (i, chance) in self.belief.iter().enumerate() {
+            seen += chance;
+            if seen >= 0.5 {
+                return self.low + i as u32;
+            }
+        }
+        self.low + self.belief.len() as u32 - 1
+    }
+
+    fn record(&mut self, guess: u32, outcome: Ordering) {
+        for (i, chance) in self.belief.iter_mut().enumerate() {
+            if judge(guess, self.low + i as u32) != outcome {
+                *chance = 0.0;
+            }
+        }
+
+        let last = self.belief.len() as i64 - 1;
+        let mut moved = vec![0.0; self.belief.len()];
+        for (i, &chance) in self.belief.iter().enumerate().filter(|(_, chance)| **chance > 0.0) {
+            for (k, weight) in (-self.step..).zip(&self.weights) {
+                moved[(i as i64 + k).clamp(0, last) as usize] += chance * weight;
+            }
+        }
+
+        // answers that contradict the model leave nothing, start over
+        let total: f64 = moved.iter().sum();
+        if total > 0.0 {
+            self.belief = moved.iter().map(|chance| chance / total).collect();
+        } else {
+            self.belief = vec![1.0 / moved.len() as f64; moved.len()];
+        }
+    }
+}
+
+// Plain bisection that ignores the drift, starting over on the whole
+// range when the number has slipped out of what it thinks is left.
+pub struct Bisection {
+    range: (u32, u32),
+    low: u32,
+    high: u32,
+}
+
+impl Bisection {
+    pub fn new(low: u32, high: u32) -> Bisection {
+        Bisection { range: (low, high), low, high }
+    }
+}
+
+impl Guesser for Bisection {
+    fn next_guess(&self) -> u32 {
+        reverse::next_guess(self.low, self.high)
+    }
+
+    fn record(&mut self, guess: u32, outcome: Ordering) {
+        match outcome {
+            Ordering::Less => self.low = guess + 1,
+            Ordering::Greater => self.high = guess.saturating_sub(1),
+            Ordering::Equal => {}
+        }
+        if self.low > self.high {
+            (self.low, self.high) = self.range;
+        }
+    }
+}
+
+// drift [--step N] [--drift KIND] [--attempts N]: the classic game, but
+// the number moves after every wrong guess. Not recorded, since the
+// score checker cannot replay a moving number.
+pub fn run(drift: Drift, max_attempts: Option<u32>) {
+    let settings = config::get();
+    let (low, high) = (settings.range.low, settings.range.high);
+    if !drift.fits(low, high) {
+        crate::usage_error(&t!("drift-step-too-wide", step = drift.step, low = low, high = high));
+    }
+    let mut rng = crate::rng();
+    let mut secret = crate::draw_secret(&mut rng, low, high);
+
+    println!("{}", t!("drift-intro", low = low, high = high, step = drift.step));
+
+    let mut session = Session::new(max_attempts.or(settings.attempts));
+    loop {
+        if session.is_over() {
+            println!("{}", t!("you-lose", secret = secret));
+            return;
+        }
+
+        println!("{}", t!("enter-guess"));
+        let Some(input) = read_input() else { return };
+        let guess = match parse::guess(&input) {
+            Ok(num) => num,
+            Err(error) => {
+                println!("{}", crate::bad_guess(&error));
+                continue;
+            }
+        };
+
+        println!("{}", t!("you-guessed", guess = guess));
+        session.attempt();
+
+        match judge(guess, secret) {
+            Ordering::Less => println!("{}", t!("too-small")),
+            Ordering::Greater => println!("{}", t!("too-big")),
+            Ordering::Equal => {
+                println!("{}", t!("you-win-in", count = session.attempts()));
+                return;
+            }
+        }
+        secret = drift.apply(secret, low, high, &mut rng);
+    }
+}
+
+// Guesses one solver needed against a number that starts at the secret
+// the seed gives and drifts with that seed, None when it gave up.
+fn simulate(seed: u64, low: u32, high: u32, drift: &Drift, solver: &mut impl Guesser) -> Option<u32> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut secret = rng.gen_range(low..=high);
+
+    for guesses in 1..=MAX_GUESSES {
+        let guess = solver.next_guess();
+        let outcome = judge(guess, secret);
+        if outcome == Ordering::Equal {
+            return Some(guesses);
+        }
+        solver.record(guess, outcome);
+        secret = drift.apply(secret, low, high, &mut rng);
+    }
+    None
+}
+
+struct Report {
+    solver: &'static str,
+    games: u32,
+    // guesses of every game that was solved
+    guesses: Vec<u32>,
+}
+
+// drift --simulate [--games N]: both solvers play the same numbers with
+// the same drift, seeded from --seed, and are compared side by side.
+pub fn report(drift: Drift, games: u32) {
+    let games = games.max(1);
+    let range = config::get().range;
+    let (low, high) = (range.low, range.high);
+    if !drift.fits(low, high) {
+        crate::usage_error(&t!("drift-step-too-wide", step = drift.step, low = low, high = high));
+    }
+    let Some(tracker) = Tracker::new(low, high, &drift) else {
+        crate::usage_error(&t!("drift-too-wide", max = MAX_TRACKED, low = low, high = high));
+    };
+    let mut rng = crate::rng_for("drift --simulate");
+    let seeds: Vec<u64> = (0..games).map(|_| rng.r#gen()).collect();
+
+    let mut tracking = Report { solver: "tracking", games, guesses: Vec::new() };
+    let mut bisection = Report { solver: "bisection", games, guesses: Vec::new() };
+    for &seed in &seeds {
+        let mut tracker = tracker.clone();
+        tracking.guesses.extend(simulate(seed, low, high, &drift, &mut tracker));
+
+        let mut naive = Bisection::new(low, high);
+        bisection.guesses.extend(simulate(seed, low, high, &drift, &mut naive));
+    }
+
+    let reports = [tracking, bisection];
+    if config::get().format == Format::Json {
+        let reports: Vec<_> = reports
+            .iter()
+            .map(|report| {
+                json!({
+                    "solver": report.solver,
+                    "games": report.games,
+                    "solved": report.guesses.len(),
+                    "average_guesses": average(&report.guesses),
+                    "median_guesses": median(&report.guesses),
+                    "worst_guesses": report.guesses.iter().max(),
+                })
+            })
+            .collect();
+        println!("{}", json!({ "low": low, "high": high, "step": drift.step, "solvers": reports }));
+        return;
+    }
+
+    println!("{}", t!("drift-report", low = low, high = high, step = drift.step, count = games, max = MAX_GUESSES));
+    println!(
+        "{:<10} {:>7} {:>8} {:>7} {:>6}",
+        t!("column-solver"),
+        t!("column-solved"),
+        t!("column-average"),
+        t!("column-median"),
+        t!("column-worst")
+    );
+    for report in &reports {
+        println!(
+            "{:<10} {:>6.1}% {:>8.2} {:>7} {:>6}",
+            report.solver,
+            report.guesses.len() as f64 * 100.0 / games as f64,
+            average(&report.guesses),
+            median(&report.guesses),
+            report.guesses.iter().max().unwrap_or(&0)
+        );
+    }
+}
+
+fn average(guesses: &[u32]) -> f64 {
+    guesses.iter().map(|&count| u64::from(count)).sum::<u64>() as f64 / guesses.len().max(1) as f64
+}
+
+fn median(guesses: &[u32]) -> u32 {
+    let mut sorted = guesses.to_vec();
+    sorted.sort_unstable();
+    sorted.get(sorted.len() / 2).copied().unwrap_or(0)
+}
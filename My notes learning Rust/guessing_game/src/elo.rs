@@ -0,0 +1,63 @@
+// The numbers behind ratings: how hard a game is, how likely a player is
+// to win it, how far a result moves the rating, and which game the
+// adaptive mode offers next.
+
+use crate::bits;
+
+pub const START_RATING: f64 = 1000.0;
+// the share of games the adaptive mode aims for the player to win
+pub const TARGET_WIN_RATE: f64 = 0.7;
+const RANGES: [u32; 9] = [10, 20, 50, 100, 200, 500, 1000, 5000, 10000];
+
+// Guesses a perfect binary search needs in the worst case.
+fn optimal(range: u32) -> u32 {
+    bits(u64::from(range) + 1)
+}
+
+// Games get an Elo rating too: bigger ranges are harder, every attempt
+// beyond what binary search needs makes the game easier.
+pub fn game_rating(range: u32, max_attempts: u32) -> f64 {
+    let slack = max_attempts as f64 - optimal(range) as f64;
+    800.0 + 100.0 * (range as f64).log2() - 150.0 * slack
+}
+
+// The usual Elo curve: 400 points apart means 10 to 1 odds.
+pub fn expected_win(player: f64, game: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((game - player) / 400.0))
+}
+
+// Like Glicko, a new player's rating moves fast and settles down as
+// games pile up.
+fn k_factor(games: u32) -> f64 {
+    (40.0 - games as f64).max(16.0)
+}
+
+// How much a player with `rating` after `games` games gains or loses by
+// winning or losing a game rated `game`.
+pub fn change(rating: f64, games: u32, game: f64, won: bool) -> f64 {
+    let score = if won { 1.0 } else { 0.0 };
+    k_factor(games) * (score - expected_win(rating, game))
+}
+
+// The range and budget whose expected win rate is closest to the target,
+// measured in rating points so a player beyond every game's reach still
+// gets the hardest one. Many games come close, so ranges far from the
+// classic 1 to 100 count as a few points further off and the game only
+// drifts away from it as the rating does.
+pub fn pick(rating: f64) -> (u32, u32) {
+    // the game rating a player of this rating wins at the target rate
+    let ideal = rating - 400.0 * (TARGET_WIN_RATE / (1.0 - TARGET_WIN_RATE)).log10();
+    let mut best = (100, optimal(100), f64::MAX);
+
+    for range in RANGES {
+        let penalty = (range as f64 / 100.0).log2().abs() * 10.0;
+        for max_attempts in optimal(range)..=optimal(range) + 10 {
+            let distance = (game_rating(range, max_attempts) - ideal).abs() + penalty;
+            if distance < best.2 {
+                best = (range, max_attempts, distance);
+            }
+        }
+    }
+
+    (best.0, best.1)
+}
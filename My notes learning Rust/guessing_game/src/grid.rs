@@ -0,0 +1,309 @@
+use std::cmp::Ordering;
+
+use crate::i18n::t;
+use crate::{bits, read_input};
+
+const MAX_SIZE: u32 = 64;
+
+// How the host answers a guess on the grid.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Feedback {
+    // one Ordering per axis, shown as a compass direction
+    Direction,
+    // the straight line distance to the secret, shown as hot or cold
+    Distance,
+}
+
+#[derive(PartialEq)]
+pub enum Answer {
+    Found,
+    Direction(Ordering, Ordering),
+    // squared distance, so cells can be compared without floats
+    Distance(u32),
+}
+
+pub struct Grid {
+    width: u32,
+    height: u32,
+    feedback: Feedback,
+    // true while the cell could still hide the secret
+    possible: Vec<bool>,
+    guesses: Vec<(u32, u32)>,
+}
+
+impl Grid {
+    pub fn new(width: u32, height: u32, feedback: Feedback) -> Grid {
+        Grid {
+            width,
+            height,
+            feedback,
+            possible: vec![true; (width * height) as usize],
+            guesses: Vec::new(),
+        }
+    }
+
+    fn index(&self, (x, y): (u32, u32)) -> usize {
+        ((y - 1) * self.width + (x - 1)) as usize
+    }
+
+    fn cells(&self) -> Vec<(u32, u32)> {
+        (1..=self.height)
+            .flat_map(|y| (1..=self.width).map(move |x| (x, y)))
+            .collect()
+    }
+
+    fn remaining(&self) -> Vec<(u32, u32)> {
+        self.cells()
+            .into_iter()
+            .filter(|&cell| self.possible[self.index(cell)])
+            .collect()
+    }
+
+    // Keeps only the cells that would have given the same answer.
+    pub fn record(&mut self, guess: (u32, u32), answer: &Answer) {
+        for cell in self.cells() {
+            if answer_for(self.feedback, guess, cell) != *answer {
+                let index = self.index(cell);
+                self.possible[index] = false;
+            }
+        }
+        self.guesses.push(guess);
+    }
+
+    // The row with the biggest y is printed first so north is up.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        for y in (1..=self.height).rev() {
+            out.push_str(&format!("{y:>3} "));
+            for x in 1..=self.width {
+                let symbol = if self.possible[self.index((x, y))] {
+                    '.'
+                } else if self.guesses.contains(&(x, y)) {
+                    'o'
+                } else {
+                    'x'
+                };
+                out.push(symbol);
+            }
+            out.push('\n');
+        }
+
+        out.push_str(&format!("    {}\n", t!("grid-cells-left", count = self.remaining().len())));
+        out
+    }
+
+    // Direction answers cut the grid down to a rectangle, so bisecting
+    // each axis is optimal. Distance answers leave rings, so we pick the
+    // cell whose worst answer leaves the fewest candidates.
+    pub fn next_guess(&self) -> (u32, u32) {
+        match self.feedback {
+            Feedback::Direction => {
+                let (mut low_x, mut high_x) = (self.width, 1);
+                let (mut low_y, mut high_y) = (self.height, 1);
+                for (x, y) in self.remaining() {
+                    low_x = low_x.min(x);
+                    high_x = high_x.max(x);
+                    low_y = low_y.min(y);
+                    high_y = high_y.max(y);
+                }
+                ((low_x + high_x) / 2, (low_y + high_y) / 2)
+            }
+            Feedback::Distance => {
+                if self.guesses.is_empty() {
+                    return (1, 1);
+                }
+                let remaining = self.remaining();
+                if remaining.len() == 1 {
+                    return remaining[0];
+                }
+                let mut best = (remaining[0], usize::MAX);
+                for guess in self.cells() {
+                    let worst = worst_split(guess, &remaining);
+                    let is_candidate = remaining.contains(&guess);
+                    if worst < best.1 || (worst == best.1 && is_candidate) {
+                        best = (guess, worst);
+                    }
+                }
+                best.0
+            }
+        }
+    }
+}
+
+pub fn answer_for(feedback: Feedback, guess: (u32, u32), secret: (u32, u32)) -> Answer {
+    if guess == secret {
+        return Answer::Found;
+    }
+
+    match feedback {
+        Feedback::Direction => Answer::Direction(guess.0.cmp(&secret.0), guess.1.cmp(&secret.1)),
+        Feedback::Distance => {
+            let dx = guess.0.abs_diff(secret.0);
+            let dy = guess.1.abs_diff(secret.1);
+            Answer::Distance(dx * dx + dy * dy)
+        }
+    }
+}
+
+// Size of the biggest group of candidates that would give the same answer.
+fn worst_split(guess: (u32, u32), candidates: &[(u32, u32)]) -> usize {
+    let mut distances: Vec<u32> = candidates
+        .iter()
+        .filter(|&&cell| cell != guess)
+        .map(|&cell| match answer_for(Feedback::Distance, guess, cell) {
+            Answer::Distance(d) => d,
+            _ => 0,
+        })
+        .collect();
+    distances.sort_unstable();
+
+    distances
+        .chunk_by(|a, b| a == b)
+        .map(|group| group.len())
+        .max()
+        .unwrap_or(0)
+}
+
+// Guess Less on x means the secret is further east, Less on y further north.
+// Returns the catalog key of the direction, like go-north-east.
+fn compass(x: Ordering, y: Ordering) -> String {
+    let north_south = match y {
+        Ordering::Less => "north",
+        Ordering::Greater => "south",
+        Ordering::Equal => "",
+    };
+    let east_west = match x {
+        Ordering::Less => "east",
+        Ordering::Greater => "west",
+        Ordering::Equal => "",
+    };
+
+    if north_south.is_empty() || east_west.is_empty() {
+        format!("go-{north_south}{east_west}")
+    } else {
+        format!("go-{north_south}-{east_west}")
+    }
+}
+
+fn temperature(distance: f64, diagonal: f64) -> &'static str {
+    match distance / diagonal {
+        d if d <= 0.1 => "temperature-burning",
+        d if d <= 0.25 => "temperature-hot",
+        d if d <= 0.5 => "temperature-warm",
+        d if d <= 0.75 => "temperature-cold",
+        _ => "temperature-freezing",
+    }
+}
+
+pub fn solve(width: u32, height: u32, feedback: Feedback, secret: (u32, u32)) -> u32 {
+    let mut grid = Grid::new(width, height, feedback);
+
+    loop {
+        let guess = grid.next_guess();
+        let answer = answer_for(feedback, guess, secret);
+        if answer == Answer::Found {
+            return grid.guesses.len() as u32 + 1;
+        }
+        grid.record(guess, &answer);
+    }
+}
+
+fn parse_point(input: &str, width: u32, height: u32) -> Option<(u32, u32)> {
+    let mut parts = input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty());
+
+    let x: u32 = parts.next()?.parse().ok()?;
+    let y: u32 = parts.next()?.parse().ok()?;
+
+    if parts.next().is_some() || !(1..=width).contains(&x) || !(1..=height).contains(&y) {
+        return None;
+    }
+    Some((x, y))
+}
+
+// grid [--solve] [WIDTH] [HEIGHT] [--hint direction|distance]
+pub fn run(solving: bool, width: u32, height: u32, feedback: Feedback) {
+    if !(1..=MAX_SIZE).contains(&width) || !(1..=MAX_SIZE).contains(&height) {
+        crate::usage_error(&t!("grid-bad-size", max = MAX_SIZE, width = width, height = height));
+    }
+
+    if solving {
+        report(width, height, feedback);
+    } else {
+        play(width, height, feedback);
+    }
+}
+
+fn play(width: u32, height: u32, feedback: Feedback) {
+    println!("{}", t!("grid-intro", width = width, height = height));
+
+    let mut rng = crate::rng();
+    let secret = (crate::draw_secret(&mut rng, 1, width), crate::draw_secret(&mut rng, 1, height));
+    let diagonal = ((width * width + height * height) as f64).sqrt();
+    let mut grid = Grid::new(width, height, feedback);
+
+    loop {
+        println!("{}", t!("grid-enter-guess"));
+
+        let Some(input) = read_input() else { return };
+
+        let guess = match parse_point(&input, width, height) {
+            Some(point) => point,
+            None => {
+                println!("{}", t!("grid-bad-point", width = width, height = height));
+                continue;
+            }
+        };
+
+        let answer = answer_for(feedback, guess, secret);
+        grid.record(guess, &answer);
+
+        match answer {
+            Answer::Found => {
+                println!("{}", t!("you-win-in", count = grid.guesses.len()));
+                break;
+            }
+            Answer::Direction(x, y) => println!("{}", t!(&compass(x, y))),
+            Answer::Distance(squared) => {
+                let distance = (squared as f64).sqrt();
+                let key = temperature(distance, diagonal);
+                println!("{}", t!(key, distance = format!("{distance:.2}")));
+            }
+        }
+
+        print!("{}", grid.render());
+    }
+}
+
+// Runs the solver against every secret and, with directions, compares it
+// with bisecting each axis on its own.
+fn report(width: u32, height: u32, feedback: Feedback) {
+    let grid = Grid::new(width, height, feedback);
+    let mut worst = 0;
+    let mut total = 0;
+
+    for secret in grid.cells() {
+        let guesses = solve(width, height, feedback, secret);
+        worst = worst.max(guesses);
+        total += guesses;
+    }
+
+    let average = total as f64 / (width * height) as f64;
+    println!(
+        "{}",
+        t!("grid-report", width = width, height = height, worst = worst, average = format!("{average:.2}"))
+    );
+
+    if feedback == Feedback::Direction {
+        // one binary search per axis, both run side by side
+        let bisection = bits(width + 1).max(bits(height + 1));
+        println!("{}", t!("grid-bisection", count = bisection));
+        if worst <= bisection {
+            println!("{}", t!("grid-bisection-met"));
+        } else {
+            println!("{}", t!("grid-bisection-missed", count = worst - bisection));
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+// What is known about K distinct secrets hidden in one range, and a
+// solver for them.
+
+// Ranges up to this size are solved exactly, larger ones with the greedy
+// guess: the exact search visits every state of knowledge, and their
+// number grows quickly with the range.
+pub const EXACT_LIMIT: u32 = 10;
+
+// How many secrets are below, at and above a guess.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Answer {
+    pub below: usize,
+    pub equal: bool,
+    pub above: usize,
+}
+
+// secrets must be sorted.
+pub fn answer(secrets: &[u32], guess: u32) -> Answer {
+    let below = secrets.partition_point(|&secret| secret < guess);
+    let equal = secrets.get(below) == Some(&guess);
+    Answer { below, equal, above: secrets.len() - below - usize::from(equal) }
+}
+
+// Every answer only ever says "the i-th smallest secret is below, at or
+// above this number", so one interval per secret, smallest first, holds
+// all there is to know.
+#[derive(Clone)]
+pub struct Knowledge {
+    low: u32,
+    high: u32,
+    pub bounds: Vec<(u32, u32)>,
+    // guessed exactly, knowing where a secret is does not count
+    pub found: Vec<bool>,
+}
+
+impl Knowledge {
+    // count must fit in low..=high.
+    pub fn new(count: usize, low: u32, high: u32) -> Knowledge {
+        let bounds = (0..count as u32).map(|i| (low + i, high - (count as u32 - 1 - i))).collect();
+        Knowledge { low, high, bounds, found: vec![false; count] }
+    }
+
+    pub fn record(&mut self, guess: u32, answer: &Answer) {
+        for (i, (low, high)) in self.bounds.iter_mut().enumerate() {
+            if i < answer.below {
+                *high = (*high).min(guess.saturating_sub(1));
+            } else if i == answer.below && answer.equal {
+                (*low, *high) = (guess, guess);
+                self.found[i] = true;
+            } else {
+                *low = (*low).max(guess.saturating_add(1));
+            }
+        }
+
+        // the secrets are distinct, so each sits above the one before
+        for i in 1..self.bounds.len() {
+            self.bounds[i].0 = self.bounds[i].0.max(self.bounds[i - 1].0 + 1);
+        }
+        for i in (0..self.bounds.len().saturating_sub(1)).rev() {
+            self.bounds[i].1 = self.bounds[i].1.min(self.bounds[i + 1].1 - 1);
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.found.iter().all(|&found| found)
+    }
+
+    // ways[i][j]: how many ways the i smallest secrets fit in the first j
+    // numbers of the range, keeping to their intervals.
+    fn ways_below(&self) -> Vec<Vec<f64>> {
+        let size = (self.high - self.low + 1) as usize;
+        let mut ways = vec![vec![0.0; size + 1]; self.bounds.len() + 1];
+        ways[0].fill(1.0);
+
+        for (i, &(low, high)) in self.bounds.iter().enumerate() {
+            for j in 1..=size {
+                let number = self.low + j as u32 - 1;
+                let here = if (low..=high).contains(&number) { ways[i][j - 1] } else { 0.0 };
+                ways[i + 1][j] = ways[i + 1][j - 1] + here;
+            }
+        }
+        ways
+    }
+
+    // ways[i][j]: how many ways the secrets from the i-th one up fit from
+    // the j-th number of the range to its end.
+    fn ways_above(&self) -> Vec<Vec<f64>> {
+        let size = (self.high - self.low + 1) as usize;
+        let count = self.bounds.len();
+        let mut ways = vec![vec![0.0; size + 1]; count + 1];
+        ways[count].fill(1.0);
+
+        for (i, &(low, high)) in self.bounds.iter().enumerate().rev() {
+            for j in (0..size).rev() {
+                let number = self.low + j as u32;
+                let here = if (low..=high).contains(&number) { ways[i + 1][j + 1] } else { 0.0 };
+                ways[i][j] = ways[i][j + 1] + here;
+            }
+        }
+        ways
+    }
+
+    // Numbers an unfound secret can still be at. Any other guess has an
+    // answer known in advance and teaches nothing.
+    fn is_open(&self, number: u32) -> bool {
+        self.bounds.iter().zip(&self.found).any(|(&(low, high), &found)| !found && (low..=high).contains(&number))
+    }
+
+    // The answers guess can still get, with how many placements of the
+    // secrets give each of them.
+    fn answers(&self, guess: u32, below: &[Vec<f64>], above: &[Vec<f64>]) -> Vec<(Answer, f64)> {
+        let count = self.bounds.len();
+        let j = (guess - self.low) as usize;
+        let mut answers = Vec::new();
+
+        for b in 0..=count {
+            let missed = below[b][j] * above[b][j + 1];
+            if missed > 0.0 {
+                answers.push((Answer { below: b, equal: false, above: count - b }, missed));
+            }
+            if let Some(&(low, high)) = self.bounds.get(b)
+                && (low..=high).contains(&guess)
+            {
+                let hit = below[b][j] * above[b + 1][j + 1];
+                if hit > 0.0 {
+                    answers.push((Answer { below: b, equal: true, above: count - b - 1 }, hit));
+                }
+            }
+        }
+        answers
+    }
+
+    // A secret whose place is known is guessed straight away, it has to be
+    // some time. Otherwise every placement of the secrets that fits what
+    // is known is taken as equally likely, and the guess whose answer is
+    // hardest to predict (the most entropy) is played: each guess then
+    // teaches as much as it can, which keeps the total number of guesses
+    // low. That is greedy, not a proven optimum, see Solver for that.
+    pub fn next_guess(&self) -> u32 {
+        if let Some(i) = (0..self.bounds.len()).find(|&i| !self.found[i] && self.bounds[i].0 == self.bounds[i].1) {
+            return self.bounds[i].0;
+        }
+
+        let below = self.ways_below();
+        let above = self.ways_above();
+        let total = above[0][0];
+        let mut best = (f64::NEG_INFINITY, self.low);
+
+        for number in (self.low..=self.high).filter(|&number| self.is_open(number)) {
+            let mut entropy = 0.0;
+            for (_, ways) in self.answers(number, &below, &above) {
+                let chance = ways / total;
+                entropy -= chance * chance.log2();
+            }
+
+            if entropy > best.0 + 1e-9 {
+                best = (entropy, number);
+            }
+        }
+        best.1
+    }
+}
+
+// The fewest guesses that find every secret however they are placed, by
+// minimax over the states of knowledge: a state costs nothing once solved,
+// and otherwise one guess plus the worst state its answers can lead to,
+// for the guess where that is lowest. States are remembered, many orders
+// of guesses reach the same one. Past EXACT_LIMIT numbers the greedy
+// guess of Knowledge is played instead.
+pub struct Solver {
+    exact: bool,
+    // for each state: its worst number of guesses left, and the guess that
+    // keeps to it
+    known: HashMap<State, (u32, u32)>,
+}
+
+// The bounds and found of a Knowledge.
+type State = (Vec<(u32, u32)>, Vec<bool>);
+
+impl Solver {
+    pub fn new(low: u32, high: u32) -> Solver {
+        Solver { exact: high - low < EXACT_LIMIT, known: HashMap::new() }
+    }
+
+    pub fn next_guess(&mut self, knowledge: &Knowledge) -> u32 {
+        if self.exact { self.search(knowledge).1 } else { knowledge.next_guess() }
+    }
+
+    // The most guesses the exact search needs from knowledge on, None past
+    // EXACT_LIMIT where there is no exact answer.
+    pub fn worst(&mut self, knowledge: &Knowledge) -> Option<u32> {
+        self.exact.then(|| self.search(knowledge).0)
+    }
+
+    fn search(&mut self, knowledge: &Knowledge) -> (u32, u32) {
+        if knowledge.is_solved() {
+            return (0, knowledge.low);
+        }
+        let key = (knowledge.bounds.clone(), knowledge.found.clone());
+        if let Some(&known) = self.known.get(&key) {
+            return known;
+        }
+
+        let below = knowledge.ways_below();
+        let above = knowledge.ways_above();
+        let mut best = (u32::MAX, knowledge.low);
+
+        for guess in (knowledge.low..=knowledge.high).filter(|&number| knowledge.is_open(number)) {
+            let mut worst = 0;
+            for (answer, _) in knowledge.answers(guess, &below, &above) {
+                // no need to look further once this guess is no better
+                if worst + 1 >= best.0 {
+                    break;
+                }
+                let mut next = knowledge.clone();
+                next.record(guess, &answer);
+                worst = worst.max(self.search(&next).0);
+            }
+            if worst + 1 < best.0 {
+                best = (worst + 1, guess);
+            }
+        }
+
+        self.known.insert(key, best);
+        best
+    }
+}
+
+// Plays the solver against secrets (sorted) and returns how many guesses
+// it needed to hit every one of them.
+pub fn solve(secrets: &[u32], low: u32, high: u32) -> u32 {
+    let mut knowledge = Knowledge::new(secrets.len(), low, high);
+    let mut solver = Solver::new(low, high);
+    let mut guesses = 0;
+
+    while !knowledge.is_solved() {
+        let guess = solver.next_guess(&knowledge);
+        knowledge.record(guess, &answer(secrets, guess));
+        guesses += 1;
+    }
+    guesses
+}
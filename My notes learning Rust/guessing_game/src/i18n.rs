@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::env;
+use std::sync::OnceLock;
+
+// The catalogs are compiled in, so a translation can never go missing at
+// run time. English is also the fallback for keys a catalog lacks.
+const CATALOGS: [(&str, &str); 3] = [
+    ("en", include_str!("../locales/en.ftl")),
+    ("fr", include_str!("../locales/fr.ftl")),
+    ("ar", include_str!("../locales/ar.ftl")),
+];
+
+// Unicode isolates keep numbers and Latin words from being reordered when
+// they sit inside right-to-left text, the same trick Fluent uses.
+const RIGHT_TO_LEFT_ISOLATE: char = '\u{2067}';
+const FIRST_STRONG_ISOLATE: char = '\u{2068}';
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+// A message is either plain text with { $name } placeables, or a choice
+// between variants keyed by the plural category of one variable.
+enum Message {
+    Text(String),
+    Select {
+        variable: String,
+        variants: Vec<(String, String)>,
+        default: String,
+    },
+}
+
+struct Catalog {
+    language: &'static str,
+    right_to_left: bool,
+    messages: HashMap<String, Message>,
+    fallback: Option<Box<Catalog>>,
+}
+
+// A small subset of the Fluent syntax: `key = text` on one line, or
+//
+//     key = { $count ->
+//         [one] text
+//        *[other] text
+//     }
+//
+// Lines starting with # are comments, `# direction: rtl` marks the catalog
+// as right-to-left.
+fn parse(language: &'static str, source: &str) -> Catalog {
+    let mut messages = HashMap::new();
+    let mut right_to_left = false;
+    let mut lines = source.lines();
+
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if line.starts_with("# direction: rtl") {
+            right_to_left = true;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else { continue };
+        let (key, value) = (key.trim().to_string(), value.trim());
+
+        let Some(variable) = value
+            .strip_prefix("{ $")
+            .and_then(|rest| rest.strip_suffix("->"))
+        else {
+            messages.insert(key, Message::Text(value.to_string()));
+            continue;
+        };
+
+        let mut variants = Vec::new();
+        let mut default = String::new();
+        for line in lines.by_ref() {
+            let line = line.trim();
+            if line == "}" {
+                break;
+            }
+            let is_default = line.starts_with('*');
+            let Some((category, text)) = line.trim_start_matches('*').strip_prefix('[').and_then(|rest| rest.split_once(']')) else {
+                continue;
+            };
+            if is_default {
+                default = text.trim().to_string();
+            }
+            variants.push((category.to_string(), text.trim().to_string()));
+        }
+
+        messages.insert(key, Message::Select { variable: variable.trim().to_string(), variants, default });
+    }
+
+    Catalog { language, right_to_left, messages, fallback: None }
+}
+
+// CLDR plural categories for the languages we ship.
+fn plural_category(language: &str, n: i64) -> &'static str {
+    match language {
+        "fr" => if n == 0 || n == 1 { "one" } else { "other" },
+        "ar" => match n {
+            0 => "zero",
+            1 => "one",
+            2 => "two",
+            _ if (3..=10).contains(&(n % 100)) => "few",
+            _ if (11..=99).contains(&(n % 100)) => "many",
+            _ => "other",
+        },
+        _ => if n == 1 { "one" } else { "other" },
+    }
+}
+
+// "fr_FR.UTF-8" and "fr-CA" both mean French.
+fn language_of(locale: &str) -> String {
+    locale
+        .split(['_', '-', '.'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+// Picks the catalog from --lang, or from LANG when no flag was given.
+pub fn init(lang: Option<&str>) {
+    let wanted = lang
+        .map(str::to_string)
+        .or_else(|| env::var("LANG").ok())
+        .map(|locale| language_of(&locale))
+        .unwrap_or_default();
+
+    let english = parse(CATALOGS[0].0, CATALOGS[0].1);
+    let catalog = match CATALOGS.iter().find(|(language, _)| *language == wanted) {
+        Some((language, source)) if *language != "en" => Catalog {
+            fallback: Some(Box::new(english)),
+            ..parse(language, source)
+        },
+        _ => english,
+    };
+
+    let _ = CATALOG.set(catalog);
+}
+
+impl Catalog {
+    fn format(&self, key: &str, args: &[(&str, String)]) -> Option<String> {
+        let Some(message) = self.messages.get(key) else {
+            return self.fallback.as_ref()?.format(key, args);
+        };
+
+        let pattern = match message {
+            Message::Text(text) => text,
+            Message::Select { variable, variants, default } => {
+                let category = args
+                    .iter()
+                    .find(|(name, _)| name == variable)
+                    .and_then(|(_, value)| value.parse().ok())
+                    .map(|n| plural_category(self.language, n));
+                let exact = args.iter().find(|(name, _)| name == variable).map(|(_, value)| value.as_str());
+
+                variants
+                    .iter()
+                    .find(|(name, _)| Some(name.as_str()) == exact || Some(name.as_str()) == category)
+                    .map(|(_, text)| text)
+                    .unwrap_or(default)
+            }
+        };
+
+        let mut text = pattern.to_string();
+        for (name, value) in args {
+            let value = if self.right_to_left {
+                format!("{FIRST_STRONG_ISOLATE}{value}{POP_DIRECTIONAL_ISOLATE}")
+            } else {
+                value.clone()
+            };
+            text = text.replace(&format!("{{ ${name} }}"), &value);
+        }
+
+        if self.right_to_left {
+            text = format!("{RIGHT_TO_LEFT_ISOLATE}{text}{POP_DIRECTIONAL_ISOLATE}");
+        }
+        Some(text)
+    }
+}
+
+pub fn language() -> &'static str {
+    CATALOG.get_or_init(|| parse(CATALOGS[0].0, CATALOGS[0].1)).language
+}
+
+// Looks the key up in the chosen catalog. A key no catalog knows is shown
+// as is, which makes a missing translation easy to spot.
+pub fn message(key: &str, args: &[(&str, String)]) -> String {
+    CATALOG
+        .get_or_init(|| parse(CATALOGS[0].0, CATALOGS[0].1))
+        .format(key, args)
+        .unwrap_or_else(|| key.to_string())
+}
+
+// t!("too-small") or t!("you-guessed", guess = guess)
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::message($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::message($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+
+pub(crate) use t;
@@ -0,0 +1,82 @@
+use rand::seq::SliceRandom;
+
+use crate::config;
+use crate::i18n::t;
+use crate::prior::{self, Prior, Shape};
+use crate::read_input;
+
+// The bot names the shape once it is this sure.
+const CONFIDENT: f64 = 0.99;
+
+fn names() -> String {
+    let names: Vec<&str> = Shape::ALL.iter().map(|shape| shape.name()).collect();
+    names.join(", ")
+}
+
+// How likely each shape is after the draws, likeliest first.
+fn show_posterior(low: u32, high: u32, draws: &[u32]) {
+    let mut posterior = prior::posterior(low, high, draws);
+    posterior.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let parts: Vec<String> =
+        posterior.iter().map(|(shape, chance)| format!("{} {:.1}%", shape.name(), chance * 100.0)).collect();
+    println!("{}", t!("infer-posterior", chances = parts.join(", ")));
+}
+
+// infer [--rounds N] [--solve]: every round draws a secret from a hidden
+// shape and shows it. Name the shape with as few rounds as you can.
+pub fn run(rounds: u32, solving: bool) {
+    let range = config::get().range;
+    let (low, high) = (range.low, range.high);
+    let mut rng = crate::rng_for("infer");
+    let shape = *Shape::ALL.choose(&mut rng).unwrap();
+    let prior = Prior::new(shape, low, high);
+
+    println!("{}", t!("infer-intro", low = low, high = high, shapes = names(), count = rounds));
+    let mut draws = Vec::new();
+
+    if solving {
+        while (draws.len() as u32) < rounds {
+            draws.push(prior.sample(&mut rng));
+            let (likeliest, chance) = prior::posterior(low, high, &draws)
+                .into_iter()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap();
+            println!("{}: {}   {} {:.1}%", draws.len(), draws[draws.len() - 1], likeliest.name(), chance * 100.0);
+            if chance >= CONFIDENT {
+                let message = if likeliest == shape { "infer-right" } else { "infer-wrong" };
+                println!("{}", t!(message, shape = shape.name(), count = draws.len()));
+                return;
+            }
+        }
+        println!("{}", t!("infer-out-of-rounds", shape = shape.name()));
+        return;
+    }
+
+    loop {
+        println!("{}", t!("infer-ask", count = draws.len()));
+        let Some(input) = read_input() else { return };
+        let input = input.trim().to_lowercase();
+
+        if input.is_empty() {
+            if draws.len() as u32 == rounds {
+                println!("{}", t!("infer-out-of-rounds", shape = shape.name()));
+                show_posterior(low, high, &draws);
+                return;
+            }
+            draws.push(prior.sample(&mut rng));
+            let shown: Vec<String> = draws.iter().map(u32::to_string).collect();
+            println!("{}", shown.join(" "));
+            continue;
+        }
+
+        match Shape::from_name(&input) {
+            Some(named) => {
+                let message = if named == shape { "infer-right" } else { "infer-wrong" };
+                println!("{}", t!(message, shape = shape.name(), count = draws.len()));
+                show_posterior(low, high, &draws);
+                return;
+            }
+            None => println!("{}", t!("infer-unknown", shapes = names())),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::cmp::Ordering;
+
+use crate::bits;
+
+// What a round of the classic game taught: how much each guess narrowed
+// the numbers the secret could still be, and how that compares with a
+// binary search.
+
+// What one guess did to the interval of numbers the secret could still be.
+#[derive(Debug, PartialEq)]
+pub struct GuessReport {
+    pub guess: u32,
+    pub low: u32,
+    pub high: u32,
+    pub outcome: Ordering,
+    pub bits: f64,
+    // the guess was outside the interval, so its answer was known already
+    pub wasted: bool,
+}
+
+// How many numbers low..=high holds, counted in u64 so 0..=u32::MAX fits.
+fn size(low: u32, high: u32) -> f64 {
+    (u64::from(high - low) + 1) as f64
+}
+
+// Replays the guesses, narrowing low..=high with every answer.
+pub fn analyse(low: u32, high: u32, secret: u32, guesses: &[u32]) -> Vec<GuessReport> {
+    let (mut low, mut high) = (low, high);
+    let mut reports = Vec::new();
+
+    for &guess in guesses {
+        let outcome = guess.cmp(&secret);
+        let before = size(low, high);
+        let wasted = guess < low || guess > high;
+        let report_low = low;
+        let report_high = high;
+
+        match outcome {
+            Ordering::Less => low = low.max(guess + 1),
+            Ordering::Greater => high = high.min(guess - 1),
+            Ordering::Equal => (low, high) = (guess, guess),
+        }
+
+        let after = size(low, high);
+        reports.push(GuessReport {
+            guess,
+            low: report_low,
+            high: report_high,
+            outcome,
+            bits: (before / after).log2(),
+            wasted,
+        });
+    }
+
+    reports
+}
+
+// The whole round at once.
+pub struct Summary {
+    pub reports: Vec<GuessReport>,
+    pub total_bits: f64,
+    // what it takes to know the secret from the start
+    pub needed_bits: f64,
+    // the most guesses a binary search takes over the range
+    pub binary_search: u32,
+    pub wasted: usize,
+    // binary_search over the guesses taken for a win, scaled down by the
+    // share of the bits found for a loss; at most 1
+    pub efficiency: f64,
+}
+
+pub fn summarise(low: u32, high: u32, secret: u32, guesses: &[u32]) -> Summary {
+    let reports = analyse(low, high, secret, guesses);
+    let total_bits: f64 = reports.iter().map(|report| report.bits).sum();
+    let needed_bits = size(low, high).log2();
+    // high - low + 1 numbers, and one more as a guess can also be right
+    let binary_search = bits(u64::from(high - low) + 2);
+    let wasted = reports.iter().filter(|report| report.wasted).count();
+    let won = reports.last().is_some_and(|report| report.outcome == Ordering::Equal);
+
+    let efficiency = match reports.len() {
+        0 => 0.0,
+        taken if won => binary_search as f64 / taken as f64,
+        taken => total_bits / needed_bits * binary_search as f64 / taken as f64,
+    };
+
+    Summary { reports, total_bits, needed_bits, binary_search, wasted, efficiency: efficiency.min(1.0) }
+}
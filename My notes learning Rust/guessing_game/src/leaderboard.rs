@@ -0,0 +1,318 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+use rusqlite::{Connection, params};
+use serde_json::json;
+
+use crate::config::{self, Format};
+use crate::daily::date;
+use crate::i18n::t;
+use crate::scores::{self, Score};
+use crate::storage;
+
+const DATABASE_FILE: &str = "leaderboard.db";
+
+// Each entry moves the schema one version up. The version the database is
+// at lives in SQLite's user_version, so only the missing steps are run.
+// Never edit an entry once released, add a new one instead.
+const MIGRATIONS: &[&str] = &[
+    "CREATE TABLE results (
+        id INTEGER PRIMARY KEY,
+        player TEXT NOT NULL,
+        played_at INTEGER NOT NULL,
+        difficulty TEXT NOT NULL,
+        low INTEGER NOT NULL,
+        high INTEGER NOT NULL,
+        max_attempts INTEGER,
+        attempts INTEGER NOT NULL,
+        won INTEGER NOT NULL,
+        seed INTEGER NOT NULL
+    );",
+    "CREATE INDEX results_by_difficulty ON results (difficulty, won, attempts, played_at);
+     CREATE INDEX results_by_player ON results (player, played_at);
+     CREATE INDEX results_by_time ON results (played_at);",
+    // the same game imported twice is only stored once
+    "CREATE UNIQUE INDEX results_unique ON results (player, played_at, seed);",
+    // where the secret came from; games from a fixed list or dice are kept
+    // but stay off the top, and the same seed on two backends is two games
+    "ALTER TABLE results ADD COLUMN rng TEXT NOT NULL DEFAULT 'chacha';
+     ALTER TABLE results ADD COLUMN verifiable INTEGER NOT NULL DEFAULT 1;
+     DROP INDEX results_unique;
+     CREATE UNIQUE INDEX results_unique ON results (player, played_at, seed, rng);",
+];
+
+pub fn open() -> rusqlite::Result<Connection> {
+    let connection = Connection::open(storage::data_dir().join(DATABASE_FILE))?;
+    migrate(&connection)?;
+    Ok(connection)
+}
+
+fn migrate(connection: &Connection) -> rusqlite::Result<()> {
+    let version: usize = connection.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+
+    for (number, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        let transaction = connection.unchecked_transaction()?;
+        transaction.execute_batch(migration)?;
+        transaction.pragma_update(None, "user_version", number + 1)?;
+        transaction.commit()?;
+    }
+    Ok(())
+}
+
+// Bigger ranges and tighter budgets are harder.
+pub fn difficulty(low: u32, high: u32, max_attempts: Option<u32>) -> String {
+    let size = high.saturating_sub(low).saturating_add(1);
+    let name = match size {
+        0..=50 => "easy",
+        51..=100 => "normal",
+        101..=1000 => "hard",
+        _ => "expert",
+    };
+
+    match max_attempts {
+        Some(max) => format!("{name}/{max}"),
+        None => name.to_string(),
+    }
+}
+
+// Returns false when the same game was already stored.
+pub fn insert(connection: &Connection, score: &Score) -> rusqlite::Result<bool> {
+    let inserted = connection.execute(
+        "INSERT OR IGNORE INTO results
+            (player, played_at, difficulty, low, high, max_attempts, attempts, won, seed, rng, verifiable)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        params![
+            score.player,
+            score.played_at as i64,
+            difficulty(score.low, score.high, score.max_attempts),
+            score.low,
+            score.high,
+            score.max_attempts,
+            score.attempts,
+            score.won,
+            // SQLite integers are signed, the bits are kept as they are
+            score.seed as i64,
+            score.backend.to_string(),
+            score.verifiable(),
+        ],
+    )?;
+    Ok(inserted == 1)
+}
+
+pub fn record(score: &Score) {
+    let stored = open().and_then(|connection| insert(&connection, score));
+    if let Err(error) = stored {
+        println!("{}", t!("leaderboard-update-failed", error = error));
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("The clock is set before 1970")
+        .as_secs() as i64
+}
+
+// The first second that counts for a daily, weekly or all-time board.
+fn window_start(window: &str) -> i64 {
+    match window {
+        // since midnight UTC
+        "daily" => now() / 86_400 * 86_400,
+        "weekly" => now() - 7 * 86_400,
+        _ => 0,
+    }
+}
+
+fn top(connection: &Connection, difficulty: &str, limit: u32, window: &str) -> rusqlite::Result<()> {
+    let mut statement = connection.prepare(
+        "SELECT player, attempts, played_at FROM results
+         WHERE difficulty = ?1 AND won = 1 AND verifiable = 1 AND played_at >= ?2
+         ORDER BY attempts, played_at
+         LIMIT ?3",
+    )?;
+    let rows = statement.query_map(params![difficulty, window_start(window), limit], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, u32>(1)?))
+    })?;
+
+    let rows = rows.collect::<rusqlite::Result<Vec<_>>>()?;
+
+    if config::get().format == Format::Json {
+        let entries: Vec<_> = rows
+            .iter()
+            .enumerate()
+            .map(|(rank, (player, attempts))| json!({ "rank": rank + 1, "player": player, "attempts": attempts }))
+            .collect();
+        println!("{}", json!({ "difficulty": difficulty, "window": window, "top": entries }));
+        return Ok(());
+    }
+
+    println!("{}", t!("leaderboard-top", limit = limit, difficulty = difficulty, window = window));
+    for (rank, (player, attempts)) in rows.iter().enumerate() {
+        println!(
+            "{}",
+            t!("leaderboard-top-row", rank = format!("{:>3}", rank + 1), player = format!("{player:<16}"), count = attempts)
+        );
+    }
+    Ok(())
+}
+
+fn history(connection: &Connection, player: &str) -> rusqlite::Result<()> {
+    let mut statement = connection.prepare(
+        "SELECT played_at, difficulty, attempts, won, rng, verifiable FROM results
+         WHERE player = ?1
+         ORDER BY played_at DESC",
+    )?;
+    let rows = statement.query_map([player], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, u32>(2)?,
+            row.get::<_, bool>(3)?,
+            row.get::<_, String>(4)?,
+            row.get::<_, bool>(5)?,
+        ))
+    })?;
+
+    let rows = rows.collect::<rusqlite::Result<Vec<_>>>()?;
+
+    if config::get().format == Format::Json {
+        let games: Vec<_> = rows
+            .iter()
+            .map(|(played_at, difficulty, attempts, won, rng, verifiable)| {
+                json!({
+                    "played_at": played_at,
+                    "difficulty": difficulty,
+                    "attempts": attempts,
+                    "won": won,
+                    "rng": rng,
+                    "verifiable": verifiable,
+                })
+            })
+            .collect();
+        println!("{}", json!({ "player": player, "games": games }));
+        return Ok(());
+    }
+
+    println!("{}", t!("leaderboard-history", player = player));
+    for (played_at, difficulty, attempts, won, rng, verifiable) in rows {
+        let message = if won { "leaderboard-history-won" } else { "leaderboard-history-lost" };
+        let day = date(played_at as u64 / 86_400);
+        let line = t!(message, day = day, difficulty = format!("{difficulty:<10}"), count = attempts);
+        if verifiable {
+            println!("{line}");
+        } else {
+            println!("{line}  {}", t!("leaderboard-unverifiable", rng = rng));
+        }
+    }
+    Ok(())
+}
+
+// Reads a plain score file, one game per line. Games whose transcript does
+// not add up are left out, the signature is not needed for that.
+fn import(connection: &Connection, path: PathBuf) -> rusqlite::Result<()> {
+    let Ok(text) = fs::read_to_string(&path) else {
+        println!("{}", t!("leaderboard-cannot-read", path = path.display()));
+        return Ok(());
+    };
+
+    let (mut added, mut skipped) = (0, 0);
+    for line in text.lines().filter(|line| !line.is_empty()) {
+        match Score::from_line(line) {
+            Some(score) if score.replay_error().is_none() => {
+                if insert(connection, &score)? {
+                    added += 1;
+                }
+            }
+            _ => skipped += 1,
+        }
+    }
+
+    println!("{} {}", t!("leaderboard-imported", count = added), t!("leaderboard-skipped", count = skipped));
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Every game as CSV, oldest first.
+fn export(connection: &Connection) -> rusqlite::Result<String> {
+    let mut statement = connection.prepare(
+        "SELECT player, played_at, difficulty, low, high, max_attempts, attempts, won, seed, rng
+         FROM results ORDER BY played_at",
+    )?;
+    let mut rows = statement.query([])?;
+
+    let mut csv = String::from("player,played_at,difficulty,low,high,max_attempts,attempts,won,seed,rng\n");
+    while let Some(row) = rows.next()? {
+        let max_attempts: Option<u32> = row.get(5)?;
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{}\n",
+            csv_field(&row.get::<_, String>(0)?),
+            row.get::<_, i64>(1)?,
+            csv_field(&row.get::<_, String>(2)?),
+            row.get::<_, u32>(3)?,
+            row.get::<_, u32>(4)?,
+            max_attempts.map(|max| max.to_string()).unwrap_or_default(),
+            row.get::<_, u32>(6)?,
+            row.get::<_, bool>(7)?,
+            row.get::<_, i64>(8)? as u64,
+            csv_field(&row.get::<_, String>(9)?),
+        ));
+    }
+    Ok(csv)
+}
+
+pub enum Request {
+    Top { difficulty: String, limit: u32, window: String },
+    // the player, yourself by default
+    History(Option<String>),
+    // the score file, scores.txt by default
+    Import(Option<PathBuf>),
+    // where the CSV goes, stdout by default
+    Export(Option<PathBuf>),
+}
+
+// leaderboard top [DIFFICULTY] [N] [daily|weekly|all]
+// leaderboard history [PLAYER]
+// leaderboard import [FILE]
+// leaderboard export [FILE]
+pub fn run(request: Request) {
+    let connection = open().unwrap_or_else(|error| fail(&t!("leaderboard-cannot-open", error = error)));
+
+    let result = match request {
+        Request::History(player) => {
+            let player = player.unwrap_or_else(scores::player_name);
+            history(&connection, &player)
+        }
+        Request::Import(path) => import(&connection, path.unwrap_or_else(scores::path)),
+        Request::Export(path) => export(&connection).map(|csv| {
+            let written = match &path {
+                Some(path) => fs::write(path, csv),
+                None => io::stdout().write_all(csv.as_bytes()),
+            };
+            if let Err(error) = written {
+                let path = path.as_deref().unwrap_or(Path::new("stdout"));
+                fail(&t!("leaderboard-cannot-write", path = path.display(), error = error));
+            }
+        }),
+        Request::Top { difficulty, limit, window } => top(&connection, &difficulty, limit, &window),
+    };
+
+    if let Err(error) = result {
+        fail(&t!("leaderboard-error", error = error));
+    }
+}
+
+// A database or file that lets us down is not the player's mistake: the
+// reason on stderr and exit status 1, like serve failing to listen.
+fn fail(message: &str) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
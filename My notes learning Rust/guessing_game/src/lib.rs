@@ -0,0 +1,28 @@
+// The parts of the game that are plain logic: reading guesses and
+// questions, the solvers, ratings and where secrets come from. Nothing in
+// here prints, reads settings or touches files, so the binary builds on it
+// and the tests use it directly.
+
+pub mod backend;
+pub mod code;
+pub mod elo;
+pub mod hidden;
+pub mod information;
+pub mod parse;
+pub mod prior;
+pub mod query;
+pub mod sorting;
+pub mod strategy;
+pub mod timing;
+pub mod uniformity;
+pub mod word;
+
+// ceil(log2(n)), the yes-or-no answers it takes to pick one of n things:
+// 0 for 1, 6 for 64, 7 for 65. A guess can also be right, so a binary
+// search over n numbers needs bits(n + 1) guesses at most.
+pub fn bits(n: impl Into<u128>) -> u32 {
+    match n.into() {
+        0 | 1 => 0,
+        n => u128::BITS - (n - 1).leading_zeros(),
+    }
+}
@@ -1,37 +1,435 @@
-use std::io;
+use std::io::{self, IsTerminal};
+use std::process;
 use rand::Rng;
 use std::cmp::Ordering;
+use clap::Parser;
+
+mod achievements;
+mod analysis;
+mod batch;
+mod bench;
+mod cli;
+mod config;
+mod coop;
+mod cost;
+mod daily;
+mod drift;
+mod fairness;
+mod grid;
+mod i18n;
+mod infer;
+mod leaderboard;
+mod mastermind;
+mod multi;
+mod rating;
+mod replay;
+mod reverse;
+mod save;
+mod scores;
+mod serve;
+mod session;
+mod skewed;
+mod sort;
+mod stats;
+mod storage;
+mod time_attack;
+mod twenty;
+mod wordle;
+
+// the plain logic lives in the library, see lib.rs
+use guessing_game::{backend, bits, code, elo, hidden, information, parse, prior, query, sorting, strategy, timing, uniformity, word};
+
+use achievements::GameResult;
+use backend::{Backend, Source};
+use cli::{Cli, Command, ConfigCommand, CostKind, DriftKind, GridHint, LeaderboardCommand, Shape, SkewedSolver, SortBot, TimedMode};
+use i18n::t;
+use leaderboard::Request;
+use save::SavedGame;
+use scores::Score;
+use session::Session;
 
 fn main() {
-    println!("Guess the number!");
+    let cli = Cli::parse();
+
+    // the language first, so that a mistake in the config is reported in it
+    let language = cli.settings.lang.clone().or_else(|| config::language(cli.settings.config.as_deref()));
+    i18n::init(language.as_deref());
+
+    // the flags win over the config file
+    let mut settings = config::load(cli.settings.config.as_deref()).unwrap_or_else(|error| config::fail(&error));
+    for (flag, value) in cli.settings.flags() {
+        settings.apply(flag, &value).unwrap_or_else(|error| config::fail(&error));
+    }
+    config::init(settings);
+
+    // no command plays the classic game
+    let command = cli.command.unwrap_or(Command::Play { attempts: None, unsealed: false });
+    match command {
+        Command::Play { attempts, unsealed } => play(attempts, !unsealed),
+        Command::Batch { input, attempts } => batch::run(input.as_deref(), attempts),
+        Command::Coop { players, attempts } => coop::run(&players, attempts),
+        Command::Cost { cost, penalty, solve } => {
+            let cost = match cost {
+                CostKind::Unit => strategy::Cost::Unit,
+                CostKind::Value => strategy::Cost::Value,
+                CostKind::Over => strategy::Cost::Over(penalty),
+            };
+            cost::run(cost, solve)
+        }
+        Command::Drift { step, drift, attempts, simulate, games } => {
+            let distribution = match drift {
+                DriftKind::Uniform => drift::Distribution::Uniform,
+                DriftKind::Triangular => drift::Distribution::Triangular,
+                DriftKind::Lazy => drift::Distribution::Lazy,
+            };
+            let drift = drift::Drift { distribution, step };
+            if simulate { drift::report(drift, games) } else { drift::run(drift, attempts) }
+        }
+        Command::Infer { rounds, solve } => infer::run(rounds, solve),
+        Command::Multi { count, solve, attempts } => multi::run(count, solve, attempts),
+        Command::Skewed { distribution, weights, solve, attempts, simulate } => {
+            let shape = match distribution {
+                Shape::Uniform => prior::Shape::Uniform,
+                Shape::Normal => prior::Shape::Normal,
+                Shape::Geometric => prior::Shape::Geometric,
+                Shape::Zipf => prior::Shape::Zipf,
+            };
+            let solver = solve.map(|solver| match solver {
+                SkewedSolver::Bisection => skewed::Solver::Bisection,
+                SkewedSolver::Median => skewed::Solver::Median,
+                SkewedSolver::Tree => skewed::Solver::Tree,
+            });
+            if simulate {
+                skewed::report(shape, weights.as_deref())
+            } else {
+                skewed::run(shape, weights.as_deref(), solver, attempts)
+            }
+        }
+        Command::Sort { items, bot, simulate, games } => {
+            let bot = bot.map(|bot| match bot {
+                SortBot::MergeInsertion => sorting::Bot::MergeInsertion,
+                SortBot::Merge => sorting::Bot::Merge,
+                SortBot::Insertion => sorting::Bot::Insertion,
+            });
+            if simulate { sort::report(items, games) } else { sort::run(items, bot) }
+        }
+        Command::Twenty { questions, solve } => twenty::run(questions, solve),
+        Command::Reverse => reverse::run(),
+        Command::Daily => daily::run(),
+        Command::Stats => stats::run(),
+        Command::Replay { game } => replay::run(game),
+        Command::Serve { address } => serve::run(&address),
+        Command::Bench { games } => bench::run(games),
+        Command::Fairness { samples } => fairness::run(samples),
+        Command::Resume { name } => resume(name),
+        Command::Adaptive => adaptive(),
+        Command::Grid { solve, width, height, hint } => {
+            let feedback = match hint {
+                GridHint::Direction => grid::Feedback::Direction,
+                GridHint::Distance => grid::Feedback::Distance,
+            };
+            grid::run(solve, width, height, feedback);
+        }
+        Command::Mastermind { solve, length, symbols, attempts, unique, colours } => {
+            let config = code::Config { length, symbols, repeats: !unique, colours };
+            mastermind::run(config, solve, attempts);
+        }
+        Command::Wordle { hard } => wordle::run(hard),
+        Command::Timed { mode, seconds } => {
+            let mode = match mode {
+                TimedMode::Countdown => time_attack::Mode::Countdown,
+                TimedMode::Total => time_attack::Mode::Total,
+                TimedMode::Marathon => time_attack::Mode::Marathon,
+            };
+            time_attack::run(mode, seconds);
+        }
+        Command::Verify => scores::verify(),
+        Command::Leaderboard { command } => {
+            let request = match command {
+                Some(LeaderboardCommand::Top { difficulty, limit, window }) => {
+                    Request::Top { difficulty, limit, window }
+                }
+                None => Request::Top { difficulty: "normal".to_string(), limit: 10, window: "all".to_string() },
+                Some(LeaderboardCommand::History { player }) => Request::History(player),
+                Some(LeaderboardCommand::Import { file }) => Request::Import(file),
+                Some(LeaderboardCommand::Export { file }) => Request::Export(file),
+            };
+            leaderboard::run(request);
+        }
+        Command::Achievements => achievements::list(),
+        Command::Config { command: None | Some(ConfigCommand::Show) } => config::show(),
+    }
+}
+
+// The seed from --seed or the config file, a random one otherwise.
+fn new_seed() -> u64 {
+    let seed = config::get().seed.unwrap_or_else(|| rand::thread_rng().r#gen());
+    announce(seed);
+    seed
+}
+
+// Refuses to run a command the config is fine for but that cannot do what
+// was asked, like a range too wide for a solver: the reason on stderr and
+// exit status 2, like any other usage error.
+fn usage_error(error: &str) -> ! {
+    eprintln!("{}", t!("usage-error", error = error));
+    process::exit(2);
+}
+
+// Tells the player why their guess could not be read.
+fn bad_guess(error: &parse::ParseError) -> String {
+    use parse::ParseError;
+
+    let error = match error {
+        ParseError::Empty => t!("parse-empty"),
+        ParseError::BadNumber(text) => t!("parse-bad-number", text = text),
+        ParseError::UnknownWord(word) => t!("parse-unknown-word", word = word),
+        ParseError::BadWords(text) => t!("parse-bad-words", text = text),
+        ParseError::Unexpected(token) => t!("parse-unexpected", token = token),
+        ParseError::MissingOperand => t!("parse-missing-operand"),
+        ParseError::UnclosedParen => t!("parse-unclosed-paren"),
+        ParseError::DivisionByZero => t!("parse-division-by-zero"),
+        ParseError::OutOfRange => t!("parse-out-of-range", max = u32::MAX),
+        ParseError::TooDeep => t!("parse-too-deep", max = parse::MAX_DEPTH),
+    };
+    t!("bad-guess", error = error)
+}
+
+// The backend and the seed go to stderr as a game starts, so it can be
+// played again with --rng and --seed, and stdout stays the game alone.
+fn announce(seed: u64) {
+    eprintln!("{}", t!("rng-announce", rng = config::get().rng, seed = seed));
+}
+
+// Where every mode draws its secrets from, so --seed replays any of them.
+fn rng() -> Source {
+    config::get().rng.source(new_seed())
+}
+
+// A generator for a mode that draws something other than one secret
+// number at a time: an order, a word, a code, several secrets at once. A
+// fixed list or the host cannot pick those, so fixed and dice are refused.
+fn rng_for(mode: &str) -> Source {
+    let backend = &config::get().rng;
+    if matches!(backend, Backend::Fixed(_) | Backend::Dice) {
+        usage_error(&t!("rng-not-for-mode", mode = mode, rng = backend));
+    }
+    rng()
+}
+
+// A secret number from rng, or from the host with dice.
+fn draw_secret(rng: &mut Source, low: u32, high: u32) -> u32 {
+    if config::get().rng == Backend::Dice { host_secret(low, high) } else { rng.secret(low, high) }
+}
+
+// The secret number of a game seeded with seed, from the chosen backend,
+// or from the host with dice. Recorded scores are checked by replaying
+// the seed through secret_from_seed with the backend the score names.
+fn seeded_secret(seed: u64, low: u32, high: u32) -> u32 {
+    draw_secret(&mut config::get().rng.source(seed), low, high)
+}
+
+// Asks the host for the secret, then clears the screen before the players
+// sit down.
+fn host_secret(low: u32, high: u32) -> u32 {
+    loop {
+        println!("{}", t!("host-secret", low = low, high = high));
+        let Some(input) = read_input() else {
+            println!("{}", t!("host-no-secret"));
+            process::exit(3);
+        };
+        match parse::guess(&input) {
+            Ok(secret) if (low..=high).contains(&secret) => {
+                if io::stdout().is_terminal() {
+                    print!("\x1b[2J\x1b[H");
+                }
+                return secret;
+            }
+            _ => println!("{}", t!("host-bad-secret", low = low, high = high)),
+        }
+    }
+}
+
+// play [ATTEMPTS] [--unsealed]
+fn play(attempts: Option<u32>, sealed: bool) {
+    let settings = config::get();
+    let max_attempts = attempts.or(settings.attempts);
+    let (low, high) = (settings.range.low, settings.range.high);
+
+    println!("{}", t!("guess-the-number"));
+    if (low, high) != (1, 100) {
+        println!("{}", t!("guess-between", low = low, high = high));
+    }
+
+    let game = SavedGame::new(low, high, new_seed(), max_attempts, sealed);
+
+    play_saved(game);
+}
+
+// Picks the range and attempt budget from the player's rating.
+fn adaptive() {
+    let player = rating::get(&scores::player_name());
+    let (range, max_attempts) = elo::pick(player.rating);
+    let chance = elo::expected_win(player.rating, elo::game_rating(range, max_attempts));
+
+    println!("{}", t!("guess-between", low = 1, high = range));
+    println!(
+        "{}",
+        t!(
+            "adaptive-odds",
+            rating = format!("{:.0}", player.rating),
+            count = max_attempts,
+            chance = format!("{:.0}", chance * 100.0)
+        )
+    );
+
+    play_saved(SavedGame::new(1, range, new_seed(), Some(max_attempts), true));
+}
+
+// resume [NAME], the most recent save when no name is given
+fn resume(name: Option<String>) {
+    let Some(name) = name.or_else(save::latest) else {
+        println!("{}", t!("no-saved-game"));
+        return;
+    };
+
+    let game = match save::load(&name) {
+        Ok(game) => game,
+        Err(error) => {
+            println!("{error}");
+            return;
+        }
+    };
+
+    println!("{}", t!("resuming", name = name, low = game.low, high = game.high));
+    print_transcript(game.secret, &game.guesses);
+
+    play_saved(game);
+}
+
+// Shows guesses that were already played, each with its answer.
+fn print_transcript(secret: u32, guesses: &[u32]) {
+    for &guess in guesses {
+        let answer = match judge(guess, secret) {
+            Ordering::Less => t!("too-small"),
+            Ordering::Greater => t!("too-big"),
+            Ordering::Equal => t!("you-win"),
+        };
+        println!("{} {answer}", t!("you-guessed", guess = guess));
+    }
+}
 
-    let secret_number = rand::thread_rng().gen_range(1..=100);
+// Saves after every guess and on Ctrl-C, and forgets the save once the
+// round is over. Closing the input keeps it for a later resume.
+fn play_saved(mut game: SavedGame) {
+    save::save_on_interrupt();
+
+    let mut session = Session::new(game.max_attempts);
+    for _ in &game.guesses {
+        session.attempt();
+    }
+    game.autosave();
+
+    let secret_number = game.secret;
+    let outcomes = guess_number(secret_number, &mut session, &mut ask, &mut |guess| {
+        game.guesses.push(guess);
+        game.autosave();
+    });
+
+    let won = outcomes.last() == Some(&Ordering::Equal);
+    if won || session.is_over() {
+        println!("{}", t!("time-played", seconds = format!("{:.0}", game.elapsed().as_secs_f64())));
+        analysis::print(game.low, game.high, game.secret, &game.guesses);
+        let score = Score::new(&game.backend, game.seed, game.low, game.high, game.max_attempts, &game.guesses, won);
+        scores::record(&score);
+        leaderboard::record(&score);
+        if let Some(max_attempts) = game.max_attempts {
+            rating::update(&score.player, game.high - game.low + 1, max_attempts, won);
+        }
+        achievements::announce(&GameResult {
+            mode: "classic",
+            won,
+            attempts: session.attempts(),
+            range: game.high - game.low + 1,
+            day: None,
+        });
+        game.remove();
+    }
+}
+
+// Plays one round against secret_number and returns how every guess
+// compared, so the caller can tell a win from a loss or a closed input.
+// next_input gives the lines to read, ask for the player at the terminal.
+// on_guess sees every accepted guess before it is judged.
+fn guess_number(
+    secret_number: u32,
+    session: &mut Session,
+    next_input: &mut dyn FnMut() -> Option<String>,
+    on_guess: &mut dyn FnMut(u32),
+) -> Vec<Ordering> {
+    let mut outcomes = Vec::new();
 
     loop {
-        println!("Please enter your guess :");
+        if session.is_over() {
+            println!("{}", t!("you-lose", secret = secret_number));
+            break;
+        }
 
-        let mut guess = String::new();
+        let Some(guess) = next_input() else { break };
 
-        io::stdin() // or std::io::stdin() if io is not imported before
-            .read_line(&mut guess)
-            .expect("Failed to read line");
-        
-        let guess: u32 = match guess.trim().parse() {
+        let guess = match parse::guess(&guess) {
             Ok(num) => num,
-            Err(_) => continue,
+            Err(error) => {
+                println!("{}", bad_guess(&error));
+                continue;
+            }
         };
 
-        println!("You guessed: {guess}");
-        
-        match guess.cmp(&secret_number) {
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
+        println!("{}", t!("you-guessed", guess = guess));
+        session.attempt();
+        on_guess(guess);
+
+        let outcome = judge(guess, secret_number);
+        outcomes.push(outcome);
+
+        match outcome {
+            Ordering::Less => println!("{}", t!("too-small")),
+            Ordering::Greater => println!("{}", t!("too-big")),
             Ordering::Equal => {
-                println!("You win!");
+                println!("{}", t!("you-win"));
                 break;
             }
         }
     }
+
+    outcomes
+}
+
+// The one place a guess is compared with the secret, shared by the game
+// loop and by the score checker that replays finished games.
+fn judge(guess: u32, secret_number: u32) -> Ordering {
+    guess.cmp(&secret_number)
+}
+
+// The seeded generators give the same secret for the same seed on every
+// machine, which is what lets a recorded game be played again.
+fn secret_from_seed(backend: &Backend, seed: u64, low: u32, high: u32) -> u32 {
+    backend.source(seed).secret(low, high)
+}
+
+// Prompts for a guess and reads it.
+fn ask() -> Option<String> {
+    println!("{}", t!("enter-guess"));
+    read_input()
 }
 
+// Reads one line from stdin, None once the input is closed.
+fn read_input() -> Option<String> {
+    let mut input = String::new();
 
+    let bytes = io::stdin() // or std::io::stdin() if io is not imported before
+        .read_line(&mut input)
+        .expect("Failed to read line");
+
+    if bytes == 0 { None } else { Some(input) }
+}
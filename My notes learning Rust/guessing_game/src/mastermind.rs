@@ -0,0 +1,101 @@
+use crate::code::{Config, MAX_LENGTH, MAX_SYMBOLS, MIN_SYMBOLS, Solver, score};
+use crate::config;
+use crate::i18n::t;
+use crate::read_input;
+use crate::session::Session;
+
+// mastermind [--solve] [--length N] [--symbols N] [--attempts N] [--unique] [--colours]
+pub fn run(config: Config, solving: bool, max_attempts: u32) {
+    if !(1..=MAX_LENGTH).contains(&config.length) {
+        crate::usage_error(&t!("mastermind-bad-length", max = MAX_LENGTH, length = config.length));
+    }
+    if !(MIN_SYMBOLS..=MAX_SYMBOLS).contains(&config.symbols) {
+        crate::usage_error(&t!(
+            "mastermind-bad-symbols",
+            min = MIN_SYMBOLS,
+            max = MAX_SYMBOLS,
+            symbols = config.symbols
+        ));
+    }
+    if !config.repeats && config.length > config.symbols {
+        crate::usage_error(&t!("mastermind-too-few-symbols", count = config.length));
+    }
+
+    if solving {
+        solve(&config);
+    } else {
+        play(&config, max_attempts);
+    }
+}
+
+fn play(config: &Config, max_attempts: u32) {
+    let alphabet: String = config.alphabet()[..config.symbols].iter().collect();
+    println!("{}", t!("mastermind-intro", count = config.length, alphabet = alphabet));
+    if !config.repeats {
+        println!("{}", t!("mastermind-unique"));
+    }
+    println!("{}", t!("hint-available"));
+
+    let secret = config.random_code(&mut crate::rng_for("mastermind"));
+    let mut session = Session::new(Some(max_attempts));
+    let hint_cost = config::get().hints.mastermind;
+    let mut revealed = 0;
+
+    loop {
+        if session.is_over() {
+            println!("{}", t!("mastermind-you-lose", code = config.show(&secret)));
+            break;
+        }
+
+        println!("{}", t!("enter-guess"));
+
+        let Some(input) = read_input() else { return };
+
+        // each hint shows the next position of the code, left to right
+        if matches!(input.trim(), "hint" | "?") && revealed < config.length {
+            let symbol = config.show(&secret[revealed..=revealed]);
+            println!("{}", t!("mastermind-hint", position = revealed + 1, symbol = symbol));
+            revealed += 1;
+            session.spend(hint_cost);
+            println!("{}", t!("hint-cost", count = hint_cost));
+            continue;
+        }
+
+        let guess = match config.parse(&input) {
+            Some(code) => code,
+            None => {
+                println!("{}", t!("mastermind-bad-code", count = config.length, alphabet = alphabet));
+                continue;
+            }
+        };
+
+        session.attempt();
+
+        let (exact, partial) = score(&guess, &secret);
+        if exact as usize == config.length {
+            println!("{}", t!("you-win-in", count = session.attempts()));
+            break;
+        }
+        println!("{}", t!("mastermind-score", exact = exact, partial = partial));
+        println!("{}", t!("attempts-left", count = session.left().unwrap_or(0)));
+    }
+}
+
+// Lets the solver crack a random code and shows each step.
+fn solve(config: &Config) {
+    let secret = config.random_code(&mut crate::rng_for("mastermind"));
+    let mut solver = Solver::new(config);
+    println!("{}", t!("mastermind-secret", code = config.show(&secret)));
+
+    for attempt in 1.. {
+        let guess = solver.next_guess();
+        let result = score(&guess, &secret);
+        let score = t!("mastermind-score", exact = result.0, partial = result.1);
+        let candidates = t!("mastermind-candidates", count = solver.candidates());
+        println!("{attempt}: {} -> {score} {candidates}", config.show(&guess));
+        if result.0 as usize == config.length {
+            break;
+        }
+        solver.record(&guess, result);
+    }
+}
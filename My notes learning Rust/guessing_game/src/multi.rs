@@ -0,0 +1,101 @@
+use rand::seq::index;
+
+use crate::config;
+use crate::hidden::{self, Knowledge, Solver};
+use crate::i18n::t;
+use crate::session::Session;
+use crate::{parse, read_input};
+
+// The solver weighs every number of the range for every guess.
+const MAX_SOLVED_RANGE: u32 = 100_000;
+
+// One line with what is known about each secret, smallest first: a range
+// while it is unsure, the number once known, and a tick once guessed.
+fn render(knowledge: &Knowledge) -> String {
+    let parts: Vec<String> = knowledge
+        .bounds
+        .iter()
+        .zip(&knowledge.found)
+        .enumerate()
+        .map(|(i, (&(low, high), &found))| match (found, low == high) {
+            (true, _) => format!("#{} {low} ✓", i + 1),
+            (false, true) => format!("#{} {low}", i + 1),
+            (false, false) => format!("#{} {low}-{high}", i + 1),
+        })
+        .collect();
+    parts.join("   ")
+}
+
+fn show_answer(answer: &hidden::Answer) -> String {
+    t!("multi-answer", below = answer.below, equal = usize::from(answer.equal), above = answer.above)
+}
+
+// multi [--count K] [--solve] [--attempts N]: K different secrets in the
+// range. Each guess says how many are below it, at it and above it, and
+// every one of them has to be guessed.
+pub fn run(count: usize, solving: bool, max_attempts: Option<u32>) {
+    let settings = config::get();
+    let (low, high) = (settings.range.low, settings.range.high);
+    let size = high - low + 1;
+    if count == 0 || count as u64 > u64::from(size) {
+        crate::usage_error(&t!("multi-bad-count", count = count, low = low, high = high));
+    }
+    if solving && size > MAX_SOLVED_RANGE {
+        crate::usage_error(&t!("multi-too-wide", max = MAX_SOLVED_RANGE, low = low, high = high));
+    }
+
+    let mut secrets: Vec<u32> = index::sample(&mut crate::rng_for("multi"), size as usize, count)
+        .into_iter()
+        .map(|offset| low + offset as u32)
+        .collect();
+    secrets.sort_unstable();
+
+    println!("{}", t!("multi-intro", count = count, low = low, high = high));
+    let mut knowledge = Knowledge::new(count, low, high);
+
+    if solving {
+        let mut solver = Solver::new(low, high);
+        let mut guesses = 0;
+        while !knowledge.is_solved() {
+            let guess = solver.next_guess(&knowledge);
+            let answer = hidden::answer(&secrets, guess);
+            knowledge.record(guess, &answer);
+            guesses += 1;
+            println!("{guesses}: {guess} -> {}   {}", show_answer(&answer), render(&knowledge));
+        }
+        println!("{}", t!("multi-found-all", count = guesses));
+        return;
+    }
+
+    let mut session = Session::new(max_attempts.or(settings.attempts));
+    println!("{}", render(&knowledge));
+    loop {
+        if session.is_over() {
+            let secrets: Vec<String> = secrets.iter().map(u32::to_string).collect();
+            println!("{}", t!("multi-you-lose", secrets = secrets.join(", ")));
+            return;
+        }
+
+        println!("{}", t!("enter-guess"));
+        let Some(input) = read_input() else { return };
+        let guess = match parse::guess(&input) {
+            Ok(num) => num,
+            Err(error) => {
+                println!("{}", crate::bad_guess(&error));
+                continue;
+            }
+        };
+
+        session.attempt();
+        let answer = hidden::answer(&secrets, guess);
+        knowledge.record(guess, &answer);
+        println!("{}", t!("you-guessed", guess = guess));
+        println!("{}", show_answer(&answer));
+        println!("{}", render(&knowledge));
+
+        if knowledge.is_solved() {
+            println!("{}", t!("multi-found-all", count = session.attempts()));
+            return;
+        }
+    }
+}
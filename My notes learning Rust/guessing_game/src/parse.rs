@@ -0,0 +1,327 @@
+use std::fmt;
+use std::num::IntErrorKind;
+
+// Why a guess could not be read. The game puts it into words for the player,
+// in their language.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    Empty,
+    // a literal like 0x, 12ab or 1__000
+    BadNumber(String),
+    UnknownWord(String),
+    // known words in an order that makes no number, like "two five"
+    BadWords(String),
+    Unexpected(String),
+    MissingOperand,
+    UnclosedParen,
+    DivisionByZero,
+    // negative, or too big for a guess
+    OutOfRange,
+    // more brackets or signs inside each other than MAX_DEPTH
+    TooDeep,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Token {
+    Number(i64),
+    Op(char),
+    Open,
+    Close,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{n}"),
+            Token::Op(op) => write!(f, "{op}"),
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+        }
+    }
+}
+
+// Words that add up; "vingt" after "quatre" and the scale words multiply.
+const NUMBER_WORDS: &[(&str, i64)] = &[
+    ("zero", 0), ("one", 1), ("two", 2), ("three", 3), ("four", 4),
+    ("five", 5), ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9),
+    ("ten", 10), ("eleven", 11), ("twelve", 12), ("thirteen", 13), ("fourteen", 14),
+    ("fifteen", 15), ("sixteen", 16), ("seventeen", 17), ("eighteen", 18), ("nineteen", 19),
+    ("twenty", 20), ("thirty", 30), ("forty", 40), ("fifty", 50), ("sixty", 60),
+    ("seventy", 70), ("eighty", 80), ("ninety", 90),
+    // "six" is spelled the same in French
+    ("zéro", 0), ("un", 1), ("une", 1), ("deux", 2), ("trois", 3), ("quatre", 4),
+    ("cinq", 5), ("sept", 7), ("huit", 8), ("neuf", 9),
+    ("dix", 10), ("onze", 11), ("douze", 12), ("treize", 13), ("quatorze", 14),
+    ("quinze", 15), ("seize", 16), ("vingt", 20), ("vingts", 20), ("trente", 30),
+    ("quarante", 40), ("cinquante", 50), ("soixante", 60), ("septante", 70), ("huitante", 80),
+    ("octante", 80), ("nonante", 90),
+];
+
+const SCALE_WORDS: &[(&str, i64)] = &[
+    ("hundred", 100), ("cent", 100), ("cents", 100),
+    ("thousand", 1_000), ("mille", 1_000),
+    ("million", 1_000_000), ("millions", 1_000_000),
+    ("billion", 1_000_000_000), ("milliard", 1_000_000_000), ("milliards", 1_000_000_000),
+];
+
+// Every bracket and every sign in front of a number is a level of
+// recursion, so a long enough run of them would overflow the stack.
+pub const MAX_DEPTH: usize = 100;
+
+// May sit between any two words, for "one hundred and two" or "vingt et un".
+const JOINERS: [&str; 2] = ["and", "et"];
+
+// Reads a guess: a plain or prefixed number (0x3F, 0o17, 0b1010_1010,
+// 1_000), an expression like (100-1)/2, or a number in English or French
+// words. Division rounds towards zero.
+pub fn guess(input: &str) -> Result<u32, ParseError> {
+    let tokens = tokens(input)?;
+    if tokens.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut parser = Parser { tokens, next: 0, depth: 0 };
+    let value = parser.sum()?;
+    if let Some(token) = parser.peek() {
+        return Err(ParseError::Unexpected(token.to_string()));
+    }
+
+    u32::try_from(value).map_err(|_| ParseError::OutOfRange)
+}
+
+fn tokens(input: &str) -> Result<Vec<Token>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            tokens.push(Token::Number(literal(&text)?));
+        } else if c.is_alphabetic() {
+            // Words run on over spaces and single hyphens while another
+            // word follows, so "forty two" is one number but "ten - two" is
+            // a subtraction.
+            loop {
+                while i < chars.len() && chars[i].is_alphabetic() {
+                    i += 1;
+                }
+                let mut next = i;
+                while next < chars.len() && (chars[next].is_whitespace() || chars[next] == '-') {
+                    next += 1;
+                }
+                let gap: String = chars[i..next].iter().collect();
+                let joined = gap == "-" || !gap.contains('-');
+                if next < chars.len() && chars[next].is_alphabetic() && joined {
+                    i = next;
+                } else {
+                    break;
+                }
+            }
+            let text: String = chars[start..i].iter().collect();
+            tokens.push(Token::Number(words(&text)?));
+        } else {
+            tokens.push(match c {
+                '+' | '-' | '*' | '/' => Token::Op(c),
+                '(' => Token::Open,
+                ')' => Token::Close,
+                _ => return Err(ParseError::Unexpected(c.to_string())),
+            });
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+// A number starting with a digit. Underscores may only sit between digits.
+fn literal(text: &str) -> Result<i64, ParseError> {
+    let bad = || ParseError::BadNumber(text.to_string());
+    let lower = text.to_ascii_lowercase();
+
+    let (digits, radix) = match lower.get(..2) {
+        Some("0x") => (&lower[2..], 16),
+        Some("0o") => (&lower[2..], 8),
+        Some("0b") => (&lower[2..], 2),
+        _ => (&lower[..], 10),
+    };
+    if digits.is_empty() || digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+        return Err(bad());
+    }
+
+    i64::from_str_radix(&digits.replace('_', ""), radix).map_err(|error| match error.kind() {
+        IntErrorKind::PosOverflow => ParseError::OutOfRange,
+        _ => bad(),
+    })
+}
+
+// Adds the words up the way they are spoken, checking that each one can
+// follow the one before: "forty two" and "soixante-dix-sept" read fine,
+// "two five" and "twenty thirty" do not.
+fn words(text: &str) -> Result<i64, ParseError> {
+    let bad = || ParseError::BadWords(text.to_string());
+    let words: Vec<String> = text
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    // whatever is complete, like the thousands in "two thousand and six"
+    let mut total = 0;
+    // the part below the next scale word
+    let mut current: i64 = 0;
+    // the last word added to `current`, to check what may follow it
+    let mut last: Option<i64> = None;
+    let mut last_scale = i64::MAX;
+
+    for (i, word) in words.iter().enumerate() {
+        let previous = if i > 0 { words[i - 1].as_str() } else { "" };
+
+        if JOINERS.contains(&word.as_str()) {
+            let between = i > 0 && i + 1 < words.len() && !JOINERS.contains(&previous);
+            if !between {
+                return Err(bad());
+            }
+            continue;
+        }
+
+        if let Some(&(_, value)) = NUMBER_WORDS.iter().find(|(name, _)| name == word) {
+            if value == 0 && words.len() > 1 {
+                return Err(bad());
+            }
+            // quatre-vingt is four twenties
+            if value == 20 && previous == "quatre" {
+                current += 80 - 4;
+                last = Some(80);
+                continue;
+            }
+            let fits = match last {
+                None => true,
+                Some(last) if last % 100 == 0 => value < 100,
+                // twenty-one, vingt et un
+                Some(last) if last % 10 == 0 && last >= 20 && value < 10 => true,
+                // soixante-douze, quatre-vingt-dix
+                Some(60 | 80) => (10..20).contains(&value),
+                // dix-sept
+                Some(10) => previous == "dix" && value < 10,
+                _ => false,
+            };
+            if !fits {
+                return Err(bad());
+            }
+            current += value;
+            last = Some(value);
+        } else if let Some(&(_, scale)) = SCALE_WORDS.iter().find(|(name, _)| name == word) {
+            if scale == 100 {
+                // "twelve hundred" is fine, "one hundred two hundred" is not
+                if current >= 100 || matches!(last, Some(100)) {
+                    return Err(bad());
+                }
+                current = current.max(1) * 100;
+                last = Some(100);
+            } else {
+                if scale >= last_scale {
+                    return Err(bad());
+                }
+                total += current.max(1) * scale;
+                current = 0;
+                last = Some(scale);
+                last_scale = scale;
+            }
+        } else {
+            return Err(ParseError::UnknownWord(word.clone()));
+        }
+    }
+
+    if words.is_empty() {
+        return Err(bad());
+    }
+    Ok(total + current)
+}
+
+// sum = product (('+' | '-') product)*
+// product = factor (('*' | '/') factor)*
+// factor = ('+' | '-') factor | number | '(' sum ')'
+struct Parser {
+    tokens: Vec<Token>,
+    next: usize,
+    // factors we are inside of
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.next).copied()
+    }
+
+    fn take(&mut self) -> Option<Token> {
+        let token = self.peek();
+        self.next += 1;
+        token
+    }
+
+    fn sum(&mut self) -> Result<i64, ParseError> {
+        let mut value = self.product()?;
+
+        while let Some(Token::Op(op @ ('+' | '-'))) = self.peek() {
+            self.next += 1;
+            let right = self.product()?;
+            let result = if op == '+' { value.checked_add(right) } else { value.checked_sub(right) };
+            value = result.ok_or(ParseError::OutOfRange)?;
+        }
+        Ok(value)
+    }
+
+    fn product(&mut self) -> Result<i64, ParseError> {
+        let mut value = self.factor()?;
+
+        while let Some(Token::Op(op @ ('*' | '/'))) = self.peek() {
+            self.next += 1;
+            let right = self.factor()?;
+            value = if op == '*' {
+                value.checked_mul(right).ok_or(ParseError::OutOfRange)?
+            } else if right == 0 {
+                return Err(ParseError::DivisionByZero);
+            } else {
+                value.checked_div(right).ok_or(ParseError::OutOfRange)?
+            };
+        }
+        Ok(value)
+    }
+
+    fn factor(&mut self) -> Result<i64, ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(ParseError::TooDeep);
+        }
+        self.depth += 1;
+        let value = self.nested();
+        self.depth -= 1;
+        value
+    }
+
+    fn nested(&mut self) -> Result<i64, ParseError> {
+        match self.take() {
+            None => Err(ParseError::MissingOperand),
+            Some(Token::Number(n)) => Ok(n),
+            Some(Token::Op('-')) => self.factor()?.checked_neg().ok_or(ParseError::OutOfRange),
+            Some(Token::Op('+')) => self.factor(),
+            Some(Token::Open) => {
+                let value = self.sum()?;
+                match self.take() {
+                    Some(Token::Close) => Ok(value),
+                    Some(token) => Err(ParseError::Unexpected(token.to_string())),
+                    None => Err(ParseError::UnclosedParen),
+                }
+            }
+            Some(token) => Err(ParseError::Unexpected(token.to_string())),
+        }
+    }
+}
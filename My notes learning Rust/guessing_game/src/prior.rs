@@ -0,0 +1,242 @@
+use rand::Rng;
+
+// Secrets that are not equally likely: the shapes they can be drawn from,
+// solvers that make use of knowing it, and how well a few draws point at
+// the shape.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shape {
+    Uniform,
+    // a bell around the middle, three deviations to either end
+    Normal,
+    // halving every tenth of the range, from the low end
+    Geometric,
+    // the k-th number from the low end weighs 1/k
+    Zipf,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 4] = [Shape::Uniform, Shape::Normal, Shape::Geometric, Shape::Zipf];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Shape::Uniform => "uniform",
+            Shape::Normal => "normal",
+            Shape::Geometric => "geometric",
+            Shape::Zipf => "zipf",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Shape> {
+        Shape::ALL.into_iter().find(|shape| shape.name() == name)
+    }
+}
+
+// How likely each number of low..=high is to be the secret.
+pub struct Prior {
+    pub low: u32,
+    // sums to 1
+    chances: Vec<f64>,
+    // sums[k]: chance of the k numbers from low
+    sums: Vec<f64>,
+}
+
+impl Prior {
+    pub fn new(shape: Shape, low: u32, high: u32) -> Prior {
+        let size = (high - low + 1) as f64;
+        let weights = (0..=high - low).map(|k| {
+            let k = k as f64;
+            match shape {
+                Shape::Uniform => 1.0,
+                Shape::Normal => {
+                    let deviation = (size / 6.0).max(0.5);
+                    let z = (k - (size - 1.0) / 2.0) / deviation;
+                    (-z * z / 2.0).exp()
+                }
+                Shape::Geometric => 0.5f64.powf(k / (size / 10.0).max(1.0)),
+                Shape::Zipf => 1.0 / (k + 1.0),
+            }
+        });
+        Prior::from(low, weights.collect())
+    }
+
+    // One "number weight" pair per line, # starts a comment. Numbers left
+    // out weigh nothing.
+    pub fn parse(text: &str, low: u32, high: u32) -> Result<Prior, String> {
+        let mut weights = vec![0.0; (high - low + 1) as usize];
+        for (line, content) in text.lines().enumerate() {
+            let content = content.split('#').next().unwrap_or("").trim();
+            if content.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = content.split_whitespace().collect();
+            let [number, weight] = fields[..] else {
+                return Err(format!("line {}: expected a number and its weight", line + 1));
+            };
+            let number: u32 = number.parse().map_err(|_| format!("line {}: {number} is not a number", line + 1))?;
+            let weight: f64 = weight.parse().map_err(|_| format!("line {}: {weight} is not a weight", line + 1))?;
+            if !(low..=high).contains(&number) {
+                return Err(format!("line {}: {number} is outside {low}..={high}", line + 1));
+            }
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(format!("line {}: a weight cannot be {weight}", line + 1));
+            }
+            weights[(number - low) as usize] += weight;
+        }
+        if weights.iter().sum::<f64>() <= 0.0 {
+            return Err("every number weighs nothing".to_string());
+        }
+        Ok(Prior::from(low, weights))
+    }
+
+    fn from(low: u32, weights: Vec<f64>) -> Prior {
+        let total: f64 = weights.iter().sum();
+        let chances: Vec<f64> = weights.iter().map(|weight| weight / total).collect();
+        let mut sums = vec![0.0; chances.len() + 1];
+        for (k, chance) in chances.iter().enumerate() {
+            sums[k + 1] = sums[k] + chance;
+        }
+        Prior { low, chances, sums }
+    }
+
+    pub fn high(&self) -> u32 {
+        self.low + self.chances.len() as u32 - 1
+    }
+
+    pub fn chance(&self, number: u32) -> f64 {
+        self.chances[(number - self.low) as usize]
+    }
+
+    // Chance the secret is in low..=high.
+    pub fn mass(&self, low: u32, high: u32) -> f64 {
+        self.sums[(high - self.low) as usize + 1] - self.sums[(low - self.low) as usize]
+    }
+
+    pub fn sample(&self, rng: &mut impl Rng) -> u32 {
+        let target = rng.r#gen::<f64>();
+        // the first number whose running sum passes target, skipping those
+        // that weigh nothing
+        let k = self.sums[1..].partition_point(|&sum| sum <= target).min(self.chances.len() - 1);
+        let k = (k..self.chances.len()).chain((0..k).rev()).find(|&k| self.chances[k] > 0.0).unwrap_or(k);
+        self.low + k as u32
+    }
+
+    // The number that splits what is left of the chance in half, bisection
+    // by weight rather than by count.
+    pub fn median(&self, low: u32, high: u32) -> u32 {
+        let half = self.sums[(low - self.low) as usize] + self.mass(low, high) / 2.0;
+        let k = self.sums[(low - self.low) as usize + 1..=(high - self.low) as usize].partition_point(|&sum| sum < half);
+        low + k as u32
+    }
+
+    // How surprising the secret is, in bits.
+    pub fn entropy(&self) -> f64 {
+        self.chances.iter().filter(|&&chance| chance > 0.0).map(|chance| -chance * chance.log2()).sum()
+    }
+
+    // log of how likely the prior makes these draws, -inf if one of them
+    // cannot happen.
+    pub fn log_likelihood(&self, draws: &[u32]) -> f64 {
+        draws.iter().map(|&draw| self.chance(draw).ln()).sum()
+    }
+}
+
+// The guesses that take the fewest on average for a prior: the optimal
+// binary search tree, where every guess is a node and the secrets are
+// found at the nodes. Knuth's bound on where the best root can be keeps it
+// quadratic.
+pub struct Tree {
+    low: u32,
+    size: usize,
+    // root of every part low..=high, a triangle with one row per low end
+    roots: Vec<u32>,
+    expected: f64,
+}
+
+impl Tree {
+    pub fn new(prior: &Prior) -> Tree {
+        let size = prior.chances.len();
+        let cells = size * (size + 1) / 2;
+        let mut tree = Tree { low: prior.low, size, roots: vec![0; cells], expected: 0.0 };
+        // cost[index]: expected guesses of the part, weighted by its chance
+        let mut cost = vec![0.0; cells];
+
+        for length in 1..=size {
+            for first in 0..=size - length {
+                let last = first + length - 1;
+                let (from, to) = if length == 1 {
+                    (first, first)
+                } else {
+                    (tree.root(first, last - 1), tree.root(first + 1, last))
+                };
+
+                let mut best = (f64::INFINITY, from);
+                for k in from..=to {
+                    let below = if k > first { cost[tree.index(first, k - 1)] } else { 0.0 };
+                    let above = if k < last { cost[tree.index(k + 1, last)] } else { 0.0 };
+                    if below + above < best.0 {
+                        best = (below + above, k);
+                    }
+                }
+
+                let index = tree.index(first, last);
+                cost[index] = best.0 + prior.sums[last + 1] - prior.sums[first];
+                tree.roots[index] = best.1 as u32;
+            }
+        }
+        tree.expected = cost[tree.index(0, size - 1)];
+        tree
+    }
+
+    // The same triangle as in strategy.rs, one row per first number.
+    fn index(&self, first: usize, last: usize) -> usize {
+        first * self.size - first * first.saturating_sub(1) / 2 + (last - first)
+    }
+
+    fn root(&self, first: usize, last: usize) -> usize {
+        self.roots[self.index(first, last)] as usize
+    }
+
+    pub fn next_guess(&self, low: u32, high: u32) -> u32 {
+        self.low + self.root((low - self.low) as usize, (high - self.low) as usize) as u32
+    }
+
+    pub fn expected(&self) -> f64 {
+        self.expected
+    }
+}
+
+// Guesses next_guess needs to find secret in low..=high.
+pub fn guesses(secret: u32, low: u32, high: u32, next_guess: &dyn Fn(u32, u32) -> u32) -> u32 {
+    let (mut low, mut high) = (low, high);
+    let mut guesses = 1;
+    loop {
+        let guess = next_guess(low, high);
+        if guess == secret {
+            return guesses;
+        }
+        if guess < secret {
+            low = guess + 1;
+        } else {
+            high = guess - 1;
+        }
+        guesses += 1;
+    }
+}
+
+// Average guesses over the prior.
+pub fn expected_guesses(prior: &Prior, next_guess: &dyn Fn(u32, u32) -> u32) -> f64 {
+    (prior.low..=prior.high())
+        .map(|secret| prior.chance(secret) * guesses(secret, prior.low, prior.high(), next_guess) as f64)
+        .sum()
+}
+
+// How likely each shape is after these draws, starting from all of them
+// being as likely.
+pub fn posterior(low: u32, high: u32, draws: &[u32]) -> Vec<(Shape, f64)> {
+    let logs: Vec<f64> = Shape::ALL.iter().map(|&shape| Prior::new(shape, low, high).log_likelihood(draws)).collect();
+    let top = logs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let weights: Vec<f64> = logs.iter().map(|log| (log - top).exp()).collect();
+    let total: f64 = weights.iter().sum();
+    Shape::ALL.iter().zip(weights).map(|(&shape, weight)| (shape, weight / total)).collect()
+}
@@ -0,0 +1,499 @@
+use std::fmt;
+
+// Yes/no questions about the secret for the twenty questions mode: a small
+// language, its parser and evaluator, and a questioner that picks the most
+// telling question.
+
+// Why a question could not be read. The game puts it into words for the
+// player, in their language.
+#[derive(Debug, PartialEq)]
+pub enum QueryError {
+    Empty,
+    UnknownWord(String),
+    Unexpected(String),
+    // the question stops where something was still needed
+    Missing(Needed),
+    BadNumber(String),
+    EmptyRange(u32, u32),
+    DivisionByZero,
+    // more than MAX_DEPTH levels of not, and, or and brackets
+    TooDeep,
+}
+
+// What a question that stops too early still needed.
+#[derive(Debug, PartialEq)]
+pub enum Needed {
+    // a word or symbol of the question language, like "by" or ")"
+    Word(&'static str),
+    Number,
+    Comparison,
+    Question,
+    RangeEnd,
+}
+
+// A number worked out from the secret, to compare with a constant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Measure {
+    Number,
+    DigitSum,
+    Digits,
+    LastDigit,
+}
+
+impl Measure {
+    fn of(self, n: u32) -> u32 {
+        match self {
+            Measure::Number => n,
+            Measure::DigitSum => n.to_string().bytes().map(|digit| (digit - b'0') as u32).sum(),
+            Measure::Digits => n.to_string().len() as u32,
+            Measure::LastDigit => n % 10,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Query {
+    // both ends included
+    In(u32, u32),
+    Even,
+    Odd,
+    Prime,
+    Square,
+    DivisibleBy(u32),
+    Compare(Measure, Comparison, u32),
+    Not(Box<Query>),
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+}
+
+fn is_prime(n: u32) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n as u64).all(|d| !(n as u64).is_multiple_of(d))
+}
+
+impl Query {
+    pub fn eval(&self, n: u32) -> bool {
+        match self {
+            Query::In(low, high) => (*low..=*high).contains(&n),
+            Query::Even => n.is_multiple_of(2),
+            Query::Odd => !n.is_multiple_of(2),
+            Query::Prime => is_prime(n),
+            Query::Square => {
+                let root = (n as f64).sqrt() as u64;
+                (root.saturating_sub(1)..=root + 1).any(|r| r * r == n as u64)
+            }
+            Query::DivisibleBy(d) => n.is_multiple_of(*d),
+            Query::Compare(measure, comparison, value) => {
+                let measured = measure.of(n);
+                match comparison {
+                    Comparison::Less => measured < *value,
+                    Comparison::LessOrEqual => measured <= *value,
+                    Comparison::Greater => measured > *value,
+                    Comparison::GreaterOrEqual => measured >= *value,
+                    Comparison::Equal => measured == *value,
+                    Comparison::NotEqual => measured != *value,
+                }
+            }
+            Query::Not(query) => !query.eval(n),
+            Query::And(left, right) => left.eval(n) && right.eval(n),
+            Query::Or(left, right) => left.eval(n) || right.eval(n),
+        }
+    }
+
+    // A guess is a question about one number, won on a yes.
+    pub fn guessed(&self) -> Option<u32> {
+        match self {
+            Query::Compare(Measure::Number, Comparison::Equal, n) => Some(*n),
+            Query::In(low, high) if low == high => Some(*low),
+            _ => None,
+        }
+    }
+}
+
+// Written back the way the parser reads it, with brackets around any
+// and/or inside another one so the meaning never changes.
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let inner = |query: &Query| match query {
+            Query::And(..) | Query::Or(..) => format!("({query})"),
+            _ => query.to_string(),
+        };
+
+        match self {
+            Query::In(low, high) => write!(f, "in {low}..{high}"),
+            Query::Even => write!(f, "even"),
+            Query::Odd => write!(f, "odd"),
+            Query::Prime => write!(f, "prime"),
+            Query::Square => write!(f, "square"),
+            Query::DivisibleBy(d) => write!(f, "divisible by {d}"),
+            Query::Compare(measure, comparison, value) => {
+                let measure = match measure {
+                    Measure::Number => "",
+                    Measure::DigitSum => "digit sum ",
+                    Measure::Digits => "digits ",
+                    Measure::LastDigit => "last digit ",
+                };
+                let comparison = match comparison {
+                    Comparison::Less => "<",
+                    Comparison::LessOrEqual => "<=",
+                    Comparison::Greater => ">",
+                    Comparison::GreaterOrEqual => ">=",
+                    Comparison::Equal => "=",
+                    Comparison::NotEqual => "!=",
+                };
+                write!(f, "{measure}{comparison} {value}")
+            }
+            Query::Not(query) => write!(f, "not {}", inner(query)),
+            Query::And(left, right) => write!(f, "{} and {}", inner(left), inner(right)),
+            Query::Or(left, right) => write!(f, "{} or {}", inner(left), inner(right)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Number(u32),
+    Symbol(&'static str),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "{word}"),
+            Token::Number(n) => write!(f, "{n}"),
+            Token::Symbol(symbol) => write!(f, "{symbol}"),
+        }
+    }
+}
+
+// Longest first, so <= is not read as < followed by =.
+const SYMBOLS: [&str; 10] = ["..", "<=", ">=", "!=", "<", ">", "=", "(", ")", "-"];
+
+// Every not, bracket, and or or is a level of the question's tree, and
+// parsing, answering, printing and dropping it all recurse down it.
+pub const MAX_DEPTH: usize = 100;
+
+// Words that only make the question read like English.
+const FILLERS: [&str; 6] = ["is", "it", "its", "the", "a", "number"];
+
+fn tokens(input: &str) -> Result<Vec<Token>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut rest = input.trim().trim_end_matches('?');
+
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if c.is_ascii_digit() {
+            let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+            let number = rest[..end].parse().map_err(|_| QueryError::BadNumber(rest[..end].to_string()))?;
+            tokens.push(Token::Number(number));
+            rest = &rest[end..];
+        } else if c.is_alphabetic() {
+            let end = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+            let word = rest[..end].to_lowercase();
+            if !FILLERS.contains(&word.as_str()) {
+                tokens.push(Token::Word(word));
+            }
+            rest = &rest[end..];
+        } else if let Some(symbol) = SYMBOLS.iter().find(|symbol| rest.starts_with(**symbol)) {
+            tokens.push(Token::Symbol(symbol));
+            rest = &rest[symbol.len()..];
+        } else {
+            return Err(QueryError::Unexpected(c.to_string()));
+        }
+    }
+    Ok(tokens)
+}
+
+// Reads a question like "is it in 20..40?", "is it even?", "is it prime?",
+// "is it divisible by 7?", "is its digit sum > 10?" or "is it 42?", joined
+// with and, or, not and brackets.
+//
+// question = or
+// or = and ("or" and)*
+// and = not ("and" not)*
+// not = "not" not | "(" or ")" | atom
+pub fn question(input: &str) -> Result<Query, QueryError> {
+    let tokens = tokens(input)?;
+    if tokens.is_empty() {
+        return Err(QueryError::Empty);
+    }
+
+    let mut parser = Parser { tokens, next: 0, depth: 0 };
+    let query = parser.or()?;
+    match parser.peek() {
+        Some(token) => Err(QueryError::Unexpected(token.to_string())),
+        None => Ok(query),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    next: usize,
+    // how far down the tree the part being read sits
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next)
+    }
+
+    fn take(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.next).cloned();
+        self.next += 1;
+        token
+    }
+
+    // Takes the word if it comes next.
+    fn word(&mut self, word: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Word(next)) if next == word);
+        if found {
+            self.next += 1;
+        }
+        found
+    }
+
+    fn expect_word(&mut self, word: &'static str) -> Result<(), QueryError> {
+        match self.take() {
+            Some(Token::Word(next)) if next == word => Ok(()),
+            Some(token) => Err(QueryError::Unexpected(token.to_string())),
+            None => Err(QueryError::Missing(Needed::Word(word))),
+        }
+    }
+
+    fn number(&mut self) -> Result<u32, QueryError> {
+        match self.take() {
+            Some(Token::Number(n)) => Ok(n),
+            Some(token) => Err(QueryError::Unexpected(token.to_string())),
+            None => Err(QueryError::Missing(Needed::Number)),
+        }
+    }
+
+    // One level further down, or an error past MAX_DEPTH.
+    fn deeper(&mut self) -> Result<(), QueryError> {
+        if self.depth == MAX_DEPTH {
+            return Err(QueryError::TooDeep);
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    // A chain like "a or b or c" leans left, so each link puts everything
+    // before it one level further down.
+    fn or(&mut self) -> Result<Query, QueryError> {
+        let depth = self.depth;
+        let mut query = self.and()?;
+        while self.word("or") {
+            self.deeper()?;
+            query = Query::Or(Box::new(query), Box::new(self.and()?));
+        }
+        self.depth = depth;
+        Ok(query)
+    }
+
+    fn and(&mut self) -> Result<Query, QueryError> {
+        let depth = self.depth;
+        let mut query = self.not()?;
+        while self.word("and") {
+            self.deeper()?;
+            query = Query::And(Box::new(query), Box::new(self.not()?));
+        }
+        self.depth = depth;
+        Ok(query)
+    }
+
+    fn not(&mut self) -> Result<Query, QueryError> {
+        if self.word("not") {
+            self.deeper()?;
+            let query = Query::Not(Box::new(self.not()?));
+            self.depth -= 1;
+            return Ok(query);
+        }
+        if self.peek() == Some(&Token::Symbol("(")) {
+            self.next += 1;
+            self.deeper()?;
+            let query = self.or()?;
+            self.depth -= 1;
+            return match self.take() {
+                Some(Token::Symbol(")")) => Ok(query),
+                Some(token) => Err(QueryError::Unexpected(token.to_string())),
+                None => Err(QueryError::Missing(Needed::Word(")"))),
+            };
+        }
+        self.atom()
+    }
+
+    // low..high, low-high, or "low to high"
+    fn range(&mut self, low: u32) -> Result<Query, QueryError> {
+        let high = self.number()?;
+        if low > high {
+            return Err(QueryError::EmptyRange(low, high));
+        }
+        Ok(Query::In(low, high))
+    }
+
+    // What a measure is compared with: a symbol, or words like "less than".
+    fn comparison(&mut self) -> Result<Comparison, QueryError> {
+        let comparison = match self.take() {
+            Some(Token::Symbol("<")) => Comparison::Less,
+            Some(Token::Symbol("<=")) => Comparison::LessOrEqual,
+            Some(Token::Symbol(">")) => Comparison::Greater,
+            Some(Token::Symbol(">=")) => Comparison::GreaterOrEqual,
+            Some(Token::Symbol("=")) => Comparison::Equal,
+            Some(Token::Symbol("!=")) => Comparison::NotEqual,
+            Some(Token::Word(word)) if word == "less" || word == "below" || word == "under" => {
+                self.word("than");
+                Comparison::Less
+            }
+            Some(Token::Word(word)) if word == "greater" || word == "more" || word == "above" || word == "over" => {
+                self.word("than");
+                Comparison::Greater
+            }
+            Some(Token::Word(word)) if word == "at" => {
+                if self.word("least") {
+                    Comparison::GreaterOrEqual
+                } else {
+                    self.expect_word("most")?;
+                    Comparison::LessOrEqual
+                }
+            }
+            Some(Token::Word(word)) if word == "equal" || word == "equals" => {
+                self.word("to");
+                Comparison::Equal
+            }
+            // "is it 42?"
+            Some(Token::Number(_)) => {
+                self.next -= 1;
+                Comparison::Equal
+            }
+            Some(token) => return Err(QueryError::Unexpected(token.to_string())),
+            None => return Err(QueryError::Missing(Needed::Comparison)),
+        };
+        Ok(comparison)
+    }
+
+    fn compare(&mut self, measure: Measure) -> Result<Query, QueryError> {
+        let comparison = self.comparison()?;
+        Ok(Query::Compare(measure, comparison, self.number()?))
+    }
+
+    fn atom(&mut self) -> Result<Query, QueryError> {
+        let Some(token) = self.take() else {
+            return Err(QueryError::Missing(Needed::Question));
+        };
+        let word = match token {
+            Token::Word(word) => word,
+            // "is it 20..40?" or "is it 20-40?"
+            Token::Number(low) if matches!(self.peek(), Some(Token::Symbol(".." | "-"))) => {
+                self.next += 1;
+                return self.range(low);
+            }
+            Token::Number(_) | Token::Symbol(_) => {
+                self.next -= 1;
+                return self.compare(Measure::Number);
+            }
+        };
+
+        match word.as_str() {
+            "in" | "between" | "from" => {
+                let low = self.number()?;
+                match self.take() {
+                    Some(Token::Symbol(".." | "-")) => {}
+                    Some(Token::Word(word)) if word == "and" || word == "to" => {}
+                    Some(token) => return Err(QueryError::Unexpected(token.to_string())),
+                    None => return Err(QueryError::Missing(Needed::RangeEnd)),
+                }
+                self.range(low)
+            }
+            "even" => Ok(Query::Even),
+            "odd" => Ok(Query::Odd),
+            "prime" => Ok(Query::Prime),
+            "square" => Ok(Query::Square),
+            "divisible" | "multiple" => {
+                if word == "divisible" {
+                    self.expect_word("by")?;
+                } else {
+                    self.expect_word("of")?;
+                }
+                match self.number()? {
+                    0 => Err(QueryError::DivisionByZero),
+                    d => Ok(Query::DivisibleBy(d)),
+                }
+            }
+            "digit" => {
+                self.expect_word("sum")?;
+                self.compare(Measure::DigitSum)
+            }
+            "digits" => self.compare(Measure::Digits),
+            "last" => {
+                self.expect_word("digit")?;
+                self.compare(Measure::LastDigit)
+            }
+            "less" | "below" | "under" | "greater" | "more" | "above" | "over" | "at" | "equal" | "equals" => {
+                self.next -= 1;
+                self.compare(Measure::Number)
+            }
+            _ => Err(QueryError::UnknownWord(word)),
+        }
+    }
+}
+
+// How many bits a yes/no answer is expected to give when `yes` of `total`
+// equally likely numbers would say yes.
+fn information(yes: usize, total: usize) -> f64 {
+    [yes, total - yes]
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let chance = count as f64 / total as f64;
+            -chance * chance.log2()
+        })
+        .sum()
+}
+
+// Questions the questioner chooses from, the friendlier ones first so they
+// win ties: a guess, then parity and other properties, then cut points at
+// evenly spaced places among the candidates, which always include a split
+// into two halves.
+fn questions(candidates: &[u32]) -> Vec<Query> {
+    let mut questions = vec![Query::Compare(Measure::Number, Comparison::Equal, candidates[0])];
+    questions.extend([Query::Even, Query::Prime, Query::Square]);
+    questions.extend((3..=12).map(Query::DivisibleBy));
+    questions.extend((1..=45).map(|sum| Query::Compare(Measure::DigitSum, Comparison::Greater, sum)));
+    questions.extend((1..=8).map(|last| Query::Compare(Measure::LastDigit, Comparison::Greater, last)));
+
+    let cuts = 64.min(candidates.len());
+    questions.extend(
+        (1..cuts)
+            .map(|i| candidates[i * candidates.len() / cuts] - 1)
+            .chain([candidates[(candidates.len() - 1) / 2]])
+            .map(|high| Query::In(candidates[0], high)),
+    );
+    questions
+}
+
+// The question that tells the most about a secret known to be one of the
+// candidates, which must be sorted and not empty: the one whose yes and no
+// split them most evenly. With one candidate left it is the winning guess.
+pub fn best_question(candidates: &[u32]) -> Query {
+    let mut best = (f64::NEG_INFINITY, Query::Even);
+    for query in questions(candidates) {
+        let yes = candidates.iter().filter(|&&n| query.eval(n)).count();
+        let bits = information(yes, candidates.len());
+        if bits > best.0 + 1e-9 {
+            best = (bits, query);
+        }
+    }
+    best.1
+}
@@ -0,0 +1,66 @@
+use std::fs;
+
+use crate::elo;
+use crate::i18n::t;
+use crate::storage;
+
+const RATINGS_FILE: &str = "ratings.txt";
+
+pub struct Rating {
+    pub player: String,
+    pub rating: f64,
+    pub games: u32,
+}
+
+fn load() -> Vec<Rating> {
+    let text = fs::read_to_string(storage::data_dir().join(RATINGS_FILE)).unwrap_or_default();
+
+    text.lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\t');
+            Some(Rating {
+                player: parts.next()?.to_string(),
+                rating: parts.next()?.parse().ok()?,
+                games: parts.next()?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+fn save(ratings: &[Rating]) {
+    let text: String = ratings
+        .iter()
+        .map(|r| format!("{}\t{:.1}\t{}\n", r.player, r.rating, r.games))
+        .collect();
+    fs::write(storage::data_dir().join(RATINGS_FILE), text).expect("Failed to save ratings");
+}
+
+pub fn get(player: &str) -> Rating {
+    load()
+        .into_iter()
+        .find(|r| r.player == player)
+        .unwrap_or(Rating { player: player.to_string(), rating: elo::START_RATING, games: 0 })
+}
+
+// Updates the player's rating after a game with an attempt budget and
+// prints the change.
+pub fn update(player: &str, range: u32, max_attempts: u32, won: bool) {
+    let mut ratings = load();
+    let index = match ratings.iter().position(|r| r.player == player) {
+        Some(index) => index,
+        None => {
+            ratings.push(get(player));
+            ratings.len() - 1
+        }
+    };
+
+    let entry = &mut ratings[index];
+    let change = elo::change(entry.rating, entry.games, elo::game_rating(range, max_attempts), won);
+
+    entry.rating += change;
+    entry.games += 1;
+    let rating = format!("{:.0}", entry.rating);
+    println!("{}", t!("rating-change", rating = rating, change = format!("{change:+.0}")));
+
+    save(&ratings);
+}
@@ -0,0 +1,33 @@
+use crate::i18n::t;
+use crate::{analysis, daily, print_transcript, scores};
+
+// replay [N]: plays the Nth most recent recorded game again from its seed
+// and transcript, then shows the same breakdown as at the end of a round.
+pub fn run(game: usize) {
+    let scores = scores::load();
+
+    let Some(score) = game.checked_sub(1).and_then(|back| scores.iter().rev().nth(back)) else {
+        println!("{}", t!("replay-no-game", game = game, count = scores.len()));
+        return;
+    };
+
+    println!(
+        "{}",
+        t!("replay-title", player = score.player, date = daily::date(score.played_at / 86_400), seed = score.seed)
+    );
+    println!("{}", t!("guess-between", low = score.low, high = score.high));
+    let Some(secret) = score.secret() else {
+        println!("{}", t!("replay-secret-unknown"));
+        return;
+    };
+
+    print_transcript(secret, &score.guesses);
+    if !score.won {
+        println!("{}", t!("you-lose", secret = secret));
+    }
+    if let Some(problem) = score.replay_error() {
+        println!("{}", t!("replay-does-not-add-up", problem = problem));
+    }
+
+    analysis::print(score.low, score.high, secret, &score.guesses);
+}
@@ -0,0 +1,46 @@
+use crate::config;
+use crate::i18n::t;
+use crate::read_input;
+
+// The guess that splits low..=high in two, the best the computer can do
+// when every number is as likely as the others.
+pub fn next_guess(low: u32, high: u32) -> u32 {
+    low + (high - low) / 2
+}
+
+// reverse: the player keeps a number in mind and answers each guess with
+// higher, lower or yes. Answers that leave no number possible are caught.
+pub fn run() {
+    let range = config::get().range;
+    let (mut low, mut high) = (range.low, range.high);
+    let mut attempts = 0;
+
+    println!("{}", t!("reverse-intro", low = low, high = high));
+    println!("{}", t!("reverse-answers"));
+
+    loop {
+        let guess = next_guess(low, high);
+        println!("{}", t!("reverse-guess", guess = guess));
+
+        let Some(input) = read_input() else { return };
+        let answer = input.trim().to_lowercase();
+        attempts += 1;
+
+        match answer.as_str() {
+            "y" | "yes" | "=" | "o" | "oui" | "نعم" => {
+                println!("{}", t!("reverse-found", count = attempts));
+                return;
+            }
+            "h" | "higher" | "+" | "plus" | "أكبر" if guess < high => low = guess + 1,
+            "l" | "lower" | "-" | "moins" | "أصغر" if guess > low => high = guess - 1,
+            "h" | "higher" | "+" | "plus" | "أكبر" | "l" | "lower" | "-" | "moins" | "أصغر" => {
+                println!("{}", t!("reverse-cheating", low = low, high = high));
+                return;
+            }
+            _ => {
+                attempts -= 1;
+                println!("{}", t!("reverse-answers"));
+            }
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use rand::Rng;
+
+use crate::backend::Backend;
+use crate::i18n::t;
+use crate::config::{self, Range};
+use crate::{secret_from_seed, seeded_secret, storage};
+
+// The game Ctrl-C should save, updated after every guess.
+static CURRENT: Mutex<Option<SavedGame>> = Mutex::new(None);
+
+// A classic round that can be stopped and picked up later. Saves are small
+// text files of key=value lines in the data directory, one per game.
+#[derive(Clone)]
+pub struct SavedGame {
+    pub name: String,
+    pub low: u32,
+    pub high: u32,
+    pub seed: u64,
+    // kept so the finished game is recorded with where its secret came from
+    pub backend: Backend,
+    pub secret: u32,
+    pub max_attempts: Option<u32>,
+    pub guesses: Vec<u32>,
+    pub sealed: bool,
+    // time played before this run, plus when this run started
+    elapsed: Duration,
+    resumed_at: Instant,
+}
+
+impl SavedGame {
+    pub fn new(low: u32, high: u32, seed: u64, max_attempts: Option<u32>, sealed: bool) -> SavedGame {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("The clock is set before 1970");
+
+        SavedGame {
+            name: format!("game-{}", now.as_secs()),
+            low,
+            high,
+            seed,
+            backend: config::get().rng.clone(),
+            secret: seeded_secret(seed, low, high),
+            max_attempts,
+            guesses: Vec::new(),
+            sealed,
+            elapsed: Duration::ZERO,
+            resumed_at: Instant::now(),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed + self.resumed_at.elapsed()
+    }
+
+    // A sealed secret keeps a curious player opening the file from reading
+    // the answer at a glance. This is obfuscation, not security. The seed
+    // gives the secret away too, so it is sealed the same way.
+    pub fn to_text(&self) -> String {
+        let seal = |value: u64| {
+            if self.sealed {
+                let salt: u64 = rand::thread_rng().r#gen();
+                format!("sealed:{salt:016x}:{:016x}", value ^ mix(salt))
+            } else {
+                value.to_string()
+            }
+        };
+        let seed = seal(self.seed);
+        let secret = seal(self.secret as u64);
+        let max_attempts = match self.max_attempts {
+            Some(max) => max.to_string(),
+            None => "none".to_string(),
+        };
+        let guesses: Vec<String> = self.guesses.iter().map(u32::to_string).collect();
+
+        format!(
+            "range={}..={}\nseed={seed}\nrng={}\nsecret={secret}\nmax_attempts={max_attempts}\nguesses={}\nelapsed={:.3}\n",
+            self.low,
+            self.high,
+            self.backend,
+            guesses.join(","),
+            self.elapsed().as_secs_f64()
+        )
+    }
+
+    pub fn from_text(name: &str, text: &str) -> Result<SavedGame, String> {
+        let field = |key: &str| {
+            text.lines()
+                .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+                .ok_or(format!("missing {key}"))
+        };
+        let number = |value: &str| value.parse::<u32>().map_err(|_| format!("bad number {value}"));
+
+        let (low, high) = field("range")?
+            .split_once("..=")
+            .ok_or("bad range".to_string())?;
+
+        let unsealed = |key: &str| -> Result<u64, String> {
+            let value = field(key)?;
+            match value.strip_prefix("sealed:") {
+                Some(sealed) => unseal(sealed).ok_or(format!("bad sealed {key}")),
+                None => value.parse().map_err(|_| format!("bad {key}")),
+            }
+        };
+        // saves from before the field were all chacha
+        let backend = match field("rng") {
+            Ok(rng) => rng.parse().map_err(|_| format!("bad rng {rng}"))?,
+            Err(_) => Backend::ChaCha,
+        };
+        let sealed = field("secret")?.starts_with("sealed:");
+        let seed = unsealed("seed")?;
+        let secret = u32::try_from(unsealed("secret")?).map_err(|_| "bad secret".to_string())?;
+
+        let max_attempts = match field("max_attempts")? {
+            "none" => None,
+            max => Some(number(max)?),
+        };
+
+        let guesses = field("guesses")?
+            .split(',')
+            .filter(|guess| !guess.is_empty())
+            .map(number)
+            .collect::<Result<Vec<u32>, String>>()?;
+
+        let elapsed: f64 = field("elapsed")?.parse().map_err(|_| "bad elapsed time".to_string())?;
+        let elapsed = Duration::try_from_secs_f64(elapsed).map_err(|_| "bad elapsed time".to_string())?;
+
+        // a hand-edited file must still be a game that could have been played
+        let Range { low, high } = Range::try_from([number(low)?, number(high)?])?;
+        let drawn = match backend {
+            Backend::Dice => (low..=high).contains(&secret),
+            _ => secret == secret_from_seed(&backend, seed, low, high),
+        };
+        if !drawn {
+            return Err("the secret does not belong to the game".to_string());
+        }
+        if max_attempts.is_some_and(|max| guesses.len() > max as usize) {
+            return Err("more guesses than attempts".to_string());
+        }
+
+        Ok(SavedGame {
+            name: name.to_string(),
+            low,
+            high,
+            seed,
+            backend,
+            secret,
+            max_attempts,
+            guesses,
+            sealed,
+            elapsed,
+            resumed_at: Instant::now(),
+        })
+    }
+
+    fn write(&self) -> io::Result<()> {
+        fs::write(path(&self.name), self.to_text())
+    }
+
+    // Writes the save and remembers it for the Ctrl-C handler.
+    pub fn autosave(&self) {
+        self.write().expect("Failed to save the game");
+        *CURRENT.lock().unwrap() = Some(self.clone());
+    }
+
+    // A finished game has nothing left to resume.
+    pub fn remove(&self) {
+        let _ = fs::remove_file(path(&self.name));
+        *CURRENT.lock().unwrap() = None;
+    }
+}
+
+// SplitMix64, enough to turn the salt into a key that looks random.
+fn mix(salt: u64) -> u64 {
+    let mut z = salt.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn unseal(sealed: &str) -> Option<u64> {
+    let (salt, value) = sealed.split_once(':')?;
+    let salt = u64::from_str_radix(salt, 16).ok()?;
+    let value = u64::from_str_radix(value, 16).ok()?;
+    Some(value ^ mix(salt))
+}
+
+fn saves_dir() -> PathBuf {
+    let dir = storage::data_dir().join("saves");
+    fs::create_dir_all(&dir).expect("Failed to create the saves directory");
+    dir
+}
+
+fn path(name: &str) -> PathBuf {
+    saves_dir().join(format!("{name}.save"))
+}
+
+pub fn load(name: &str) -> Result<SavedGame, String> {
+    let text = fs::read_to_string(path(name)).map_err(|_| t!("no-save-called", name = name))?;
+    SavedGame::from_text(name, &text).map_err(|error| t!("save-damaged", name = name, error = error))
+}
+
+// The name of the save written last, if there is any.
+pub fn latest() -> Option<String> {
+    fs::read_dir(saves_dir())
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "save"))
+        .max_by_key(|entry| entry.metadata().and_then(|meta| meta.modified()).ok())
+        .and_then(|entry| Some(entry.path().file_stem()?.to_str()?.to_string()))
+}
+
+// On Ctrl-C the game in progress is saved with its elapsed time first.
+pub fn save_on_interrupt() {
+    ctrlc::set_handler(|| {
+        if let Some(game) = CURRENT.lock().unwrap().as_ref() {
+            let _ = game.write();
+            println!();
+            println!("{}", t!("game-saved", name = game.name));
+        }
+        process::exit(130);
+    })
+    .expect("Failed to set the Ctrl-C handler");
+}
+
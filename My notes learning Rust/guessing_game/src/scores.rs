@@ -0,0 +1,300 @@
+use std::cmp::Ordering;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+
+use crate::backend::Backend;
+use crate::i18n::t;
+use crate::{config, judge, secret_from_seed, storage};
+
+const SCORES_FILE: &str = "scores.txt";
+const KEY_FILE: &str = "scores.key";
+
+type HmacSha256 = Hmac<Sha256>;
+
+// One finished classic round. The seed, the backend and every guess are
+// kept so the claimed result can be checked by playing the round again.
+pub struct Score {
+    pub player: String,
+    pub played_at: u64,
+    pub seed: u64,
+    pub low: u32,
+    pub high: u32,
+    pub max_attempts: Option<u32>,
+    pub guesses: Vec<u32>,
+    pub attempts: u32,
+    pub won: bool,
+    // where the secret came from, chacha for entries older than the field
+    pub backend: Backend,
+}
+
+impl Score {
+    pub fn new(
+        backend: &Backend,
+        seed: u64,
+        low: u32,
+        high: u32,
+        max_attempts: Option<u32>,
+        guesses: &[u32],
+        won: bool,
+    ) -> Score {
+        let played_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("The clock is set before 1970")
+            .as_secs();
+
+        Score {
+            player: player_name(),
+            played_at,
+            seed,
+            low,
+            high,
+            max_attempts,
+            guesses: guesses.to_vec(),
+            attempts: guesses.len() as u32,
+            won,
+            backend: backend.clone(),
+        }
+    }
+
+    // Tab separated, the same text the signature is computed over.
+    fn fields(&self) -> String {
+        let max_attempts = match self.max_attempts {
+            Some(max) => max.to_string(),
+            None => "none".to_string(),
+        };
+        let guesses: Vec<String> = self.guesses.iter().map(u32::to_string).collect();
+        let outcome = if self.won { "won" } else { "lost" };
+
+        format!(
+            "{}\t{}\t{}\t{}..={}\t{max_attempts}\t{}\t{}\t{outcome}\t{}",
+            self.player,
+            self.played_at,
+            self.seed,
+            self.low,
+            self.high,
+            guesses.join(","),
+            self.attempts,
+            self.backend
+        )
+    }
+
+    // A line of the score file, with or without its signature. Older lines
+    // stop at the outcome, a signature is never a backend name.
+    pub fn from_line(line: &str) -> Option<Score> {
+        let parts: Vec<&str> = line.split('\t').collect();
+        let count = if parts.get(8).is_some_and(|rng| rng.parse::<Backend>().is_ok()) { 9 } else { 8 };
+        Score::parse(&parts[..count.min(parts.len())].join("\t"))
+    }
+
+    fn parse(fields: &str) -> Option<Score> {
+        let parts: Vec<&str> = fields.split('\t').collect();
+        let (backend, parts) = match parts[..] {
+            [ref fields @ .., rng] if fields.len() == 8 => (rng.parse().ok()?, fields),
+            _ => (Backend::ChaCha, &parts[..]),
+        };
+        let [player, played_at, seed, range, max_attempts, guesses, attempts, outcome] = parts[..] else {
+            return None;
+        };
+        let (low, high) = range.split_once("..=")?;
+
+        let score = Score {
+            player: player.to_string(),
+            played_at: played_at.parse().ok()?,
+            seed: seed.parse().ok()?,
+            low: low.parse().ok()?,
+            high: high.parse().ok()?,
+            max_attempts: match max_attempts {
+                "none" => None,
+                max => Some(max.parse().ok()?),
+            },
+            guesses: guesses
+                .split(',')
+                .filter(|guess| !guess.is_empty())
+                .map(|guess| guess.parse().ok())
+                .collect::<Option<Vec<u32>>>()?,
+            attempts: attempts.parse().ok()?,
+            won: match outcome {
+                "won" => true,
+                "lost" => false,
+                _ => return None,
+            },
+            backend,
+        };
+        Some(score)
+    }
+
+    // Only a secret drawn from a seeded generator says anything about the
+    // player. With a fixed list or dice it was known to someone.
+    pub fn verifiable(&self) -> bool {
+        self.backend.is_seeded()
+    }
+
+    // The secret of the round, worked out again from the backend and the
+    // seed. The host's number in a dice game is only known when it was
+    // found.
+    pub fn secret(&self) -> Option<u32> {
+        if self.backend == Backend::Dice {
+            return if self.won { self.guesses.last().copied() } else { None };
+        }
+        Some(secret_from_seed(&self.backend, self.seed, self.low, self.high))
+    }
+
+    // Plays the transcript again against the secret the seed gives and
+    // says what does not add up, if anything.
+    pub fn replay_error(&self) -> Option<String> {
+        if self.attempts as usize != self.guesses.len() {
+            return Some(t!("scores-attempts-mismatch", count = self.attempts, actual = self.guesses.len()));
+        }
+
+        // a lost dice game only has its counts to check
+        let won = match self.secret() {
+            Some(secret) => {
+                let outcomes: Vec<Ordering> = self.guesses.iter().map(|&guess| judge(guess, secret)).collect();
+                if let Some(position) = outcomes.iter().position(|&outcome| outcome == Ordering::Equal)
+                    && position + 1 < outcomes.len()
+                {
+                    return Some(t!("scores-after-win", guess = position + 1));
+                }
+                outcomes.last() == Some(&Ordering::Equal)
+            }
+            None => self.won,
+        };
+        if self.won != won {
+            return Some(if self.won { t!("scores-claims-win") } else { t!("scores-claims-loss") });
+        }
+        if !won && self.max_attempts.is_some_and(|max| self.attempts < max) {
+            return Some(t!("scores-loss-with-attempts-left"));
+        }
+        if self.max_attempts.is_some_and(|max| self.attempts > max) {
+            return Some(t!("scores-too-many-attempts"));
+        }
+        None
+    }
+}
+
+pub fn player_name() -> String {
+    if let Some(player) = &config::get().player {
+        return player.clone();
+    }
+    env::var("USER")
+        .or_else(|_| env::var("USERNAME"))
+        .unwrap_or_else(|_| "player".to_string())
+}
+
+// The key lives next to the scores and is made on first use. Anyone who
+// can read it can still forge entries, but hand edits to the score file
+// no longer go unnoticed.
+fn key() -> Vec<u8> {
+    let path = storage::data_dir().join(KEY_FILE);
+
+    if let Ok(key) = fs::read(&path)
+        && !key.is_empty()
+    {
+        return key;
+    }
+
+    let mut key = vec![0u8; 32];
+    rand::thread_rng().fill_bytes(&mut key);
+    fs::write(&path, &key).expect("Failed to create the score key");
+    key
+}
+
+fn sign(key: &[u8], fields: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC takes keys of any size");
+    mac.update(fields.as_bytes());
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn check_signature(key: &[u8], fields: &str, signature: &str) -> bool {
+    let Some(bytes) = (0..signature.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(signature.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()
+    else {
+        return false;
+    };
+
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC takes keys of any size");
+    mac.update(fields.as_bytes());
+    // verify_slice compares in constant time
+    mac.verify_slice(&bytes).is_ok()
+}
+
+pub fn path() -> PathBuf {
+    storage::data_dir().join(SCORES_FILE)
+}
+
+pub fn record(score: &Score) {
+    if !score.verifiable() {
+        println!("{}", t!("scores-unverifiable", rng = score.backend));
+    }
+    let fields = score.fields();
+    let signature = sign(&key(), &fields);
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path())
+        .expect("Failed to open the score file");
+
+    writeln!(file, "{fields}\t{signature}").expect("Failed to save the score");
+}
+
+// Every entry that can be read, oldest first. Signatures are not checked
+// here, that is what verify is for.
+pub fn load() -> Vec<Score> {
+    let text = fs::read_to_string(path()).unwrap_or_default();
+    text.lines().filter_map(Score::from_line).collect()
+}
+
+// Checks every entry and exits with 1 when any of them is flagged.
+pub fn verify() {
+    let Ok(text) = fs::read_to_string(path()) else {
+        println!("{}", t!("scores-none"));
+        return;
+    };
+    let key = key();
+    let mut flagged = 0;
+
+    for (number, line) in text.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+        let number = number + 1;
+
+        let checked = match line.rsplit_once('\t') {
+            Some((fields, signature)) if check_signature(&key, fields, signature) => {
+                Score::parse(fields).ok_or_else(|| t!("scores-unreadable"))
+            }
+            Some(_) => Err(t!("scores-bad-signature")),
+            None => Err(t!("scores-unreadable")),
+        }
+        .and_then(|score| score.replay_error().map_or(Ok(score), Err));
+
+        match checked {
+            Err(problem) => {
+                flagged += 1;
+                println!("{}", t!("scores-line-flagged", line = number, problem = problem));
+            }
+            // it adds up, but says nothing about the player
+            Ok(score) if !score.verifiable() => {
+                println!("{}", t!("scores-line-unverifiable", line = number, rng = score.backend));
+            }
+            Ok(_) => println!("{}", t!("scores-line-ok", line = number)),
+        }
+    }
+
+    if flagged > 0 {
+        println!("{}", t!("scores-flagged", count = flagged));
+        process::exit(1);
+    }
+    println!("{}", t!("scores-all-ok"));
+}
@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process;
+use std::thread;
+
+use crate::backend::Backend;
+use crate::i18n::t;
+use crate::session::Session;
+use crate::{config, judge, new_seed, parse, seeded_secret};
+
+// No guess needs more, and a client sending an endless line would
+// otherwise have the server keep all of it.
+const MAX_LINE: usize = 1024;
+
+// serve [--address HOST:PORT]: every connection plays its own classic
+// game, in the range and with the attempts of the config. Try it with
+// `nc 127.0.0.1 7878`.
+pub fn run(address: &str) {
+    // every connection has a thread of its own, and they cannot all ask
+    // the host at the server's keyboard
+    if config::get().rng == Backend::Dice {
+        crate::usage_error(&t!("serve-no-dice"));
+    }
+    let listener = match TcpListener::bind(address) {
+        Ok(listener) => listener,
+        Err(error) => {
+            eprintln!("{}", t!("serve-cannot-listen", address = address, error = error));
+            process::exit(1);
+        }
+    };
+    // the port the system picked when asked for port 0
+    let address = listener.local_addr().map(|address| address.to_string()).unwrap_or(address.to_string());
+    println!("{}", t!("serve-listening", address = address));
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                thread::spawn(move || {
+                    let peer = stream.peer_addr().map(|peer| peer.to_string()).unwrap_or_default();
+                    match host(stream) {
+                        Ok(Some(attempts)) => println!("{}", t!("serve-won", peer = peer, count = attempts)),
+                        Ok(None) => println!("{}", t!("serve-left", peer = peer)),
+                        Err(error) => println!("{}", t!("serve-error", peer = peer, error = error)),
+                    }
+                });
+            }
+            Err(error) => println!("{}", t!("serve-connection-failed", error = error)),
+        }
+    }
+}
+
+// Plays one game over the connection, the attempts it took when won.
+fn host(stream: TcpStream) -> io::Result<Option<u32>> {
+    let mut out = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+
+    let settings = config::get();
+    let (low, high) = (settings.range.low, settings.range.high);
+    let secret = seeded_secret(new_seed(), low, high);
+    let mut session = Session::new(settings.attempts);
+
+    writeln!(out, "{}", t!("guess-the-number"))?;
+    writeln!(out, "{}", t!("guess-between", low = low, high = high))?;
+
+    loop {
+        if session.is_over() {
+            writeln!(out, "{}", t!("you-lose", secret = secret))?;
+            return Ok(None);
+        }
+        writeln!(out, "{}", t!("enter-guess"))?;
+
+        let line = match read_line(&mut reader)? {
+            Some(line) => line,
+            None => return Ok(None),
+        };
+        let guess = match parse::guess(&line) {
+            Ok(guess) => guess,
+            Err(error) => {
+                writeln!(out, "{}", crate::bad_guess(&error))?;
+                continue;
+            }
+        };
+        session.attempt();
+
+        match judge(guess, secret) {
+            Ordering::Less => writeln!(out, "{}", t!("too-small"))?,
+            Ordering::Greater => writeln!(out, "{}", t!("too-big"))?,
+            Ordering::Equal => {
+                writeln!(out, "{}", t!("you-win-in", count = session.attempts()))?;
+                return Ok(Some(session.attempts()));
+            }
+        }
+    }
+}
+
+// One line of at most MAX_LINE bytes, None once the client hangs up. A
+// longer line is an error, which drops the connection.
+fn read_line(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut line = String::new();
+    if reader.by_ref().take(MAX_LINE as u64 + 1).read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    if line.len() > MAX_LINE && !line.ends_with('\n') {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, t!("serve-line-too-long", limit = MAX_LINE)));
+    }
+    Ok(Some(line))
+}
@@ -0,0 +1,33 @@
+// Counts the attempts of one round and tells when the budget is spent.
+// None means the player can keep guessing forever, like the classic game.
+pub struct Session {
+    max_attempts: Option<u32>,
+    attempts: u32,
+}
+
+impl Session {
+    pub fn new(max_attempts: Option<u32>) -> Session {
+        Session { max_attempts, attempts: 0 }
+    }
+
+    pub fn attempt(&mut self) {
+        self.attempts += 1;
+    }
+
+    // A hint is paid for with attempts that are never played.
+    pub fn spend(&mut self, attempts: u32) {
+        self.attempts += attempts;
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn left(&self) -> Option<u32> {
+        self.max_attempts.map(|max| max.saturating_sub(self.attempts))
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.left() == Some(0)
+    }
+}
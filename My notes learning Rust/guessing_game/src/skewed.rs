@@ -0,0 +1,181 @@
+use std::cmp::Ordering;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use serde_json::json;
+
+use crate::config::{self, Format};
+use crate::i18n::t;
+use crate::prior::{self, Prior, Shape, Tree};
+use crate::session::Session;
+use crate::{ask, guess_number, judge};
+
+// The best tree keeps a table of size²/2 parts.
+const MAX_SOLVED_RANGE: u32 = 2_000;
+
+// Bars of the prior, at most this many, each this wide at most.
+const BARS: u32 = 10;
+const BAR_WIDTH: f64 = 30.0;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Solver {
+    // halves the count, ignoring the prior
+    Bisection,
+    // halves the chance
+    Median,
+    // fewest guesses on average
+    Tree,
+}
+
+impl Solver {
+    const ALL: [Solver; 3] = [Solver::Bisection, Solver::Median, Solver::Tree];
+
+    fn name(self) -> &'static str {
+        match self {
+            Solver::Bisection => "bisection",
+            Solver::Median => "median",
+            Solver::Tree => "tree",
+        }
+    }
+}
+
+// The prior from --weights when given, the shape otherwise.
+fn load(shape: Shape, weights: Option<&Path>) -> Prior {
+    let range = config::get().range;
+    let (low, high) = (range.low, range.high);
+    if high - low >= MAX_SOLVED_RANGE {
+        crate::usage_error(&t!("skewed-too-wide", max = MAX_SOLVED_RANGE, low = low, high = high));
+    }
+
+    let Some(path) = weights else { return Prior::new(shape, low, high) };
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("Could not read {}: {error}", path.display());
+            process::exit(2);
+        }
+    };
+    match Prior::parse(&text, low, high) {
+        Ok(prior) => prior,
+        Err(error) => {
+            eprintln!("Could not read {}: {error}", path.display());
+            process::exit(2);
+        }
+    }
+}
+
+// The prior as a bar chart, one bar per slice of the range.
+fn show(prior: &Prior) {
+    let (low, high) = (prior.low, prior.high());
+    let width = (high - low + 1).div_ceil(BARS);
+    let slices: Vec<(u32, u32, f64)> = (low..=high)
+        .step_by(width as usize)
+        .map(|from| {
+            let to = from.saturating_add(width - 1).min(high);
+            (from, to, prior.mass(from, to))
+        })
+        .collect();
+    let top = slices.iter().map(|slice| slice.2).fold(0.0, f64::max);
+
+    for (from, to, mass) in slices {
+        let bar = "█".repeat((mass / top * BAR_WIDTH).round() as usize);
+        let numbers = if from == to { from.to_string() } else { format!("{from}-{to}") };
+        println!("{numbers:>11} {bar:<30} {:>5.1}%", mass * 100.0);
+    }
+}
+
+// The guess solver makes when the secret is known to be in low..=high.
+fn next_guess(solver: Solver, prior: &Prior, tree: &Tree, low: u32, high: u32) -> u32 {
+    match solver {
+        Solver::Bisection => low + (high - low) / 2,
+        Solver::Median => prior.median(low, high),
+        Solver::Tree => tree.next_guess(low, high),
+    }
+}
+
+// skewed [--distribution D | --weights FILE] [--solve S] [--attempts N]:
+// the classic game with a secret that is not equally likely everywhere.
+// The prior is shown first, so a good player can use it.
+pub fn run(shape: Shape, weights: Option<&Path>, solver: Option<Solver>, max_attempts: Option<u32>) {
+    let prior = load(shape, weights);
+    let tree = Tree::new(&prior);
+    let secret = prior.sample(&mut crate::rng_for("skewed"));
+
+    println!("{}", t!("guess-between", low = prior.low, high = prior.high()));
+    println!("{}", t!("skewed-prior"));
+    show(&prior);
+
+    let guesses = match solver {
+        Some(solver) => {
+            let (mut low, mut high) = (prior.low, prior.high());
+            let mut guesses = 0;
+            loop {
+                let guess = next_guess(solver, &prior, &tree, low, high);
+                guesses += 1;
+                let answer = match judge(guess, secret) {
+                    Ordering::Less => t!("too-small"),
+                    Ordering::Greater => t!("too-big"),
+                    Ordering::Equal => t!("you-win"),
+                };
+                println!("{guesses}: {guess} {answer}");
+                match judge(guess, secret) {
+                    Ordering::Less => low = guess + 1,
+                    Ordering::Greater => high = guess - 1,
+                    Ordering::Equal => break guesses,
+                }
+            }
+        }
+        None => {
+            let mut session = Session::new(max_attempts.or(config::get().attempts));
+            let outcomes = guess_number(secret, &mut session, &mut ask, &mut |_| {});
+            if outcomes.last() != Some(&Ordering::Equal) {
+                return;
+            }
+            session.attempts()
+        }
+    };
+
+    println!("{}", t!("you-win-in", count = guesses));
+    let best = prior::guesses(secret, prior.low, prior.high(), &|low, high| tree.next_guess(low, high));
+    println!("{}", t!("skewed-best", count = best, expected = format!("{:.2}", tree.expected())));
+}
+
+// skewed --simulate: how many guesses each solver takes on average over
+// the prior and at worst. The averages are exact, every secret is weighed
+// by its chance rather than drawn.
+pub fn report(shape: Shape, weights: Option<&Path>) {
+    let prior = load(shape, weights);
+    let tree = Tree::new(&prior);
+    let (low, high) = (prior.low, prior.high());
+
+    let results: Vec<(Solver, f64, u32)> = Solver::ALL
+        .iter()
+        .map(|&solver| {
+            let next = |low, high| next_guess(solver, &prior, &tree, low, high);
+            let worst = (low..=high)
+                .filter(|&secret| prior.chance(secret) > 0.0)
+                .map(|secret| prior::guesses(secret, low, high, &next))
+                .max()
+                .unwrap_or(0);
+            (solver, prior::expected_guesses(&prior, &next), worst)
+        })
+        .collect();
+
+    if config::get().format == Format::Json {
+        let solvers: Vec<_> = results
+            .iter()
+            .map(|(solver, expected, worst)| {
+                json!({ "solver": solver.name(), "expected_guesses": expected, "worst_guesses": worst })
+            })
+            .collect();
+        println!("{}", json!({ "low": low, "high": high, "entropy_bits": prior.entropy(), "solvers": solvers }));
+        return;
+    }
+
+    println!("{}", t!("skewed-report", low = low, high = high, bits = format!("{:.2}", prior.entropy())));
+    println!("{:<10} {:>9} {:>6}", t!("column-solver"), t!("column-expected"), t!("column-worst"));
+    for (solver, expected, worst) in &results {
+        println!("{:<10} {:>9.3} {:>6}", solver.name(), expected, worst);
+    }
+}
@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+
+use rand::seq::SliceRandom;
+use serde_json::json;
+
+use crate::config::{self, Format};
+use crate::i18n::t;
+use crate::read_input;
+use crate::sorting::{self, Bot, Hidden};
+
+// One letter per item.
+const MAX_ITEMS: usize = 26;
+
+fn letter(item: usize) -> char {
+    (b'A' + item as u8) as char
+}
+
+fn show(order: &[usize]) -> String {
+    let letters: Vec<String> = order.iter().map(|&item| letter(item).to_string()).collect();
+    letters.join(" < ")
+}
+
+fn compared(a: usize, b: usize, outcome: Ordering) -> String {
+    match outcome {
+        Ordering::Less => format!("{} < {}", letter(a), letter(b)),
+        Ordering::Greater => format!("{} > {}", letter(a), letter(b)),
+        Ordering::Equal => format!("{} = {}", letter(a), letter(b)),
+    }
+}
+
+enum Move {
+    Compare(usize, usize),
+    Answer(Vec<usize>),
+}
+
+// "A C" compares A with C, "C < A < B" is the answer, smallest first.
+fn read_move(input: &str, count: usize) -> Option<Move> {
+    let mut items = Vec::new();
+    for c in input.chars().filter(|c| c.is_alphabetic()) {
+        let item = (c.to_ascii_uppercase() as usize).checked_sub('A' as usize).filter(|&item| item < count)?;
+        if items.contains(&item) {
+            return None;
+        }
+        items.push(item);
+    }
+
+    if input.contains('<') {
+        (items.len() == count).then_some(Move::Answer(items))
+    } else if let [a, b] = items[..] {
+        Some(Move::Compare(a, b))
+    } else {
+        None
+    }
+}
+
+fn hidden_order(count: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..count).collect();
+    order.shuffle(&mut crate::rng_for("sort"));
+    order
+}
+
+fn check(count: usize) {
+    if !(2..=MAX_ITEMS).contains(&count) {
+        crate::usage_error(&t!("sort-bad-count", max = MAX_ITEMS, count = count));
+    }
+}
+
+// How the result compares with the lower bound and with merge-insertion
+// on the same order.
+fn bounds(order: &[usize]) {
+    let mut hidden = Hidden::new(order);
+    Bot::MergeInsertion.sort(order.len(), &mut |a, b| hidden.compare(a, b));
+    println!(
+        "{}",
+        t!("sort-bounds", count = order.len(), bound = sorting::lower_bound(order.len()), best = hidden.comparisons)
+    );
+}
+
+// sort [--items N] [--bot B]: N items in a hidden order. Compare any two,
+// then give the whole order with as few comparisons as you can.
+pub fn run(count: usize, bot: Option<Bot>) {
+    check(count);
+    let order = hidden_order(count);
+    let mut hidden = Hidden::new(&order);
+    println!("{}", t!("sort-intro", count = count, last = letter(count - 1)));
+
+    if let Some(bot) = bot {
+        let sorted = bot.sort(count, &mut |a, b| {
+            let outcome = hidden.compare(a, b);
+            println!("{}: {}", hidden.comparisons, compared(a, b, outcome));
+            outcome
+        });
+        println!("{}", t!("sort-bot-done", bot = bot.name(), count = hidden.comparisons, order = show(&sorted)));
+        bounds(&order);
+        return;
+    }
+
+    println!("{}", t!("sort-how"));
+    loop {
+        println!("{}", t!("sort-ask", count = hidden.comparisons));
+        let Some(input) = read_input() else { return };
+        match read_move(&input, count) {
+            Some(Move::Compare(a, b)) => println!("{}", compared(a, b, hidden.compare(a, b))),
+            Some(Move::Answer(answer)) => {
+                if hidden.is_sorted(&answer) {
+                    println!("{}", t!("sort-win", count = hidden.comparisons));
+                    bounds(&order);
+                } else {
+                    println!("{}", t!("sort-wrong", order = show(&hidden.order())));
+                }
+                return;
+            }
+            None => println!("{}", t!("sort-bad-move", last = letter(count - 1))),
+        }
+    }
+}
+
+// sort --simulate [--games N]: every bot sorts the same hidden orders,
+// seeded from --seed, next to the lower bound.
+pub fn report(count: usize, games: u32) {
+    check(count);
+    let games = games.max(1);
+    let orders: Vec<Vec<usize>> = {
+        let mut rng = crate::rng_for("sort");
+        (0..games)
+            .map(|_| {
+                let mut order: Vec<usize> = (0..count).collect();
+                order.shuffle(&mut rng);
+                order
+            })
+            .collect()
+    };
+
+    let results: Vec<(Bot, Vec<u32>)> = Bot::ALL
+        .iter()
+        .map(|&bot| {
+            let used = orders
+                .iter()
+                .map(|order| {
+                    let mut hidden = Hidden::new(order);
+                    bot.sort(count, &mut |a, b| hidden.compare(a, b));
+                    hidden.comparisons
+                })
+                .collect();
+            (bot, used)
+        })
+        .collect();
+    let bound = sorting::lower_bound(count);
+
+    if config::get().format == Format::Json {
+        let bots: Vec<_> = results
+            .iter()
+            .map(|(bot, used)| {
+                json!({
+                    "bot": bot.name(),
+                    "average_comparisons": average(used),
+                    "worst_comparisons": used.iter().max(),
+                })
+            })
+            .collect();
+        println!("{}", json!({ "items": count, "games": games, "lower_bound": bound, "bots": bots }));
+        return;
+    }
+
+    println!("{}", t!("sort-report", items = count, count = games, bound = bound));
+    println!("{:<16} {:>8} {:>6}", t!("column-bot"), t!("column-average"), t!("column-worst"));
+    for (bot, used) in &results {
+        println!("{:<16} {:>8.2} {:>6}", bot.name(), average(used), used.iter().max().unwrap_or(&0));
+    }
+}
+
+fn average(used: &[u32]) -> f64 {
+    used.iter().sum::<u32>() as f64 / used.len().max(1) as f64
+}
@@ -0,0 +1,179 @@
+use std::cmp::Ordering;
+
+use crate::bits;
+
+// Sorting items that can only be compared two at a time, and counting the
+// comparisons. Items are numbered 0..n and `compare(a, b)` answers whether
+// a comes before or after b, like judge does for a guess.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bot {
+    // Ford-Johnson, the fewest comparisons known for small n
+    MergeInsertion,
+    Merge,
+    Insertion,
+}
+
+impl Bot {
+    pub const ALL: [Bot; 3] = [Bot::MergeInsertion, Bot::Merge, Bot::Insertion];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Bot::MergeInsertion => "merge-insertion",
+            Bot::Merge => "merge",
+            Bot::Insertion => "insertion",
+        }
+    }
+
+    // The items 0..n, first to last.
+    pub fn sort(self, n: usize, compare: &mut dyn FnMut(usize, usize) -> Ordering) -> Vec<usize> {
+        let items = (0..n).collect();
+        match self {
+            Bot::MergeInsertion => merge_insertion(items, compare),
+            Bot::Merge => merge_sort(items, compare),
+            Bot::Insertion => insertion_sort(items, compare),
+        }
+    }
+}
+
+// Items in a hidden order, counting how often they were compared.
+pub struct Hidden {
+    // rank[i]: where item i belongs, 0 first
+    rank: Vec<usize>,
+    pub comparisons: u32,
+}
+
+impl Hidden {
+    // order lists the items first to last.
+    pub fn new(order: &[usize]) -> Hidden {
+        let mut rank = vec![0; order.len()];
+        for (place, &item) in order.iter().enumerate() {
+            rank[item] = place;
+        }
+        Hidden { rank, comparisons: 0 }
+    }
+
+    pub fn compare(&mut self, a: usize, b: usize) -> Ordering {
+        self.comparisons += 1;
+        self.rank[a].cmp(&self.rank[b])
+    }
+
+    pub fn is_sorted(&self, order: &[usize]) -> bool {
+        order.len() == self.rank.len() && order.iter().enumerate().all(|(place, &item)| self.rank.get(item) == Some(&place))
+    }
+
+    pub fn order(&self) -> Vec<usize> {
+        let mut order = vec![0; self.rank.len()];
+        for (item, &place) in self.rank.iter().enumerate() {
+            order[place] = item;
+        }
+        order
+    }
+}
+
+// Any way of sorting n items by comparisons needs, for some order, at
+// least ⌈log2 n!⌉ of them: each answer at most halves the n! orders still
+// possible. n! is exact in a u128 up to 34.
+pub fn lower_bound(n: usize) -> u32 {
+    let orders: u128 = (2..=n as u128).product();
+    bits(orders)
+}
+
+// Where item goes in sorted[..end], by binary search.
+fn insert_at(item: usize, sorted: &[usize], end: usize, compare: &mut dyn FnMut(usize, usize) -> Ordering) -> usize {
+    let (mut low, mut high) = (0, end);
+    while low < high {
+        let middle = (low + high) / 2;
+        if compare(item, sorted[middle]) == Ordering::Less {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    low
+}
+
+// Ford-Johnson: sort the pairs by their larger item, recursively, then
+// binary-insert the smaller ones. They go in an order (by the Jacobsthal
+// numbers 3, 5, 11, 21, ...) that keeps each search within a part of the
+// chain just under a power of two long, so no comparison is wasted.
+fn merge_insertion(items: Vec<usize>, compare: &mut dyn FnMut(usize, usize) -> Ordering) -> Vec<usize> {
+    if items.len() < 2 {
+        return items;
+    }
+
+    let mut larger = Vec::new();
+    let mut partner = std::collections::HashMap::new();
+    for pair in items.chunks_exact(2) {
+        let (small, large) = match compare(pair[0], pair[1]) {
+            Ordering::Greater => (pair[1], pair[0]),
+            _ => (pair[0], pair[1]),
+        };
+        larger.push(large);
+        partner.insert(large, small);
+    }
+    let straggler = (items.len() % 2 == 1).then(|| items[items.len() - 1]);
+
+    let larger = merge_insertion(larger, compare);
+    // the smaller of the first pair is below everything in the chain
+    let mut chain = vec![partner[&larger[0]]];
+    chain.extend(&larger);
+
+    // pending[i] has to go before larger[i]; the straggler anywhere
+    let mut pending: Vec<(usize, Option<usize>)> = larger.iter().map(|large| (partner[large], Some(*large))).collect();
+    pending.extend(straggler.map(|item| (item, None)));
+
+    let (mut done, mut next) = (1, 3);
+    while done < pending.len() {
+        let group = next.min(pending.len());
+        for &(item, bound) in pending[done..group].iter().rev() {
+            let end = match bound {
+                Some(large) => chain.iter().position(|&x| x == large).unwrap(),
+                None => chain.len(),
+            };
+            let at = insert_at(item, &chain, end, compare);
+            chain.insert(at, item);
+        }
+        // the next Jacobsthal number
+        (done, next) = (group, next + 2 * done);
+    }
+    chain
+}
+
+fn merge_sort(items: Vec<usize>, compare: &mut dyn FnMut(usize, usize) -> Ordering) -> Vec<usize> {
+    if items.len() < 2 {
+        return items;
+    }
+    let mut right = items;
+    let left = right.drain(..right.len() / 2).collect();
+    let (left, right) = (merge_sort(left, compare), merge_sort(right, compare));
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if compare(right[j], left[i]) == Ordering::Less {
+            merged.push(right[j]);
+            j += 1;
+        } else {
+            merged.push(left[i]);
+            i += 1;
+        }
+    }
+    merged.extend(&left[i..]);
+    merged.extend(&right[j..]);
+    merged
+}
+
+// Each item walks down from the end of the sorted part until it finds its
+// place.
+fn insertion_sort(items: Vec<usize>, compare: &mut dyn FnMut(usize, usize) -> Ordering) -> Vec<usize> {
+    let mut sorted: Vec<usize> = Vec::with_capacity(items.len());
+    for item in items {
+        let mut at = sorted.len();
+        while at > 0 && compare(item, sorted[at - 1]) == Ordering::Less {
+            at -= 1;
+        }
+        sorted.insert(at, item);
+    }
+    sorted
+}
@@ -0,0 +1,61 @@
+use serde_json::json;
+
+use crate::config::{self, Format};
+use crate::i18n::t;
+use crate::scores;
+
+// The longest game the histogram has a bar for.
+const MAX_SPREAD: u32 = 10_000;
+
+// stats: how the recorded classic games went, from scores.txt.
+pub fn run() {
+    let scores = scores::load();
+    let wins: Vec<u32> = scores.iter().filter(|score| score.won).map(|score| score.attempts).collect();
+    let games = scores.len();
+    let win_rate = if games == 0 { 0.0 } else { wins.len() as f64 / games as f64 };
+
+    // the attempts of the wins, leaving out counts no game could have
+    // ended with, which only a hand-edited file has
+    let counted: Vec<u32> = wins.iter().copied().filter(|attempts| (1..=MAX_SPREAD).contains(attempts)).collect();
+    let total: u64 = counted.iter().map(|&attempts| u64::from(attempts)).sum();
+    let average = if counted.is_empty() { 0.0 } else { total as f64 / counted.len() as f64 };
+    let best = counted.iter().min().copied();
+
+    // how many wins took 1, 2, 3... attempts
+    let longest = counted.iter().max().copied().unwrap_or(0) as usize;
+    let mut spread = vec![0usize; longest];
+    for &attempts in &counted {
+        spread[attempts as usize - 1] += 1;
+    }
+
+    if config::get().format == Format::Json {
+        let stats = json!({
+            "games": games,
+            "wins": wins.len(),
+            "win_rate": win_rate,
+            "average_attempts": average,
+            "best": best,
+            "attempts": spread,
+        });
+        println!("{stats}");
+        return;
+    }
+
+    if games == 0 {
+        println!("{}", t!("stats-none"));
+        return;
+    }
+
+    println!("{}", t!("stats-games", count = games));
+    println!("{}", t!("stats-wins", count = wins.len(), percent = format!("{:.0}", win_rate * 100.0)));
+    if let Some(best) = best {
+        println!("{}", t!("stats-attempts", average = format!("{average:.2}"), best = best));
+    }
+
+    let widest = spread.iter().max().copied().unwrap_or(0);
+    for (i, &count) in spread.iter().enumerate().filter(|&(_, &count)| count > 0) {
+        // at most 40 marks, so long histories still fit
+        let bar = "#".repeat((count * 40).div_ceil(widest.max(1)));
+        println!("{:>4} {bar} {count}", i + 1);
+    }
+}
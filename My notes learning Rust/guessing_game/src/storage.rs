@@ -0,0 +1,26 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+// Where the game keeps its files between runs: $XDG_DATA_HOME/guessing_game,
+// or ~/.local/share/guessing_game when that variable is not set.
+pub fn data_dir() -> PathBuf {
+    let base = env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    let dir = base.join("guessing_game");
+    fs::create_dir_all(&dir).expect("Failed to create the data directory");
+    dir
+}
+
+// Where the player's own settings live: $XDG_CONFIG_HOME/guessing_game,
+// or ~/.config/guessing_game. Nothing is created here, the game only reads.
+pub fn config_dir() -> PathBuf {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("guessing_game")
+}
@@ -0,0 +1,151 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+// Guessing when guesses have a price: the cost models and the decision
+// tree that keeps the worst total as low as it can be.
+
+// What one guess costs, given how it compared with the secret.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cost {
+    // every guess costs 1, the classic game
+    Unit,
+    // a guess costs its own value, so low guesses are cheap
+    Value,
+    // every guess costs 1, and a guess that is too high this much more
+    Over(u32),
+}
+
+impl Cost {
+    // outcome is judge(guess, secret), Greater when the guess is too high.
+    pub fn of(self, guess: u32, outcome: Ordering) -> u64 {
+        match self {
+            Cost::Unit => 1,
+            Cost::Value => guess as u64,
+            Cost::Over(penalty) if outcome == Ordering::Greater => 1 + penalty as u64,
+            Cost::Over(_) => 1,
+        }
+    }
+
+    // What every guess costs, whatever the answer.
+    fn base(self, guess: u32) -> u64 {
+        match self {
+            Cost::Value => guess as u64,
+            Cost::Unit | Cost::Over(_) => 1,
+        }
+    }
+
+    // What a guess that is too high costs on top of that.
+    fn extra(self) -> u64 {
+        match self {
+            Cost::Over(penalty) => penalty as u64,
+            Cost::Unit | Cost::Value => 0,
+        }
+    }
+}
+
+// For every part low..=high of the range the secret can still be in: the
+// guess to play there and the most the rest of the game can then cost.
+// Stored as a triangle, one row per low end.
+pub struct Strategy {
+    low: u32,
+    size: usize,
+    cost: Cost,
+    worst: Vec<u64>,
+    guess: Vec<u32>,
+}
+
+impl Strategy {
+    // Minimax over every part, built one last number at a time and, for
+    // each, from the shortest part to the longest. A guess k on first..=last
+    // costs base(k) plus the worse of the two sides left: the part below
+    // (with the extra cost of being too high), which only grows as k moves
+    // up, and the part above, which only shrinks. From the first k where
+    // the part below is the worse one, the total only grows, so that k is
+    // the best of them. Below it the part above decides, and the cheapest
+    // of those guesses is kept in a sliding window. Where the sides cross
+    // only moves down as the part grows downwards, which keeps the whole
+    // table quadratic.
+    pub fn new(low: u32, high: u32, cost: Cost) -> Strategy {
+        let size = (high - low + 1) as usize;
+        let cells = size * (size + 1) / 2;
+        let mut strategy = Strategy { low, size, cost, worst: vec![0; cells], guess: vec![0; cells] };
+        let base = |k: usize| cost.base(low + k as u32);
+
+        for last in 0..size {
+            let mut cross = last;
+            // guesses below `cross`, cheapest at the back
+            let mut window: VecDeque<(usize, u64)> = VecDeque::new();
+
+            for first in (0..=last).rev() {
+                let below = |k: usize| if k > first { cost.extra() + strategy.part(first, k - 1) } else { 0 };
+                let above = |k: usize| if k < last { strategy.part(k + 1, last) } else { 0 };
+
+                while cross > first && below(cross - 1) >= above(cross - 1) {
+                    cross -= 1;
+                }
+                while window.back().is_some_and(|&(k, _)| k >= cross) {
+                    window.pop_back();
+                }
+                if first < cross {
+                    let total = base(first) + above(first);
+                    while window.front().is_some_and(|&(_, cheapest)| cheapest >= total) {
+                        window.pop_front();
+                    }
+                    window.push_front((first, total));
+                }
+
+                let mut best = (base(cross) + below(cross), cross);
+                if let Some(&(k, total)) = window.back()
+                    && total < best.0
+                {
+                    best = (total, k);
+                }
+
+                let index = strategy.index(first, last);
+                strategy.worst[index] = best.0;
+                strategy.guess[index] = low + best.1 as u32;
+            }
+        }
+        strategy
+    }
+
+    // Row `first` of the triangle holds the parts first..=first, up to
+    // first..=size-1, after the size - r cells of every row r above it.
+    fn index(&self, first: usize, last: usize) -> usize {
+        first * self.size - first * first.saturating_sub(1) / 2 + (last - first)
+    }
+
+    // Worst cost of the part first..=last of the range, offsets from low.
+    fn part(&self, first: usize, last: usize) -> u64 {
+        self.worst[self.index(first, last)]
+    }
+
+    // The most the best play can cost, whatever the secret.
+    pub fn worst(&self) -> u64 {
+        self.part(0, self.size - 1)
+    }
+
+    // What to guess when the secret is known to be in low..=high.
+    pub fn next_guess(&self, low: u32, high: u32) -> u32 {
+        self.guess[self.index((low - self.low) as usize, (high - self.low) as usize)]
+    }
+
+    // The guesses the strategy plays against secret and what they cost.
+    pub fn play(&self, secret: u32) -> (Vec<u32>, u64) {
+        let (mut low, mut high) = (self.low, self.low + self.size as u32 - 1);
+        let mut guesses = Vec::new();
+        let mut total = 0;
+
+        loop {
+            let guess = self.next_guess(low, high);
+            let outcome = guess.cmp(&secret);
+            guesses.push(guess);
+            total += self.cost.of(guess, outcome);
+            match outcome {
+                Ordering::Less => low = guess + 1,
+                Ordering::Greater => high = guess - 1,
+                Ordering::Equal => return (guesses, total),
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::cmp::Ordering;
+use std::time::Duration;
+
+use crate::timing::{Clock, Lap, Limits, SystemClock};
+use crate::i18n::t;
+use crate::{parse, read_input};
+
+// Where guesses come from, one line at a time, None once it is closed.
+pub type Input<'a> = &'a mut dyn FnMut() -> Option<String>;
+
+const UPPER: u32 = 100;
+
+// One answered guess and how long the player took for it. A guess that
+// came in after the countdown has no outcome, the turn was forfeited.
+pub struct Split {
+    pub guess: u32,
+    pub outcome: Option<Ordering>,
+    pub time: Duration,
+}
+
+pub struct Round {
+    pub splits: Vec<Split>,
+    pub solved: bool,
+}
+
+// Plays one secret until it is found, the deadline passes or the input
+// closes. `started` is the clock reading the round began at.
+pub fn round(secret: u32, clock: &dyn Clock, input: Input, limits: &Limits, started: Duration) -> Round {
+    let mut splits = Vec::new();
+    let mut last = started;
+
+    loop {
+        println!("{}", t!("enter-guess"));
+
+        let Some(input) = input() else { break };
+        let guess = match parse::guess(&input) {
+            Ok(num) => num,
+            Err(error) => {
+                println!("{}", crate::bad_guess(&error));
+                continue;
+            }
+        };
+
+        let now = clock.now();
+        let lap = limits.judge(last, now);
+        last = now;
+
+        let time = match lap {
+            Lap::TimeUp => {
+                println!("{}", t!("time-up"));
+                break;
+            }
+            Lap::TooSlow(time) => {
+                println!("{}", t!("too-slow"));
+                splits.push(Split { guess, outcome: None, time });
+                continue;
+            }
+            Lap::InTime(time) => time,
+        };
+
+        let outcome = guess.cmp(&secret);
+        splits.push(Split { guess, outcome: Some(outcome), time });
+
+        match outcome {
+            Ordering::Less => println!("{}", t!("too-small")),
+            Ordering::Greater => println!("{}", t!("too-big")),
+            Ordering::Equal => {
+                println!("{}", t!("you-win"));
+                return Round { splits, solved: true };
+            }
+        }
+    }
+
+    Round { splits, solved: false }
+}
+
+fn print_splits(splits: &[Split]) {
+    let mut total = Duration::ZERO;
+
+    for (i, split) in splits.iter().enumerate() {
+        total += split.time;
+        let outcome = match split.outcome {
+            Some(Ordering::Less) => t!("too-small"),
+            Some(Ordering::Greater) => t!("too-big"),
+            Some(Ordering::Equal) => t!("you-win"),
+            None => t!("forfeited"),
+        };
+        println!(
+            "{:>3}. {:>5}  {:<9}  +{:.2}s  {:.2}s",
+            i + 1,
+            split.guess,
+            outcome,
+            split.time.as_secs_f64(),
+            total.as_secs_f64()
+        );
+    }
+}
+
+pub enum Mode {
+    Countdown,
+    Total,
+    Marathon,
+}
+
+// timed countdown [SECS] | timed total [SECS] | timed marathon [SECS]
+pub fn run(mode: Mode, seconds: Option<Duration>) {
+    let clock = SystemClock::new();
+    let input = &mut read_input;
+    let seconds = |default: u64| seconds.unwrap_or(Duration::from_secs(default));
+
+    match mode {
+        Mode::Countdown => countdown(&clock, input, seconds(10)),
+        Mode::Total => total(&clock, input, seconds(60)),
+        Mode::Marathon => marathon(&clock, input, seconds(120)),
+    }
+}
+
+fn countdown(clock: &dyn Clock, input: Input, per_guess: Duration) {
+    println!("{}", t!("guess-the-number"));
+    println!("{}", t!("countdown-intro", seconds = format!("{:.0}", per_guess.as_secs_f64())));
+
+    let secret = crate::draw_secret(&mut crate::rng(), 1, UPPER);
+    let limits = Limits { per_guess: Some(per_guess), deadline: None };
+    let round = round(secret, clock, input, &limits, clock.now());
+
+    print_splits(&round.splits);
+}
+
+fn total(clock: &dyn Clock, input: Input, time: Duration) {
+    println!("{}", t!("guess-the-number"));
+    println!("{}", t!("total-intro", seconds = format!("{:.0}", time.as_secs_f64())));
+
+    let secret = crate::draw_secret(&mut crate::rng(), 1, UPPER);
+    let limits = Limits { per_guess: None, deadline: Some(time) };
+    let round = round(secret, clock, input, &limits, clock.now());
+
+    if !round.solved {
+        println!("{}", t!("you-lose", secret = secret));
+    }
+    print_splits(&round.splits);
+}
+
+fn marathon(clock: &dyn Clock, input: Input, time: Duration) {
+    println!("{}", t!("marathon-intro", seconds = format!("{:.0}", time.as_secs_f64())));
+
+    let limits = Limits { per_guess: None, deadline: Some(time) };
+    let mut solved = 0;
+    let mut started = clock.now();
+    let mut rng = crate::rng();
+
+    loop {
+        let secret = crate::draw_secret(&mut rng, 1, UPPER);
+        println!("#{} {}", solved + 1, t!("guess-between", low = 1, high = UPPER));
+
+        let round = round(secret, clock, input, &limits, started);
+        print_splits(&round.splits);
+
+        if !round.solved {
+            println!("{}", t!("you-lose", secret = secret));
+            break;
+        }
+
+        solved += 1;
+        started += round.splits.iter().map(|split| split.time).sum::<Duration>();
+    }
+
+    println!("{}", t!("marathon-solved", count = solved));
+}
@@ -0,0 +1,88 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+// The clocks of the timed modes and the limits a guess has to come in
+// within.
+
+// Anything that can tell how much time went by since a round started.
+// The game only ever asks for the time, so a fake clock can stand in for
+// the real one and timed rounds can be checked without sleeping.
+pub trait Clock {
+    fn now(&self) -> Duration;
+}
+
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock { start: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+// Moves forward by the same step every time it is read, so every guess
+// seems to take exactly `step`.
+pub struct TickClock {
+    step: Duration,
+    ticks: Cell<u32>,
+}
+
+impl TickClock {
+    pub fn new(step: Duration) -> TickClock {
+        TickClock { step, ticks: Cell::new(0) }
+    }
+}
+
+impl Clock for TickClock {
+    fn now(&self) -> Duration {
+        let ticks = self.ticks.get();
+        self.ticks.set(ticks + 1);
+        self.step * ticks
+    }
+}
+
+// Limits for one round, both measured on the same clock.
+pub struct Limits {
+    // how long a single guess may take before its turn is lost
+    pub per_guess: Option<Duration>,
+    // when the whole round (or marathon) is over, counted from the start
+    pub deadline: Option<Duration>,
+}
+
+// What the clock says about a guess that came in.
+#[derive(Debug, PartialEq)]
+pub enum Lap {
+    // in time, after this long
+    InTime(Duration),
+    // this long, more than a guess may take, so the turn is lost
+    TooSlow(Duration),
+    // past the deadline, the round is over
+    TimeUp,
+}
+
+impl Limits {
+    // A guess read at `now`, the one before it at `last`.
+    pub fn judge(&self, last: Duration, now: Duration) -> Lap {
+        let time = now - last;
+        if self.deadline.is_some_and(|deadline| now > deadline) {
+            Lap::TimeUp
+        } else if self.per_guess.is_some_and(|limit| time > limit) {
+            Lap::TooSlow(time)
+        } else {
+            Lap::InTime(time)
+        }
+    }
+}
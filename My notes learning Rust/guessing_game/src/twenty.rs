@@ -0,0 +1,90 @@
+use crate::config;
+use crate::i18n::t;
+use crate::query::{self, Needed, Query, QueryError};
+use crate::read_input;
+
+// The questioner keeps every number that still fits the answers.
+const MAX_SOLVED_RANGE: u32 = 1_000_000;
+
+fn answer(query: &Query, secret: u32) -> String {
+    if query.eval(secret) { t!("twenty-yes") } else { t!("twenty-no") }
+}
+
+fn bad_question(error: &QueryError) -> String {
+    let error = match error {
+        QueryError::Empty => t!("query-empty"),
+        QueryError::UnknownWord(word) => t!("query-unknown-word", word = word),
+        QueryError::Unexpected(token) => t!("query-unexpected", token = token),
+        QueryError::Missing(Needed::Word(word)) => t!("query-missing-word", word = word),
+        QueryError::Missing(Needed::Number) => t!("query-missing-number"),
+        QueryError::Missing(Needed::Comparison) => t!("query-missing-comparison"),
+        QueryError::Missing(Needed::Question) => t!("query-missing-question"),
+        QueryError::Missing(Needed::RangeEnd) => t!("query-missing-range-end"),
+        QueryError::BadNumber(text) => t!("query-bad-number", text = text),
+        QueryError::EmptyRange(low, high) => t!("query-empty-range", low = low, high = high),
+        QueryError::DivisionByZero => t!("query-division-by-zero"),
+        QueryError::TooDeep => t!("query-too-deep", max = query::MAX_DEPTH),
+    };
+    t!("twenty-bad-question", error = error)
+}
+
+// twenty [--questions N] [--solve]: yes/no questions about the secret
+// instead of guesses. Asking about one number, "is it 42?", is the guess,
+// and every question, the last one too, comes out of the budget.
+pub fn run(budget: u32, solving: bool) {
+    let range = config::get().range;
+    let (low, high) = (range.low, range.high);
+    let secret = crate::draw_secret(&mut crate::rng(), low, high);
+
+    println!("{}", t!("twenty-intro", low = low, high = high, count = budget));
+
+    if solving {
+        if high - low >= MAX_SOLVED_RANGE {
+            crate::usage_error(&t!("twenty-too-wide", max = MAX_SOLVED_RANGE, low = low, high = high));
+        }
+        solve(secret, low, high, budget);
+        return;
+    }
+
+    println!("{}", t!("twenty-examples"));
+    let mut asked = 0;
+    while asked < budget {
+        println!("{}", t!("twenty-ask", count = budget - asked));
+        let Some(input) = read_input() else { return };
+        let query = match query::question(&input) {
+            Ok(query) => query,
+            Err(error) => {
+                println!("{}", bad_question(&error));
+                continue;
+            }
+        };
+
+        asked += 1;
+        println!("{}", answer(&query, secret));
+        if query.guessed() == Some(secret) {
+            println!("{}", t!("twenty-win", count = asked));
+            return;
+        }
+    }
+    println!("{}", t!("you-lose", secret = secret));
+}
+
+// Lets the questioner play, showing each question and how many numbers
+// are left after its answer.
+fn solve(secret: u32, low: u32, high: u32, budget: u32) {
+    let mut candidates: Vec<u32> = (low..=high).collect();
+
+    for asked in 1..=budget {
+        let query = query::best_question(&candidates);
+        let yes = query.eval(secret);
+        candidates.retain(|&n| query.eval(n) == yes);
+
+        let left = t!("twenty-candidates", count = candidates.len());
+        println!("{asked}: {query}? {} {left}", answer(&query, secret));
+        if query.guessed() == Some(secret) {
+            println!("{}", t!("twenty-win", count = asked));
+            return;
+        }
+    }
+    println!("{}", t!("you-lose", secret = secret));
+}
@@ -0,0 +1,198 @@
+// Statistics for telling whether secrets drawn from low..=high look
+// uniform: a chi-square test on how often each number (or slice of the
+// range) came up, a Kolmogorov-Smirnov test on the whole spread, and
+// whether both ends of the range turned up at all. It only sees the draws,
+// not how they were made.
+
+// Wider ranges are counted in this many slices for the chi-square test.
+const MAX_BINS: u64 = 1000;
+
+// Ends are only checked when each should come up this often, so missing
+// one by chance is less likely than one in a hundred million.
+const ENDPOINT_EXPECTED: f64 = 20.0;
+
+pub struct Check {
+    pub low: u32,
+    pub high: u32,
+    pub samples: usize,
+    pub chi_square: f64,
+    pub freedom: u64,
+    pub chi_square_p: f64,
+    pub ks: f64,
+    pub ks_p: f64,
+    // None when the sample is too small for a missing end to mean much
+    pub endpoints: Option<bool>,
+}
+
+impl Check {
+    // Both tests keep their p-value above alpha and no end is missing.
+    pub fn passed(&self, alpha: f64) -> bool {
+        self.chi_square_p >= alpha && self.ks_p >= alpha && self.endpoints != Some(false)
+    }
+}
+
+pub fn check(low: u32, high: u32, draws: &[u32]) -> Check {
+    let size = u64::from(high - low) + 1;
+    let bins = size.min(MAX_BINS);
+    // bin b holds the numbers from low + start(b) up to low + start(b+1) - 1
+    let start = |b: u64| (u128::from(b) * u128::from(size)).div_ceil(u128::from(bins)) as u64;
+    let n = draws.len() as f64;
+
+    let mut observed = vec![0u64; bins as usize];
+    for &draw in draws {
+        let offset = u64::from(draw - low);
+        observed[(u128::from(offset) * u128::from(bins) / u128::from(size)) as usize] += 1;
+    }
+    let expected: Vec<f64> = (0..bins).map(|b| n * (start(b + 1) - start(b)) as f64 / size as f64).collect();
+    let statistic = chi_square(&observed, &expected);
+    let freedom = bins - 1;
+
+    let mut sorted = draws.to_vec();
+    sorted.sort_unstable();
+    let cdf = |x: u32| (u64::from(x - low) + 1) as f64 / size as f64;
+    let distance = ks(&sorted, low, &cdf);
+
+    let endpoints = (n / size as f64 >= ENDPOINT_EXPECTED).then(|| sorted.first() == Some(&low) && sorted.last() == Some(&high));
+
+    Check {
+        low,
+        high,
+        samples: draws.len(),
+        chi_square: statistic,
+        freedom,
+        chi_square_p: chi_square_p(statistic, freedom),
+        ks: distance,
+        ks_p: ks_p(distance, draws.len()),
+        endpoints,
+    }
+}
+
+// Σ (observed - expected)² / expected
+pub fn chi_square(observed: &[u64], expected: &[f64]) -> f64 {
+    observed
+        .iter()
+        .zip(expected)
+        .map(|(&observed, &expected)| (observed as f64 - expected).powi(2) / expected)
+        .sum()
+}
+
+// Chance of a statistic at least this large from freedom degrees of
+// freedom when the draws are fair: the upper regularized gamma function
+// Q(freedom / 2, statistic / 2).
+pub fn chi_square_p(statistic: f64, freedom: u64) -> f64 {
+    if freedom == 0 {
+        return 1.0;
+    }
+    upper_gamma(freedom as f64 / 2.0, statistic / 2.0)
+}
+
+// Largest gap between the share of sorted draws up to each number and the
+// share cdf says it should be. The draws are whole numbers, so both sides
+// of every step of the empirical curve are compared.
+pub fn ks(sorted: &[u32], low: u32, cdf: &dyn Fn(u32) -> f64) -> f64 {
+    let n = sorted.len() as f64;
+    let mut distance: f64 = 0.0;
+    let mut i = 0;
+    while i < sorted.len() {
+        let value = sorted[i];
+        let below = i as f64 / n;
+        while i < sorted.len() && sorted[i] == value {
+            i += 1;
+        }
+        let up_to = i as f64 / n;
+        let before = if value > low { cdf(value - 1) } else { 0.0 };
+        distance = distance.max((below - before).abs()).max((up_to - cdf(value)).abs());
+    }
+    distance
+}
+
+// Chance of a distance at least this large over n draws, from
+// Kolmogorov's limit with Stephens' correction for finite n. For whole
+// numbers it errs on the high side, so it only flags real gaps.
+pub fn ks_p(distance: f64, n: usize) -> f64 {
+    let root = (n as f64).sqrt();
+    let lambda = (root + 0.12 + 0.11 / root) * distance;
+    if lambda < 0.3 {
+        return 1.0;
+    }
+    let sum: f64 = (1..=100)
+        .map(|k| {
+            let k = k as f64;
+            let sign = if k as u32 % 2 == 1 { 1.0 } else { -1.0 };
+            sign * (-2.0 * k * k * lambda * lambda).exp()
+        })
+        .sum();
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
+// Q(a, x), by its series below a + 1 and its continued fraction above,
+// the way Numerical Recipes does it.
+fn upper_gamma(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let front = (a * x.ln() - x - ln_gamma(a)).exp();
+
+    if x < a + 1.0 {
+        let (mut term, mut sum, mut denominator) = (1.0 / a, 1.0 / a, a);
+        for _ in 0..1000 {
+            denominator += 1.0;
+            term *= x / denominator;
+            sum += term;
+            if term.abs() < sum.abs() * 1e-15 {
+                break;
+            }
+        }
+        return (1.0 - sum * front).clamp(0.0, 1.0);
+    }
+
+    // Lentz's method
+    let tiny = 1e-300;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / tiny;
+    let mut d = 1.0 / b;
+    let mut fraction = d;
+    for i in 1..1000 {
+        let i = i as f64;
+        let an = -i * (i - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = b + an / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = 1.0 / d;
+        let step = d * c;
+        fraction *= step;
+        if (step - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    (front * fraction).clamp(0.0, 1.0)
+}
+
+// Lanczos' approximation, good to about 15 digits.
+fn ln_gamma(x: f64) -> f64 {
+    const G: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // reflection
+        return (std::f64::consts::PI / (std::f64::consts::PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let sum = G[1..].iter().enumerate().fold(G[0], |sum, (i, g)| sum + g / (x + i as f64 + 1.0));
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
@@ -0,0 +1,78 @@
+// Marking Wordle guesses, and the rules hard mode adds.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mark {
+    Correct,
+    Present,
+    Absent,
+}
+
+// What a hard mode guess failed to reuse, with letters in lower case.
+#[derive(Debug, PartialEq)]
+pub enum HardModeError {
+    // a green letter moved, positions counting from 1
+    MustBe { position: usize, letter: char },
+    // a yellow letter was left out
+    MustContain(char),
+}
+
+// Exact matches are marked first, then the leftover letters of the secret
+// decide which other letters are present. A letter that appears once in
+// the secret is only marked once, even if the guess repeats it.
+pub fn score(guess: &str, secret: &str) -> Vec<Mark> {
+    let guess: Vec<char> = guess.chars().collect();
+    let secret: Vec<char> = secret.chars().collect();
+    let mut marks = vec![Mark::Absent; guess.len()];
+    let mut leftover = Vec::new();
+
+    for i in 0..guess.len() {
+        if guess[i] == secret[i] {
+            marks[i] = Mark::Correct;
+        } else {
+            leftover.push(secret[i]);
+        }
+    }
+
+    for i in 0..guess.len() {
+        if marks[i] == Mark::Correct {
+            continue;
+        }
+        if let Some(position) = leftover.iter().position(|&c| c == guess[i]) {
+            leftover.remove(position);
+            marks[i] = Mark::Present;
+        }
+    }
+
+    marks
+}
+
+// In hard mode every green letter must stay in place and every yellow
+// letter must be used again, as many times as it was revealed.
+pub fn hard_mode_error(guess: &str, previous: &str, marks: &[Mark]) -> Option<HardModeError> {
+    let guess: Vec<char> = guess.chars().collect();
+    let mut unused = guess.clone();
+
+    for (i, c) in previous.chars().enumerate() {
+        if marks[i] == Mark::Correct {
+            if guess[i] != c {
+                return Some(HardModeError::MustBe { position: i + 1, letter: c });
+            }
+            if let Some(position) = unused.iter().position(|&u| u == c) {
+                unused.remove(position);
+            }
+        }
+    }
+
+    for (i, c) in previous.chars().enumerate() {
+        if marks[i] == Mark::Present {
+            match unused.iter().position(|&u| u == c) {
+                Some(position) => {
+                    unused.remove(position);
+                }
+                None => return Some(HardModeError::MustContain(c)),
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,153 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use rand::seq::SliceRandom;
+
+use crate::config::{self, Colour};
+use crate::i18n::t;
+use crate::read_input;
+use crate::session::Session;
+use crate::word::{HardModeError, Mark, hard_mode_error, score};
+
+// Five letter words, one per line, so the game never needs the network.
+const WORDS: &str = include_str!("words.txt");
+const WORD_LENGTH: usize = 5;
+const MAX_ATTEMPTS: u32 = 6;
+
+pub fn dictionary() -> Vec<&'static str> {
+    WORDS.lines().map(str::trim).filter(|word| !word.is_empty()).collect()
+}
+
+fn paint(guess: &str, marks: &[Mark], colour: bool) -> String {
+    guess
+        .chars()
+        .zip(marks)
+        .map(|(c, mark)| {
+            let c = c.to_ascii_uppercase();
+            if !colour {
+                return match mark {
+                    Mark::Correct => format!("[{c}]"),
+                    Mark::Present => format!("({c})"),
+                    Mark::Absent => format!(" {c} "),
+                };
+            }
+            let background = match mark {
+                Mark::Correct => "42",
+                Mark::Present => "43",
+                Mark::Absent => "100",
+            };
+            format!("\x1b[30;{background}m {c} \x1b[0m")
+        })
+        .collect()
+}
+
+pub fn emoji(marks: &[Mark]) -> String {
+    marks
+        .iter()
+        .map(|mark| match mark {
+            Mark::Correct => '🟩',
+            Mark::Present => '🟨',
+            Mark::Absent => '⬛',
+        })
+        .collect()
+}
+
+// Reveals the first letter that no guess or earlier hint has placed yet,
+// false when every letter already is.
+fn hint(secret: &str, history: &[(String, Vec<Mark>)], revealed: &mut Vec<usize>) -> bool {
+    let found = |i: usize| revealed.contains(&i) || history.iter().any(|(_, marks)| marks[i] == Mark::Correct);
+
+    let Some((i, c)) = secret.chars().enumerate().find(|&(i, _)| !found(i)) else {
+        return false;
+    };
+    println!("{}", t!("wordle-hint", position = i + 1, letter = c.to_ascii_uppercase()));
+    revealed.push(i);
+    true
+}
+
+// wordle [--hard]
+pub fn run(hard: bool) {
+    let dictionary = dictionary();
+    let secret = *dictionary
+        .choose(&mut crate::rng_for("wordle"))
+        .expect("The word list is empty");
+
+    play(&dictionary, secret, hard);
+}
+
+fn play(dictionary: &[&str], secret: &str, hard: bool) {
+    println!("{}", t!("wordle-intro", count = WORD_LENGTH));
+    if hard {
+        println!("{}", t!("wordle-hard-mode"));
+    }
+    println!("{}", t!("hint-available"));
+
+    // NO_COLOR is the usual way to ask terminal programs for plain text
+    let colour = match config::get().colour {
+        Colour::Always => true,
+        Colour::Never => false,
+        Colour::Auto => io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+    };
+    let hint_cost = config::get().hints.wordle;
+    let mut revealed = Vec::new();
+    let mut session = Session::new(Some(MAX_ATTEMPTS));
+    let mut history: Vec<(String, Vec<Mark>)> = Vec::new();
+
+    let won = loop {
+        if session.is_over() {
+            println!("{}", t!("wordle-you-lose", word = secret.to_ascii_uppercase()));
+            break false;
+        }
+
+        println!("{}", t!("enter-guess"));
+
+        let Some(input) = read_input() else { return };
+        let guess = input.trim().to_ascii_lowercase();
+
+        if guess == "hint" || guess == "?" {
+            if hint(secret, &history, &mut revealed) {
+                session.spend(hint_cost);
+                println!("{}", t!("hint-cost", count = hint_cost));
+            } else {
+                println!("{}", t!("wordle-no-hint"));
+            }
+            continue;
+        }
+
+        if guess.chars().count() != WORD_LENGTH || !dictionary.contains(&guess.as_str()) {
+            println!("{}", t!("wordle-not-a-word"));
+            continue;
+        }
+
+        if hard
+            && let Some((previous, marks)) = history.last()
+            && let Some(error) = hard_mode_error(&guess, previous, marks)
+        {
+            let error = match error {
+                HardModeError::MustBe { position, letter } => {
+                    t!("wordle-letter-must-be", position = position, letter = letter.to_ascii_uppercase())
+                }
+                HardModeError::MustContain(letter) => t!("wordle-must-contain", letter = letter.to_ascii_uppercase()),
+            };
+            println!("{error}");
+            continue;
+        }
+
+        session.attempt();
+        let marks = score(&guess, secret);
+        println!("{}", paint(&guess, &marks, colour));
+        history.push((guess, marks));
+
+        if history.last().is_some_and(|(_, marks)| marks.iter().all(|&m| m == Mark::Correct)) {
+            println!("{}", t!("you-win"));
+            break true;
+        }
+    };
+
+    let attempts = if won { session.attempts().to_string() } else { "X".to_string() };
+    let star = if hard { "*" } else { "" };
+    println!();
+    println!("{}", t!("wordle-share", attempts = attempts, max = MAX_ATTEMPTS, star = star));
+    for (_, marks) in &history {
+        println!("{}", emoji(marks));
+    }
+}
@@ -0,0 +1,58 @@
+use guessing_game::backend::{Backend, BackendError};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+#[test]
+fn names_round_trip() {
+    for text in ["chacha", "pcg", "xoshiro", "dice", "fixed:15,40,99", "fixed:7"] {
+        let backend: Backend = text.parse().unwrap();
+        assert_eq!(backend.to_string(), text);
+    }
+    assert_eq!("fixed: 1, 2".parse::<Backend>(), Ok(Backend::Fixed(vec![1, 2])));
+    assert_eq!("mersenne".parse::<Backend>(), Err(BackendError::Unknown("mersenne".to_string())));
+    assert_eq!("fixed:".parse::<Backend>(), Err(BackendError::NotANumber(String::new())));
+    assert_eq!("fixed:1,x".parse::<Backend>(), Err(BackendError::NotANumber("x".to_string())));
+
+    assert_eq!(Backend::default(), Backend::ChaCha);
+    assert!(Backend::Pcg.is_seeded());
+    assert!(!Backend::Dice.is_seeded() && !Backend::Fixed(vec![1]).is_seeded());
+}
+
+#[test]
+fn chacha_keeps_the_secrets_recorded_games_were_played_with() {
+    for seed in [0, 7, 42, u64::MAX] {
+        let expected = ChaCha8Rng::seed_from_u64(seed).gen_range(1..=100);
+        assert_eq!(Backend::ChaCha.source(seed).secret(1, 100), expected);
+    }
+}
+
+#[test]
+fn seeded_backends_repeat_and_differ() {
+    for backend in [Backend::ChaCha, Backend::Pcg, Backend::Xoshiro] {
+        let draws = |seed| -> Vec<u32> {
+            let mut source = backend.source(seed);
+            (0..20).map(|_| source.secret(1, 1000)).collect()
+        };
+        assert_eq!(draws(3), draws(3), "{backend}");
+        assert_ne!(draws(3), draws(4), "{backend}");
+    }
+
+    let first = |backend: Backend| -> Vec<u64> {
+        let mut source = backend.source(3);
+        (0..4).map(|_| source.r#gen()).collect()
+    };
+    assert_ne!(first(Backend::ChaCha), first(Backend::Pcg));
+    assert_ne!(first(Backend::Pcg), first(Backend::Xoshiro));
+}
+
+#[test]
+fn a_fixed_list_plays_its_values_in_turn() {
+    let mut source = Backend::Fixed(vec![15, 40, 250]).source(0);
+    let secrets: Vec<u32> = (0..4).map(|_| source.secret(1, 100)).collect();
+    // 250 does not fit, so it wraps to 1 + 250 % 100
+    assert_eq!(secrets, vec![15, 40, 51, 15]);
+
+    // anything else still comes from the seed
+    let mut other = Backend::Fixed(vec![15]).source(9);
+    assert_eq!(other.gen_range(0..1000u32), ChaCha8Rng::seed_from_u64(9).gen_range(0..1000u32));
+}
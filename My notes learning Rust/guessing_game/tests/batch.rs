@@ -0,0 +1,99 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+// Golden files: tests/batch/NAME.txt holds the guesses and NAME.out the
+// transcript they must print. Run with UPDATE_GOLDEN=1 to rewrite the .out
+// files after an intended change, then review the diff.
+fn golden(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/batch").join(name)
+}
+
+fn batch(args: &[&str], input: &str) -> Output {
+    let home = Path::new(env!("CARGO_TARGET_TMPDIR")).join("batch");
+    let mut child = Command::new(env!("CARGO_BIN_EXE_guessing_game"))
+        .arg("batch")
+        .args(["--seed", "7", "--lang", "en"])
+        .args(args)
+        .env("XDG_DATA_HOME", home.join("data"))
+        .env("XDG_CONFIG_HOME", home.join("config"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to start the game");
+
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+// Plays NAME.txt with --input and checks the transcript and exit status.
+fn check(name: &str, args: &[&str], code: i32) {
+    let input = golden(&format!("{name}.txt"));
+    let expected = golden(&format!("{name}.out"));
+
+    let mut all_args = vec!["--input", input.to_str().unwrap()];
+    all_args.extend(args);
+    let output = batch(&all_args, "");
+    let transcript = String::from_utf8(output.stdout).unwrap();
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&expected, &transcript).unwrap();
+    }
+    assert_eq!(transcript, fs::read_to_string(&expected).unwrap(), "{name}.out");
+    assert_eq!(output.status.code(), Some(code), "{name}");
+}
+
+#[test]
+fn win() {
+    check("win", &[], 0);
+}
+
+#[test]
+fn loss() {
+    check("loss", &["3"], 1);
+}
+
+#[test]
+fn invalid_input() {
+    check("invalid", &[], 0);
+}
+
+#[test]
+fn eof() {
+    check("eof", &[], 3);
+}
+
+#[test]
+fn empty_script() {
+    check("empty", &["5"], 3);
+}
+
+#[test]
+fn stdin_plays_like_a_file() {
+    let script = fs::read_to_string(golden("win.txt")).unwrap();
+    let output = batch(&[], &script);
+
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), fs::read_to_string(golden("win.out")).unwrap());
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn missing_script() {
+    let output = batch(&["--input", "no/such/file.txt"], "");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr).unwrap().starts_with("Could not read no/such/file.txt"));
+}
+
+#[test]
+fn dice_takes_the_secret_before_the_guesses() {
+    let output = batch(&["--rng", "dice"], "42\n50\n42\n");
+    let transcript = String::from_utf8(output.stdout).unwrap();
+
+    assert!(transcript.contains("Host, type the secret"), "{transcript}");
+    assert!(transcript.contains("You guessed: 50\nToo big!"), "{transcript}");
+    assert!(transcript.contains("You win in 2 attempts!"), "{transcript}");
+    assert_eq!(output.status.code(), Some(0));
+}
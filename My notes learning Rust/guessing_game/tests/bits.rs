@@ -0,0 +1,25 @@
+use guessing_game::bits;
+
+#[test]
+fn bits_round_up_past_powers_of_two() {
+    assert_eq!(bits(0u32), 0);
+    assert_eq!(bits(1u32), 0);
+    assert_eq!(bits(2u32), 1);
+    assert_eq!(bits(3u32), 2);
+    for k in 1..64 {
+        let power = 1u64 << k;
+        assert_eq!(bits(power), k, "2^{k}");
+        assert_eq!(bits(power + 1), k + 1, "2^{k} + 1");
+    }
+    assert_eq!(bits(u32::MAX), 32);
+    assert_eq!(bits(u128::MAX), 128);
+}
+
+#[test]
+fn binary_search_needs_one_more_than_the_bits_of_a_power_of_two() {
+    // 100 numbers: 7 either way; 64 numbers: 6 bits, but bisection can
+    // take 7 guesses, the first one leaves 32 on one side
+    assert_eq!(bits(100u32 + 1), 7);
+    assert_eq!(bits(64u32 + 1), 7);
+    assert_eq!(bits(64u32), 6);
+}
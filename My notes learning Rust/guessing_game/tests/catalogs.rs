@@ -0,0 +1,41 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+const LOCALES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/locales");
+
+// The keys a catalog defines: every `key = ...` line that is not indented.
+fn keys(file: &str) -> BTreeSet<String> {
+    let text = fs::read_to_string(Path::new(LOCALES).join(file)).expect("Failed to read catalog");
+
+    text.lines()
+        .filter(|line| !line.starts_with([' ', '#', '}']) && !line.is_empty())
+        .filter_map(|line| line.split_once('=').map(|(key, _)| key.trim().to_string()))
+        .collect()
+}
+
+#[test]
+fn every_catalog_has_every_english_key() {
+    let english = keys("en.ftl");
+    assert!(!english.is_empty());
+
+    for entry in fs::read_dir(LOCALES).expect("Failed to list catalogs") {
+        let file = entry.expect("Failed to read entry").file_name().into_string().unwrap();
+        let translated = keys(&file);
+        let missing: Vec<&String> = english.difference(&translated).collect();
+        assert!(missing.is_empty(), "{file} is missing {missing:?}");
+    }
+}
+
+#[test]
+fn every_plural_message_has_a_default() {
+    for entry in fs::read_dir(LOCALES).expect("Failed to list catalogs") {
+        let path = entry.expect("Failed to read entry").path();
+        let text = fs::read_to_string(&path).expect("Failed to read catalog");
+
+        for block in text.split("->").skip(1) {
+            let variants = block.split("\n}").next().unwrap_or_default();
+            assert!(variants.contains("*["), "{} has a plural message without a default", path.display());
+        }
+    }
+}
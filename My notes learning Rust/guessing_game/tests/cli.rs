@@ -0,0 +1,937 @@
+use std::fs;
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::time::Duration;
+
+use guessing_game::bits;
+
+// A fresh data and config directory for one test, so tests neither see
+// each other's scores nor the ones of whoever runs them.
+fn home(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cli").join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("config/guessing_game")).unwrap();
+    dir
+}
+
+fn run(home: &Path, args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_guessing_game"))
+        .args(args)
+        .env("XDG_DATA_HOME", home.join("data"))
+        .env("XDG_CONFIG_HOME", home.join("config"))
+        .env("LANG", "C")
+        .env("USER", "tester")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to start the game");
+
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+// Every number of the range, one per line: wins any classic game.
+fn every_number() -> String {
+    (1..=100).map(|n| format!("{n}\n")).collect()
+}
+
+#[test]
+fn help_lists_every_command() {
+    let output = run(&home("help"), &["--help"], "");
+
+    assert_eq!(output.status.code(), Some(0));
+    for command in ["play", "reverse", "daily", "stats", "replay", "serve", "bench", "config"] {
+        assert!(stdout(&output).contains(command), "{command} is missing from --help");
+    }
+    for flag in ["--seed", "--range", "--format"] {
+        assert!(stdout(&output).contains(flag), "{flag} is missing from --help");
+    }
+}
+
+#[test]
+fn version() {
+    let output = run(&home("version"), &["--version"], "");
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output).trim(), format!("guessing_game {}", env!("CARGO_PKG_VERSION")));
+}
+
+#[test]
+fn usage_errors_exit_with_2() {
+    let home = home("usage");
+
+    let output = run(&home, &["frobnicate"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("unrecognized subcommand"));
+
+    let output = run(&home, &["stats", "--format", "xml"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("invalid value 'xml'"));
+
+    let output = run(&home, &["play", "--range", "50-10"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("the range 50-10 is empty"));
+
+    let output = run(&home, &["play", "--range", "5-5"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("the range 5-5 holds a single number"));
+
+    let output = run(&home, &["coop", "--range", "0-4294967295", "ana", "bo"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("the range 0-4294967295 is too wide"));
+
+    // a valid config that a command cannot run with is not a config error
+    let output = run(&home, &["sort", "--items", "40"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("error: sort needs between 2 and 26 items, not 40"), "{}", stderr(&output));
+}
+
+#[test]
+fn the_same_seed_plays_the_same_game() {
+    let home = home("seed");
+    let transcript = |output: &Output| -> Vec<String> {
+        stdout(output)
+            .lines()
+            .filter(|line| !line.starts_with("Time played") && !line.starts_with("Achievement"))
+            .map(str::to_string)
+            .collect()
+    };
+
+    let first = run(&home, &["play", "--seed", "7"], &every_number());
+    let second = run(&home, &["--seed", "7", "play"], &every_number());
+
+    assert_eq!(first.status.code(), Some(0));
+    assert!(stdout(&first).contains("You win!"));
+    assert_eq!(transcript(&first), transcript(&second));
+}
+
+#[test]
+fn range_flag_sets_the_classic_range() {
+    let output = run(&home("range"), &["play", "--range", "1-3", "--seed", "1"], "1\n2\n3\n");
+
+    assert!(stdout(&output).contains("Guess the number between 1 and 3!"));
+    assert!(stdout(&output).contains("You win!"));
+}
+
+#[test]
+fn reverse_bisects_and_catches_cheating() {
+    let home = home("reverse");
+
+    let output = run(&home, &["reverse"], "l\nl\nl\nl\nl\ny\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).contains("Is it 50?"));
+    assert!(stdout(&output).contains("Found it in 6 guesses!"));
+
+    let output = run(&home, &["reverse", "--range", "1-2"], "l\n");
+    assert!(stdout(&output).contains("No number between 1 and 2 fits your answers"));
+}
+
+#[test]
+fn config_show_merges_file_and_flags() {
+    let home = home("config");
+    let file = home.join("config/guessing_game/config.toml");
+    fs::write(&file, "range = [1, 1000]\nplayer = \"ana\"\n\n[hints]\nwordle = 3\n").unwrap();
+
+    let output = run(&home, &["config", "show", "--format", "json", "--player", "bo"], "");
+    assert_eq!(output.status.code(), Some(0));
+
+    let config: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(config["range"], serde_json::json!([1, 1000]));
+    assert_eq!(config["player"], "bo");
+    assert_eq!(config["format"], "json");
+    assert_eq!(config["hints"]["wordle"], 3);
+    assert_eq!(config["hints"]["mastermind"], 1);
+}
+
+#[test]
+fn invalid_config_reports_line_and_column() {
+    let home = home("bad-config");
+    let file = home.join("broken.toml");
+    fs::write(&file, "player = \"ana\"\ncolour = \"pink\"\n").unwrap();
+
+    let output = run(&home, &["--config", file.to_str().unwrap(), "config", "show"], "");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("broken.toml:2:10: unknown variant `pink`"), "{}", stderr(&output));
+
+    // in the language of the flag, or of the file itself
+    let output = run(&home, &["--lang", "fr", "play", "--range", "50-10"], "");
+    assert!(stderr(&output).starts_with("Configuration invalide : l'intervalle 50-10 est vide"), "{}", stderr(&output));
+    fs::write(&file, "language = \"fr\"\nrange = [5, 5]\n").unwrap();
+    let output = run(&home, &["--config", file.to_str().unwrap(), "play"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("broken.toml:2:9: l'intervalle 5-5 ne contient qu'un nombre"), "{}", stderr(&output));
+}
+
+#[test]
+fn stats_and_replay_read_recorded_games() {
+    let home = home("stats");
+
+    let output = run(&home, &["stats"], "");
+    assert!(stdout(&output).contains("No games recorded yet."));
+
+    run(&home, &["play", "--seed", "7"], &every_number());
+
+    let output = run(&home, &["stats", "--format", "json"], "");
+    let stats: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(stats["games"], 1);
+    assert_eq!(stats["wins"], 1);
+
+    let output = run(&home, &["replay"], "");
+    assert!(stdout(&output).starts_with("tester on "));
+    assert!(stdout(&output).contains("seed 7"));
+    assert!(stdout(&output).contains("You win!"));
+
+    let output = run(&home, &["replay", "2"], "");
+    assert!(stdout(&output).contains("There is no recorded game number 2"));
+}
+
+#[test]
+fn verify_exits_with_1_on_tampered_scores() {
+    let home = home("verify");
+    run(&home, &["play", "--seed", "7"], &every_number());
+
+    let output = run(&home, &["verify"], "");
+    assert_eq!(output.status.code(), Some(0));
+
+    let scores = home.join("data/guessing_game/scores.txt");
+    let text = fs::read_to_string(&scores).unwrap().replacen("\twon\t", "\tlost\t", 1);
+    fs::write(&scores, text).unwrap();
+
+    let output = run(&home, &["verify"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("line 1: FLAGGED, bad signature"));
+    assert!(stdout(&output).contains("1 entry flagged."));
+
+    let output = run(&home, &["verify", "--lang", "fr"], "");
+    assert!(stdout(&output).contains("1 entrée signalée."));
+}
+
+#[test]
+fn serve_drops_clients_that_send_endless_lines() {
+    let home = home("serve");
+    let mut server = Command::new(env!("CARGO_BIN_EXE_guessing_game"))
+        .args(["serve", "--address", "127.0.0.1:0", "--seed", "7"])
+        .env("XDG_DATA_HOME", home.join("data"))
+        .env("XDG_CONFIG_HOME", home.join("config"))
+        .env("LANG", "C")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("Failed to start the server");
+    let mut log = BufReader::new(server.stdout.take().unwrap());
+    let mut line = String::new();
+    log.read_line(&mut line).unwrap();
+    let address = line.trim().rsplit(' ').next().unwrap().to_string();
+
+    // a megabyte without a newline is cut off long before the parser sees it
+    let mut client = TcpStream::connect(&address).unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+    let _ = client.write_all(&vec![b'('; 1 << 20]);
+    let mut reply = Vec::new();
+    let dropped = client.read_to_end(&mut reply).map_or_else(|error| error.kind() != ErrorKind::WouldBlock, |_| true);
+    assert!(dropped, "the server is still waiting for the end of the line");
+
+    // deep brackets that fit in a line get an answer, not a crash
+    let mut client = TcpStream::connect(&address).unwrap();
+    client.write_all(format!("{}1\n15\n", "(".repeat(1000)).as_bytes()).unwrap();
+    client.shutdown(Shutdown::Write).unwrap();
+    let mut reply = String::new();
+    client.read_to_string(&mut reply).unwrap();
+    server.kill().unwrap();
+    server.wait().unwrap();
+
+    assert!(reply.contains("brackets or signs inside each other"), "{reply}");
+    assert!(reply.contains("You win in 1 attempt!"), "{reply}");
+}
+
+#[test]
+fn bench_reports_every_solver() {
+    let output = run(&home("bench"), &["bench", "--games", "1", "--format", "json"], "");
+    assert_eq!(output.status.code(), Some(0));
+
+    let results: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    let solvers: Vec<&str> = results
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["solver"].as_str().unwrap())
+        .collect();
+    assert_eq!(solvers, ["classic", "grid", "multi", "mastermind"]);
+}
+
+#[test]
+fn lang_flag_picks_the_catalog() {
+    let output = run(&home("lang"), &["play", "--lang", "fr", "--seed", "3"], &every_number());
+
+    assert!(stdout(&output).starts_with("Devinez le nombre !"));
+    assert!(stdout(&output).contains("Gagné !"));
+}
+
+#[test]
+fn guess_errors_read_well_in_every_language() {
+    let home = home("guess-errors");
+    let input = "5 / 0\n0x\ntwo five\n";
+
+    let output = run(&home, &["play", "--seed", "3"], input);
+    assert!(stdout(&output).contains("Could not read that guess: division by zero."));
+    assert!(stdout(&output).contains("Could not read that guess: 0x is not a number."));
+    assert!(stdout(&output).contains("Could not read that guess: \"two five\" does not spell a number."));
+
+    let output = run(&home, &["play", "--lang", "fr", "--seed", "3"], input);
+    assert!(stdout(&output).contains("Proposition illisible : division par zéro."), "{}", stdout(&output));
+    assert!(stdout(&output).contains("n'est pas un nombre."));
+    assert!(!stdout(&output).contains("not a number"));
+
+    let output = run(&home, &["play", "--lang", "ar", "--seed", "3"], input);
+    assert!(stdout(&output).contains("قسمة على صفر"), "{}", stdout(&output));
+    assert!(!stdout(&output).contains("division by zero"));
+}
+
+#[test]
+fn coop_keeps_players_in_their_range_and_records_the_team() {
+    let home = home("coop");
+    let script = "> low, I think\n25\n12\npass\n12\npass\n15\n";
+
+    let output = run(&home, &["coop", "ana", "bo", "--seed", "7"], script);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).contains("bo guesses between 51 and 100."));
+    assert!(stdout(&output).contains("ana says: low, I think"));
+    assert!(stdout(&output).contains("12 is not yours to guess"));
+    assert!(stdout(&output).contains("ana found it, the team wins in 3 attempts!"));
+
+    let output = run(&home, &["leaderboard", "history", "ana+bo"], "");
+    assert!(stdout(&output).contains("won  in 3 attempts"));
+    assert_eq!(run(&home, &["verify"], "").status.code(), Some(0));
+}
+
+#[test]
+fn drift_tracking_beats_bisection() {
+    let home = home("drift");
+
+    let output = run(&home, &["drift", "--simulate", "--games", "200", "--seed", "1", "--format", "json"], "");
+    assert_eq!(output.status.code(), Some(0));
+    let report: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    let [tracking, bisection] = [0, 1].map(|i| &report["solvers"][i]);
+    assert_eq!(tracking["solver"], "tracking");
+    assert_eq!(tracking["solved"], 200);
+    assert!(tracking["average_guesses"].as_f64() < bisection["average_guesses"].as_f64());
+
+    // without drift it is the classic game
+    let output = run(&home, &["drift", "--step", "0", "--seed", "7"], &every_number());
+    assert!(stdout(&output).contains("It moves by up to 0 after every wrong guess."));
+    assert!(stdout(&output).contains("You win in 15 attempts!"));
+
+    // the widest step is the range, so a huge one is refused, not allocated
+    for mode in [&["drift"][..], &["drift", "--simulate"]] {
+        let output = run(&home, &[mode, &["--step", "1000000000"]].concat(), "");
+        assert_eq!(output.status.code(), Some(2));
+        assert!(stderr(&output).contains("a step of 1000000000 does not fit the range 1-100"), "{}", stderr(&output));
+    }
+    // every drift still plays and tracks with the widest step
+    for drift in ["uniform", "triangular", "lazy"] {
+        let args = ["drift", "--simulate", "--games", "20", "--step", "99", "--drift", drift, "--seed", "1"];
+        assert_eq!(run(&home, &args, "").status.code(), Some(0), "{drift}");
+    }
+}
+
+#[test]
+fn multi_shows_what_is_known_about_each_secret() {
+    let home = home("multi");
+
+    let output = run(&home, &["multi", "--count", "2", "--seed", "3", "--attempts", "1"], "50\n");
+    assert!(stdout(&output).contains("2 different secrets are hidden between 1 and 100."));
+    assert!(stdout(&output).contains("Below: 1, here: 0, above: 1"));
+    assert!(stdout(&output).contains("#1 1-49   #2 51-100"));
+    assert!(stdout(&output).contains("You lose! The secrets were 11, 63."));
+
+    for count in ["1", "5", "10"] {
+        let output = run(&home, &["multi", "--solve", "--count", count, "--seed", "3"], "");
+        assert!(stdout(&output).contains("All found in"), "{count} secrets");
+    }
+    // ten numbers are few enough for the exact solver
+    let output = run(&home, &["multi", "--solve", "--count", "3", "--range", "1-10", "--seed", "3"], "");
+    assert!(stdout(&output).contains("All found in"));
+
+    let output = run(&home, &["multi", "--count", "4", "--range", "1-3"], "");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn twenty_questions_answers_and_counts_questions() {
+    let home = home("twenty");
+
+    let script = "is it prime?\nwhat?\nis it 63?\n";
+    let output = run(&home, &["twenty", "--seed", "3", "--questions", "3"], script);
+    assert!(stdout(&output).contains("Your question (3 left):\nNo."));
+    assert!(stdout(&output).contains("I do not understand that question: what is not part of a question."));
+    assert!(stdout(&output).contains("Got it with 2 questions!"));
+
+    let output = run(&home, &["twenty", "--solve", "--seed", "3"], "");
+    assert!(stdout(&output).contains("1: even? No. (50 numbers left)"));
+    assert!(stdout(&output).contains("Got it with 7 questions!"));
+
+    let script = "is it in 20\ndivisible by 0\n";
+    let output = run(&home, &["twenty", "--seed", "3"], script);
+    assert!(stdout(&output).contains("I do not understand that question: the end of the range is missing."));
+    assert!(stdout(&output).contains("I do not understand that question: nothing is divisible by 0."));
+
+    let output = run(&home, &["twenty", "--lang", "fr", "--seed", "3"], script);
+    assert!(stdout(&output).contains("Je ne comprends pas cette question : il manque la fin de l'intervalle."));
+    assert!(stdout(&output).contains("Je ne comprends pas cette question : aucun nombre n'est divisible par 0."));
+}
+
+#[test]
+fn cost_mode_adds_up_prices_and_compares_with_the_best() {
+    let home = home("cost");
+
+    let output = run(&home, &["cost", "--seed", "7"], "50\n15\n");
+    assert!(stdout(&output).contains("Every guess costs its own value"));
+    assert!(stdout(&output).contains("Too big!\nCost 50, 50 so far."));
+    assert!(stdout(&output).contains("Cost 15, 65 so far."));
+    assert!(stdout(&output).contains("You got it with 2 guesses for a total cost of 65."));
+    assert!(stdout(&output).contains("The best strategy pays"));
+
+    let output = run(&home, &["cost", "--cost", "over", "--penalty", "5", "--solve", "--seed", "7"], "");
+    assert!(stdout(&output).contains("and 5 more when it is too high"));
+    assert!(stdout(&output).contains("1: "));
+
+    let output = run(&home, &["cost", "--range", "1-100000"], "");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn sort_compares_items_and_checks_the_order() {
+    let home = home("sort");
+
+    let output = run(&home, &["sort", "--seed", "3"], "A B\nxyz\nB < D < E < C < A\n");
+    assert!(stdout(&output).contains("5 items, A to E, are in a hidden order."));
+    assert!(stdout(&output).contains("A > B\n1 comparison so far:"));
+    assert!(stdout(&output).contains("Type two items from A to E"));
+    assert!(stdout(&output).contains("That is the order! You used 1 comparison."));
+    assert!(stdout(&output).contains("Sorting 5 items takes at least 7 comparisons in the worst case."));
+
+    let output = run(&home, &["sort", "--seed", "3"], "A < B < C < D < E\n");
+    assert!(stdout(&output).contains("Wrong! The order was B < D < E < C < A."));
+
+    let output = run(&home, &["sort", "--seed", "3", "--bot", "merge-insertion"], "");
+    assert!(stdout(&output).contains("merge-insertion found B < D < E < C < A with 7 comparisons."));
+
+    let output = run(&home, &["sort", "--simulate", "--items", "12", "--games", "50"], "");
+    assert!(stdout(&output).contains("at least 29 comparisons"));
+
+    let output = run(&home, &["sort", "--items", "27"], "");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn skewed_shows_the_prior_and_compares_solvers() {
+    let home = home("skewed");
+
+    let output = run(&home, &["skewed", "--seed", "4", "--distribution", "zipf", "--solve", "tree"], "");
+    assert!(stdout(&output).contains("Not every number is as likely."));
+    assert!(stdout(&output).contains("1-10 ██████████████████████████████  56.5%"));
+    assert!(stdout(&output).contains("The best strategy for this spread finds this number with"));
+
+    let output = run(&home, &["skewed", "--simulate", "--distribution", "geometric", "--format", "json"], "");
+    let report: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    let expected: Vec<f64> = report["solvers"].as_array().unwrap().iter().map(|s| s["expected_guesses"].as_f64().unwrap()).collect();
+    assert!(expected[2] <= expected[1] && expected[1] < expected[0], "{expected:?}");
+
+    let weights = home.join("die.txt");
+    fs::write(&weights, "6 1\n").unwrap();
+    let output = run(&home, &["skewed", "--range", "1-6", "--weights", weights.to_str().unwrap()], "6\n");
+    assert!(stdout(&output).contains("You win in 1 attempt!"));
+
+    fs::write(&weights, "7 1\n").unwrap();
+    let output = run(&home, &["skewed", "--range", "1-6", "--weights", weights.to_str().unwrap()], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("7 is outside 1..=6"));
+}
+
+#[test]
+fn infer_names_the_distribution() {
+    let home = home("infer");
+
+    let output = run(&home, &["infer", "--seed", "5"], "\n\nfoo\nzipf\n");
+    assert!(stdout(&output).contains("1 draw so far."));
+    assert!(stdout(&output).contains("That is not one of: uniform, normal, geometric, zipf."));
+    assert!(stdout(&output).contains("Wrong, it was normal."));
+    assert!(stdout(&output).contains("From these draws: normal"));
+
+    let output = run(&home, &["infer", "--seed", "5", "--solve"], "");
+    assert!(stdout(&output).contains("Right, it is normal!"));
+
+    let output = run(&home, &["infer", "--seed", "5", "--rounds", "1"], "\n\n");
+    assert!(stdout(&output).contains("No draws left! It was normal."));
+}
+
+#[test]
+fn fairness_with_a_fixed_seed() {
+    let home = home("fairness");
+
+    let output = run(&home, &["fairness", "--seed", "1", "--samples", "20000", "--range", "5-17"], "");
+    assert_eq!(output.status.code(), Some(0), "{}", stdout(&output));
+    assert!(stdout(&output).contains("20000 secrets per range and backend"));
+    for row in ["chacha   1..=2 ", "pcg      1..=100 ", "xoshiro  0..=4294967295 ", "chacha   5..=17 "] {
+        assert!(stdout(&output).contains(row), "{row}");
+    }
+    assert!(!stdout(&output).contains("FAILED"));
+
+    let output = run(&home, &["fairness", "--seed", "1", "--samples", "5000", "--format", "json"], "");
+    let report: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(report["failed"], 0);
+    assert_eq!(report["ranges"][2]["endpoints_seen"], true);
+    assert_eq!(report["ranges"][7]["backend"], "pcg");
+}
+
+// A fresh seed every run, so it fails about once in twenty-five runs
+// even when nothing is wrong: cargo test -- --ignored
+#[test]
+#[ignore]
+fn fairness_with_random_seeds() {
+    let home = home("fairness-random");
+
+    let output = run(&home, &["fairness"], "");
+    assert_eq!(output.status.code(), Some(0), "{}", stdout(&output));
+}
+
+#[test]
+fn a_resumed_game_keeps_its_backend() {
+    let home = home("rng-resume");
+
+    // the host types 33, the player leaves before finding it
+    run(&home, &["--rng", "dice", "play"], "33\n50\n");
+    let output = run(&home, &["resume"], "20\n33\n");
+    assert!(stdout(&output).contains("You guessed: 33\nYou win!"));
+    assert!(stdout(&output).contains("recorded as unverifiable"));
+
+    let verify = run(&home, &["verify"], "");
+    assert_eq!(verify.status.code(), Some(0), "{}", stdout(&verify));
+    assert!(stdout(&verify).contains("line 1: unverifiable, the secret came from dice"));
+    let replay = run(&home, &["replay"], "");
+    assert!(stdout(&replay).contains("You guessed: 50 Too big!"));
+}
+
+#[test]
+fn rng_backends_are_announced_and_replayable() {
+    let french = run(&home("rng-fr"), &["--rng", "pcg", "--lang", "fr", "play", "--seed", "5"], &every_number());
+    assert!(stderr(&french).contains("générateur pcg, graine 5"));
+
+    let home = home("rng");
+
+    let output = run(&home, &["--rng", "pcg", "play", "--seed", "5"], &every_number());
+    assert!(stderr(&output).contains("rng pcg, seed 5"));
+    let secret = |output: &Output| stdout(output).lines().take_while(|line| *line != "You win!").last().map(str::to_string);
+    let again = run(&home, &["--rng", "pcg", "play", "--seed", "5"], &every_number());
+    assert_eq!(secret(&output), secret(&again));
+
+    // recorded with the backend, which verify replays it with
+    let verify = run(&home, &["verify"], "");
+    assert_eq!(verify.status.code(), Some(0), "{}", stdout(&verify));
+    assert!(stdout(&verify).contains("line 1: ok"));
+    let chacha = run(&home, &["play", "--seed", "5"], &every_number());
+    assert_ne!(secret(&output), secret(&chacha));
+
+    let output = run(&home, &["--rng", "fixed:42", "play"], "42\n");
+    assert!(stdout(&output).contains("You guessed: 42\nYou win!"));
+
+    let output = run(&home, &["--rng", "dice", "play"], "500\n33\n33\n");
+    assert!(stdout(&output).contains("The secret has to be a number between 1 and 100."));
+    assert!(stdout(&output).contains("You guessed: 33\nYou win!"));
+    let output = run(&home, &["--rng", "dice", "play"], "");
+    assert_eq!(output.status.code(), Some(3));
+
+    // fixed lists and dice are recorded, but not ranked
+    let verify = run(&home, &["verify"], "");
+    assert_eq!(verify.status.code(), Some(0), "{}", stdout(&verify));
+    assert!(stdout(&verify).contains("line 4: unverifiable, the secret came from fixed:42"));
+    assert!(stdout(&verify).contains("line 5: unverifiable, the secret came from dice"));
+    let top = run(&home, &["leaderboard", "top", "normal"], "");
+    assert_eq!(stdout(&top).lines().count(), 3, "{}", stdout(&top));
+    let history = run(&home, &["leaderboard", "history"], "");
+    assert!(stdout(&history).contains("(unverifiable, dice)"));
+
+    // timed and grid games draw from the backend too, and the modes that
+    // draw something else refuse what cannot pick it
+    let output = run(&home, &["--rng", "fixed:42", "timed", "total", "60"], "42\n");
+    assert!(stdout(&output).contains("You win!"), "{}", stdout(&output));
+    let output = run(&home, &["--rng", "fixed:3,4", "grid", "5", "5"], "3 4\n");
+    assert!(stdout(&output).contains("You win in 1 attempt!"), "{}", stdout(&output));
+    for mode in ["wordle", "multi", "skewed"] {
+        let output = run(&home, &["--rng", "fixed:42", mode], "");
+        assert_eq!(output.status.code(), Some(2), "{mode}");
+        assert!(stderr(&output).contains("cannot use --rng fixed:42"), "{mode}");
+    }
+    let output = run(&home, &["--rng", "dice", "serve", "--address", "127.0.0.1:0"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("serve cannot use --rng dice"));
+
+    let output = run(&home, &["--rng", "mersenne", "play"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("unknown rng mersenne"));
+
+    let output = run(&home, &["config", "show", "--rng", "xoshiro"], "");
+    assert!(stdout(&output).contains("rng = \"xoshiro\""));
+}
+
+#[test]
+fn timed_games_play_on_the_real_clock() {
+    let home = home("timed");
+
+    // the limits themselves are checked on a fake clock in tests/timing.rs
+    let output = run(&home, &["--rng", "fixed:42", "timed", "total"], "50\n42\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).contains("Too big!\nPlease enter your guess :\nYou win!"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("  1.    50  Too big!   +"));
+    assert!(stdout(&output).contains("  2.    42  You win!   +"));
+
+    for seconds in ["nan", "0", "-5", "inf", "ten"] {
+        let output = run(&home, &["timed", "total", "--", seconds], "");
+        assert_eq!(output.status.code(), Some(2), "{seconds}");
+        assert!(stderr(&output).contains(&format!("invalid value '{seconds}'")), "{seconds}");
+    }
+}
+
+#[test]
+fn the_grid_solver_finds_every_cell() {
+    let home = home("grid");
+    let worst = |output: &Output| -> u32 {
+        let report = stdout(output);
+        let (_, after) = report.split_once("worst case ").expect("no report");
+        after.split(' ').next().unwrap().parse().unwrap()
+    };
+
+    // with directions, never more than a binary search on the longer side,
+    // and every cell is found within ceil(log2 w) + ceil(log2 h) direction
+    // answers, the guess that finds it aside
+    for (width, height) in [(1, 1), (2, 3), (10, 10), (7, 33), (64, 5), (64, 64), (64, 1), (1, 64), (17, 9)] {
+        let output = run(&home, &["grid", "--solve", &width.to_string(), &height.to_string()], "");
+        let bisection = bits(u32::max(width, height) + 1);
+        assert!(worst(&output) <= bisection, "{width}x{height}: {}", stdout(&output));
+        assert!(worst(&output) - 1 <= bits(width) + bits(height), "{width}x{height}: {}", stdout(&output));
+        assert!(stdout(&output).contains(&format!("bisecting each axis takes {bisection} guess")));
+        assert!(stdout(&output).contains("the solver needs no more than that"));
+    }
+
+    // distances leave rings, and the solver still finds every cell quickly
+    for (size, most) in [("1", 1), ("3", 3), ("8", 3), ("12", 3)] {
+        let output = run(&home, &["grid", "--solve", size, size, "--hint", "distance"], "");
+        assert!(worst(&output) <= most, "{size}x{size}: {}", stdout(&output));
+        assert!(!stdout(&output).contains("bisecting"));
+    }
+
+    for (width, height) in [("0", "5"), ("5", "65")] {
+        let output = run(&home, &["grid", width, height], "");
+        assert_eq!(output.status.code(), Some(2));
+        assert!(stderr(&output).contains(&format!("not {width}x{height}")), "{}", stderr(&output));
+    }
+
+    let output = run(&home, &["--rng", "fixed:3,4", "grid", "5", "5"], "9 9\nthree\n3 4\n");
+    let complaint = "Please enter two whole numbers, x from 1 to 5 and y from 1 to 5.";
+    assert_eq!(stdout(&output).matches(complaint).count(), 2);
+    assert!(stdout(&output).contains("You win in 1 attempt!"));
+}
+
+#[test]
+fn stats_survive_hand_edited_attempt_counts() {
+    let home = home("stats-edited");
+    let scores = home.join("data/guessing_game/scores.txt");
+    fs::create_dir_all(scores.parent().unwrap()).unwrap();
+    let line = |attempts: u32| format!("ana\t1\t7\t1..=100\tnone\t50\t{attempts}\twon\n");
+    fs::write(&scores, [line(0), line(4_000_000_000), line(4_000_000_000), line(3)].concat()).unwrap();
+
+    let output = run(&home, &["stats", "--format", "json"], "");
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    let stats: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(stats["wins"], 4);
+    assert_eq!(stats["average_attempts"], 3.0);
+    assert_eq!(stats["best"], 3);
+    assert_eq!(stats["attempts"], serde_json::json!([0, 0, 1]));
+
+    let output = run(&home, &["stats"], "");
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+
+    // a range no flag accepts still replays without overflowing
+    fs::write(&scores, "ana\t1\t7\t0..=4294967295\tnone\t5,7\t2\tlost\n").unwrap();
+    let output = run(&home, &["replay"], "");
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    let output = run(&home, &["leaderboard", "import"], "");
+    assert!(stdout(&output).contains("Imported 1 game"), "{}", stderr(&output));
+}
+
+#[test]
+fn leaderboard_migrates_imports_once_and_exports() {
+    let home = home("leaderboard-file");
+    let database = home.join("data/guessing_game/leaderboard.db");
+    fs::create_dir_all(database.parent().unwrap()).unwrap();
+
+    // a database from before the rng column, at schema version 3
+    let connection = rusqlite::Connection::open(&database).unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE results (
+                id INTEGER PRIMARY KEY,
+                player TEXT NOT NULL,
+                played_at INTEGER NOT NULL,
+                difficulty TEXT NOT NULL,
+                low INTEGER NOT NULL,
+                high INTEGER NOT NULL,
+                max_attempts INTEGER,
+                attempts INTEGER NOT NULL,
+                won INTEGER NOT NULL,
+                seed INTEGER NOT NULL
+            );
+            CREATE UNIQUE INDEX results_unique ON results (player, played_at, seed);
+            INSERT INTO results (player, played_at, difficulty, low, high, max_attempts, attempts, won, seed)
+                VALUES ('ana', 1700000000, 'normal', 1, 100, NULL, 5, 1, 7);
+            PRAGMA user_version = 3;",
+        )
+        .unwrap();
+    drop(connection);
+
+    let header = "player,played_at,difficulty,low,high,max_attempts,attempts,won,seed,rng\n";
+    let output = run(&home, &["leaderboard", "export"], "");
+    assert_eq!(stdout(&output), format!("{header}ana,1700000000,normal,1,100,,5,true,7,chacha\n"));
+    let connection = rusqlite::Connection::open(&database).unwrap();
+    let version: u32 = connection.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+    assert_eq!(version, 4);
+
+    // a played game is already on the board, so importing it adds nothing
+    run(&home, &["play", "--seed", "7", "--player", "bo"], &every_number());
+    let output = run(&home, &["leaderboard", "import"], "");
+    assert!(stdout(&output).contains("Imported 0 games, skipped 0 invalid lines."), "{}", stdout(&output));
+    let copy = home.join("copy.txt");
+    let scores = fs::read_to_string(home.join("data/guessing_game/scores.txt")).unwrap();
+    fs::write(&copy, format!("{scores}not a score\n")).unwrap();
+    let output = run(&home, &["leaderboard", "import", copy.to_str().unwrap()], "");
+    assert!(stdout(&output).contains("Imported 0 games, skipped 1 invalid line."), "{}", stdout(&output));
+
+    let output = run(&home, &["leaderboard", "export"], "");
+    assert_eq!(stdout(&output).lines().count(), 3, "{}", stdout(&output));
+    assert!(stdout(&output).lines().any(|line| line.starts_with("bo,") && line.ends_with(",true,7,chacha")));
+
+    let file = home.join("games.csv");
+    run(&home, &["leaderboard", "export", file.to_str().unwrap()], "");
+    assert_eq!(fs::read_to_string(&file).unwrap(), stdout(&output));
+
+    let missing = home.join("missing/games.csv");
+    let output = run(&home, &["leaderboard", "export", missing.to_str().unwrap()], "");
+    assert_eq!(output.status.code(), Some(1), "{}", stderr(&output));
+    assert!(stderr(&output).starts_with(&format!("Cannot write {}: ", missing.display())), "{}", stderr(&output));
+    assert!(stdout(&output).is_empty());
+}
+
+#[test]
+fn the_daily_puzzle_is_used_up_by_the_first_guess() {
+    let home = home("daily");
+
+    // leaving before guessing keeps the day open
+    let output = run(&home, &["daily"], "");
+    assert!(stdout(&output).starts_with("Daily challenge "));
+    let output = run(&home, &["daily"], "1\n");
+    assert!(stdout(&output).starts_with("Daily challenge "));
+
+    // one guess and a closed input count as played, with the share kept
+    let output = run(&home, &["daily"], "");
+    assert!(stdout(&output).contains("You already played the "), "{}", stdout(&output));
+    assert!(stdout(&output).lines().nth(1).unwrap().starts_with("Daily "));
+
+    // but only for that player
+    let output = run(&home, &["daily", "--player", "bo"], "");
+    assert!(stdout(&output).starts_with("Daily challenge "));
+
+    // a line from before players were kept counts for everyone
+    let history = home.join("data/guessing_game/daily.txt");
+    let date = fs::read_to_string(&history).unwrap().split('\t').next().unwrap().to_string();
+    fs::write(&history, format!("{date}\tDaily {date} 1-50 3/8 ⬆⬇✅\n")).unwrap();
+    let output = run(&home, &["daily", "--player", "bo"], "");
+    assert!(stdout(&output).contains(&format!("Daily {date} 1-50 3/8 ⬆⬇✅")), "{}", stdout(&output));
+
+    // the share line is translated, with every value isolated in Arabic
+    let home = self::home("daily-lang");
+    let output = run(&home, &["daily", "--player", "cy", "--lang", "fr"], "1\n");
+    assert!(stdout(&output).contains(&format!("Défi du jour {date} 1-")), "{}", stdout(&output));
+    let output = run(&home, &["daily", "--player", "di", "--lang", "ar"], "1\n");
+    let share = stdout(&output).lines().find(|line| line.contains("تحدي اليوم")).unwrap().to_string();
+    assert!(share.starts_with('\u{2067}') && share.ends_with('\u{2069}'), "{share:?}");
+    assert!(share.contains(&format!("\u{2068}{date}\u{2069}")), "{share:?}");
+    assert!(share.contains("\u{2068}1-"), "{share:?}");
+}
+
+#[test]
+fn saved_games_resume_and_damaged_ones_are_refused() {
+    let home = home("save");
+    let saves = home.join("data/guessing_game/saves");
+    let save = || fs::read_dir(&saves).unwrap().next().unwrap().unwrap().path();
+
+    // seed 7 hides 15; the first run leaves after one guess
+    run(&home, &["play", "--seed", "7", "--unsealed", "5"], "1\n");
+    let text = fs::read_to_string(save()).unwrap();
+    assert!(text.contains("secret=15\n") && text.contains("guesses=1\n") && text.contains("max_attempts=5\n"));
+    let sealed = text.replace("secret=15", "secret=sealed:0000000000000001:0000000000000002");
+
+    // what was played and for how long carries over
+    fs::write(save(), text.replace("elapsed=0.000", "elapsed=100.000")).unwrap();
+    let output = run(&home, &["resume"], "15\n");
+    assert!(stdout(&output).contains("You guessed: 1 Too small!"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("You win!"));
+    assert!(stdout(&output).contains("Time played: 100s"));
+    assert!(fs::read_dir(&saves).unwrap().next().is_none());
+    let output = run(&home, &["replay"], "");
+    assert!(stdout(&output).contains("You guessed: 1 Too small!"));
+
+    let path = saves.join("edited.save");
+    for (edited, error) in [
+        (text.replace("elapsed=0.000", "elapsed=-5"), "bad elapsed time"),
+        (text.replace("elapsed=0.000", "elapsed=NaN"), "bad elapsed time"),
+        (text.replace("range=1..=100", "range=9..=9"), "holds a single number"),
+        (text.replace("range=1..=100", "range=0..=4294967295"), "is too wide"),
+        (text.replace("secret=15", "secret=40"), "the secret does not belong to the game"),
+        (sealed, "bad secret"),
+        (text.replace("guesses=1", "guesses=1,2,3,4,5,6"), "more guesses than attempts"),
+        (text.replace("seed=7", "seed=x"), "bad seed"),
+        (String::from("not a save"), "missing range"),
+    ] {
+        fs::write(&path, edited).unwrap();
+        // the game stops before reading anything, so there is no input
+        let output = run(&home, &["resume", "edited"], "");
+        assert!(stdout(&output).starts_with("edited is damaged: "), "{}", stdout(&output));
+        assert!(stdout(&output).contains(error), "{}", stdout(&output));
+    }
+}
+
+#[test]
+fn achievements_unlock_on_their_conditions() {
+    let unlocked = |output: &Output| -> Vec<String> {
+        stdout(output)
+            .lines()
+            .filter_map(|line| line.strip_prefix("Achievement unlocked: ")?.strip_suffix('!'))
+            .map(str::to_string)
+            .collect()
+    };
+
+    // the built-in list: fifteen guesses is a win but no binary search,
+    // and neither is one guess, it takes exactly seven on 1 to 100
+    let home = home("achievements");
+    let output = run(&home, &["--rng", "fixed:15", "play"], &every_number());
+    assert_eq!(unlocked(&output), ["First win"]);
+    let output = run(&home, &["--rng", "fixed:15", "play"], "15\n");
+    assert_eq!(unlocked(&output), ["Mind reader"]);
+    let output = run(&home, &["--rng", "fixed:7", "play"], "1\n2\n3\n4\n5\n6\n7\n");
+    assert_eq!(unlocked(&output), ["Binary search"]);
+    let output = run(&home, &["--rng", "fixed:15", "play"], "15\n");
+    assert!(unlocked(&output).is_empty());
+    let list = run(&home, &["achievements"], "");
+    assert!(stdout(&list).contains("[x] First win"));
+    assert!(stdout(&list).contains("[ ] On a roll"));
+    assert!(stdout(&list).contains("[ ] Outsmarted the host"));
+
+    // optimal is ceil(log2(range)): k on 2^k numbers, k + 1 on one more
+    for (range, optimal) in [("1-2", 1), ("1-3", 2), ("1-64", 6), ("1-65", 7), ("1-1024", 10), ("1-1025", 11)] {
+        for attempts in [optimal, optimal + 1] {
+            let home = self::home("achievements-optimal");
+            fs::write(home.join("config/guessing_game/achievements.txt"), "exact | Exact | won && attempts == optimal\n").unwrap();
+            let guesses: String = (1..=attempts).map(|guess| format!("{guess}\n")).collect();
+            let output = run(&home, &["--rng", &format!("fixed:{attempts}"), "play", "--range", range], &guesses);
+            let expected: &[&str] = if attempts == optimal { &["Exact"] } else { &[] };
+            assert_eq!(unlocked(&output), expected, "{attempts} guesses on {range}");
+        }
+    }
+
+    let home = self::home("achievements-own");
+    fs::write(
+        home.join("config/guessing_game/achievements.txt"),
+        "seven   | Seven   | won && optimal == 7\n\
+         six     | Six     | mode == classic && optimal == 6\n\
+         twice   | Twice   | streak >= 2\n\
+         third   | Third   | mode == classic && games >= 3\n\
+         broken  | Broken  | attempts ~ 3\n\
+         regular | Regular | mode == daily && daily_streak >= 3\n",
+    )
+    .unwrap();
+
+    // 64 numbers take 6 bits, 100 take 7
+    let output = run(&home, &["--rng", "fixed:64", "play", "--range", "1-64"], "64\n");
+    assert_eq!(unlocked(&output), ["Six"]);
+    let output = run(&home, &["--rng", "fixed:64", "play"], "64\n");
+    assert_eq!(unlocked(&output), ["Seven", "Twice"]);
+    let output = run(&home, &["--rng", "fixed:64", "play", "1"], "1\n");
+    assert_eq!(unlocked(&output), ["Third"]);
+
+    // the third daily in a row
+    let today = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs() / 86_400;
+    let progress = home.join("data/guessing_game/achievements_progress.txt");
+    fs::write(&progress, format!("games=3\nstreak=0\ndaily_streak=2\nlast_daily={}\n", today - 1)).unwrap();
+    let output = run(&home, &["daily"], "1\n");
+    assert_eq!(unlocked(&output), ["Regular"]);
+}
+
+#[test]
+fn ratings_follow_games_with_a_budget() {
+    let home = home("rating");
+
+    // a new player against 1 to 100 in 7 attempts, a hard game to win
+    let output = run(&home, &["--rng", "fixed:15", "play", "7"], "15\n");
+    assert!(stdout(&output).contains("Rating: 1037 (+37)"), "{}", stdout(&output));
+    // games without a budget leave the rating alone
+    let output = run(&home, &["--rng", "fixed:15", "play"], "15\n");
+    assert!(!stdout(&output).contains("Rating:"));
+    let ratings = fs::read_to_string(home.join("data/guessing_game/ratings.txt")).unwrap();
+    assert_eq!(ratings, "tester\t1037.4\t1\n");
+
+    let output = run(&home, &["adaptive"], "");
+    assert!(stdout(&output).contains("Rating 1037: 11 attempts, about 73% chance to win."), "{}", stdout(&output));
+    let output = run(&home, &["adaptive", "--player", "bo"], "");
+    assert!(stdout(&output).contains("Rating 1000: "), "{}", stdout(&output));
+}
+
+#[test]
+fn mastermind_says_what_a_code_looks_like() {
+    let output = run(&home("mastermind"), &["mastermind", "--seed", "3"], "12\n1239\n1234\n");
+    assert_eq!(stdout(&output).matches("Please enter 4 symbols from 123456.").count(), 2, "{}", stdout(&output));
+    assert!(stdout(&output).contains(" exact, ") || stdout(&output).contains("You win in 1 attempt!"));
+}
+
+#[test]
+fn mastermind_refuses_codes_it_cannot_play() {
+    let home = home("mastermind-sizes");
+
+    let output = run(&home, &["mastermind", "--length", "7"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("error: a code is 1 to 6 symbols long, not 7"), "{}", stderr(&output));
+
+    let output = run(&home, &["mastermind", "--length", "0"], "");
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = run(&home, &["mastermind", "--symbols", "12"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("error: a code uses 2 to 9 symbols, not 12"), "{}", stderr(&output));
+
+    let output = run(&home, &["mastermind", "--unique", "--length", "5", "--symbols", "4"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("a code of 5 without repeats needs at least 5 symbols"));
+    assert!(stdout(&output).is_empty());
+}
+
+#[test]
+fn wordle_hints_cost_nothing_once_every_letter_is_known() {
+    // seed 2 hides CLAIM; five hints spell it out and use five of the six
+    // attempts, the sixth hint has nothing left to show
+    let input = "hint\n".repeat(6) + "claim\n";
+    let output = run(&home("wordle-hints"), &["wordle", "--seed", "2"], &input);
+    assert_eq!(stdout(&output).matches("That hint cost 1 attempt.").count(), 5, "{}", stdout(&output));
+    assert!(stdout(&output).contains("Every letter is already placed, so there is nothing to hint."));
+    assert!(stdout(&output).contains("You win!"));
+}
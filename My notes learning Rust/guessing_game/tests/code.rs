@@ -0,0 +1,84 @@
+use guessing_game::code::{Code, Config, MINIMAX_LIMIT, Solver, score};
+
+fn classic() -> Config {
+    Config { length: 4, symbols: 6, repeats: true, colours: false }
+}
+
+#[test]
+fn repeated_symbols_count_once_per_match() {
+    let code = |text: &str| classic().parse(text).unwrap();
+
+    assert_eq!(score(&code("1122"), &code("1122")), (4, 0));
+    assert_eq!(score(&code("1122"), &code("2211")), (0, 4));
+    // one 1 in the secret, so the guess's three 1s earn one peg
+    assert_eq!(score(&code("1112"), &code("1345")), (1, 0));
+    assert_eq!(score(&code("1112"), &code("3451")), (0, 1));
+    // two 1s in the secret, three in the guess: two pegs, one exact
+    assert_eq!(score(&code("1113"), &code("1451")), (1, 1));
+    assert_eq!(score(&code("6666"), &code("1236")), (1, 0));
+    assert_eq!(score(&code("1234"), &code("5566")), (0, 0));
+}
+
+#[test]
+fn codes_are_read_in_either_alphabet() {
+    let colours = Config { colours: true, ..classic() };
+    assert_eq!(colours.parse("r g b y"), Some(vec![0, 1, 2, 3]));
+    assert_eq!(colours.show(&[5, 4, 3, 2]), "POYB");
+    // too short, outside the six symbols, a repeat when none are allowed
+    assert_eq!(classic().parse("123"), None);
+    assert_eq!(classic().parse("1237"), None);
+    assert_eq!(Config { repeats: false, ..classic() }.parse("1123"), None);
+    assert_eq!(classic().all_codes().len(), 1296);
+    assert_eq!(Config { repeats: false, ..classic() }.all_codes().len(), 360);
+}
+
+// The most guesses the solver needs over every secret, following each
+// answer from the same position once. Answers no candidate gives count 0.
+fn worst_case(solver: &Solver, all: &[Code]) -> u32 {
+    let guess = solver.next_guess();
+    let mut answers: Vec<(u32, u32)> = all.iter().map(|secret| score(&guess, secret)).collect();
+    answers.sort_unstable();
+    answers.dedup();
+
+    answers
+        .into_iter()
+        .map(|answer| {
+            if answer.0 as usize == guess.len() {
+                return 1;
+            }
+            let mut next = solver.clone();
+            next.record(&guess, answer);
+            if next.candidates() == 0 { 0 } else { 1 + worst_case(&next, all) }
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn knuth_breaks_every_classic_code_in_five() {
+    let config = classic();
+    let solver = Solver::new(&config);
+    assert_eq!(worst_case(&solver, &config.all_codes()), 5);
+}
+
+#[test]
+fn big_games_fall_back_to_the_first_candidate() {
+    let config = Config { length: 6, symbols: 9, repeats: true, colours: false };
+    assert!(config.all_codes().len() > MINIMAX_LIMIT);
+
+    let secret = config.parse("987654").unwrap();
+    let mut solver = Solver::new(&config);
+    assert_eq!(solver.next_guess(), config.parse("111111").unwrap());
+
+    for guesses in 1.. {
+        let guess = solver.next_guess();
+        let answer = score(&guess, &secret);
+        if answer.0 == 6 {
+            assert!(guesses <= 60, "{guesses} guesses");
+            break;
+        }
+        solver.record(&guess, answer);
+        // every guess after the first could still be the secret
+        assert_eq!(score(&solver.next_guess(), &guess), answer);
+    }
+}
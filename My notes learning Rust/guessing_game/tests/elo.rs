@@ -0,0 +1,66 @@
+use guessing_game::bits;
+use guessing_game::elo::{self, START_RATING, TARGET_WIN_RATE};
+use proptest::prelude::*;
+
+#[test]
+fn four_hundred_points_are_ten_to_one() {
+    assert!((elo::expected_win(1400.0, 1000.0) - 10.0 / 11.0).abs() < 1e-12);
+    assert!((elo::expected_win(1000.0, 1400.0) - 1.0 / 11.0).abs() < 1e-12);
+    assert_eq!(elo::expected_win(1234.0, 1234.0), 0.5);
+}
+
+#[test]
+fn games_get_harder_with_the_range_and_easier_with_attempts() {
+    // 1 to 100 with exactly the 7 guesses binary search needs
+    assert!((elo::game_rating(100, 7) - (800.0 + 100.0 * 100f64.log2())).abs() < 1e-9);
+    assert!(elo::game_rating(1000, 10) > elo::game_rating(100, 7));
+    assert_eq!(elo::game_rating(100, 8), elo::game_rating(100, 7) - 150.0);
+}
+
+#[test]
+fn new_players_move_fast_and_settle_down() {
+    let game = elo::game_rating(100, 7);
+    let expected = elo::expected_win(START_RATING, game);
+
+    // k is 40 for a first game and never drops below 16
+    assert!((elo::change(START_RATING, 0, game, true) - 40.0 * (1.0 - expected)).abs() < 1e-9);
+    assert!((elo::change(START_RATING, 0, game, false) + 40.0 * expected).abs() < 1e-9);
+    assert!((elo::change(START_RATING, 24, game, true) - 16.0 * (1.0 - expected)).abs() < 1e-9);
+    assert_eq!(elo::change(START_RATING, 24, game, true), elo::change(START_RATING, 500, game, true));
+}
+
+#[test]
+fn a_new_player_gets_the_classic_range() {
+    assert_eq!(elo::pick(START_RATING).0, 100);
+}
+
+#[test]
+fn players_beyond_every_game_get_the_hardest_or_easiest() {
+    assert_eq!(elo::pick(5000.0), (10000, 14));
+    assert_eq!(elo::pick(-2000.0), (10, 14));
+}
+
+proptest! {
+    #[test]
+    fn winning_never_costs_and_losing_never_pays(rating in 0.0..3000.0f64, games in 0u32..100, range in 2u32..10_000, slack in 0u32..10) {
+        let game = elo::game_rating(range, bits(u64::from(range) + 1) + slack);
+        prop_assert!(elo::change(rating, games, game, true) > 0.0);
+        prop_assert!(elo::change(rating, games, game, false) < 0.0);
+    }
+
+    // where some game can be won at the target rate, the pick lands near it
+    #[test]
+    fn the_pick_aims_at_the_target(rating in 0.0..2200.0f64) {
+        let (range, max_attempts) = elo::pick(rating);
+        let chance = elo::expected_win(rating, elo::game_rating(range, max_attempts));
+        prop_assert!((chance - TARGET_WIN_RATE).abs() < 0.1, "{chance} for {range} in {max_attempts}");
+    }
+
+    // a better player never gets an easier game
+    #[test]
+    fn the_pick_follows_the_rating(rating in -500.0..4000.0f64, more in 0.0..500.0f64) {
+        let (range, max_attempts) = elo::pick(rating);
+        let (harder_range, harder_attempts) = elo::pick(rating + more);
+        prop_assert!(elo::game_rating(harder_range, harder_attempts) >= elo::game_rating(range, max_attempts));
+    }
+}
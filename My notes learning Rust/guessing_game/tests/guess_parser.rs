@@ -0,0 +1,279 @@
+use guessing_game::parse::{ParseError, guess};
+use proptest::prelude::*;
+
+#[test]
+fn plain_numbers() {
+    assert_eq!(guess("42"), Ok(42));
+    assert_eq!(guess("  7 \n"), Ok(7));
+    assert_eq!(guess("007"), Ok(7));
+    assert_eq!(guess("4294967295"), Ok(u32::MAX));
+}
+
+#[test]
+fn prefixed_literals() {
+    assert_eq!(guess("0x3F"), Ok(63));
+    assert_eq!(guess("0X3f"), Ok(63));
+    assert_eq!(guess("0o17"), Ok(15));
+    assert_eq!(guess("0b1010_1010"), Ok(170));
+    assert_eq!(guess("1_000"), Ok(1000));
+    assert_eq!(guess("0xFF_FF"), Ok(65535));
+}
+
+#[test]
+fn bad_literals() {
+    for input in ["0x", "0b", "0b102", "0o8", "12ab", "1__000", "1000_", "0x_1", "3F"] {
+        assert_eq!(guess(input), Err(ParseError::BadNumber(input.to_string())), "{input}");
+    }
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(guess("50+12"), Ok(62));
+    assert_eq!(guess("(100-1)/2"), Ok(49));
+    assert_eq!(guess("2 + 3 * 4"), Ok(14));
+    assert_eq!(guess("(2 + 3) * 4"), Ok(20));
+    assert_eq!(guess("100 - 10 - 1"), Ok(89));
+    assert_eq!(guess("64 / 4 / 2"), Ok(8));
+    assert_eq!(guess("-5 + 10"), Ok(5));
+    assert_eq!(guess("--3"), Ok(3));
+    assert_eq!(guess("+3"), Ok(3));
+    assert_eq!(guess("0x10 * 0b11"), Ok(48));
+    assert_eq!(guess("((((1))))"), Ok(1));
+}
+
+#[test]
+fn arithmetic_errors() {
+    assert_eq!(guess(""), Err(ParseError::Empty));
+    assert_eq!(guess("   "), Err(ParseError::Empty));
+    assert_eq!(guess("5 / 0"), Err(ParseError::DivisionByZero));
+    assert_eq!(guess("5 / (3 - 3)"), Err(ParseError::DivisionByZero));
+    assert_eq!(guess("50+"), Err(ParseError::MissingOperand));
+    assert_eq!(guess("(1 + 2"), Err(ParseError::UnclosedParen));
+    assert_eq!(guess("1 + 2)"), Err(ParseError::Unexpected(")".to_string())));
+    assert_eq!(guess("5 5"), Err(ParseError::Unexpected("5".to_string())));
+    assert_eq!(guess("*5"), Err(ParseError::Unexpected("*".to_string())));
+    assert_eq!(guess("()"), Err(ParseError::Unexpected(")".to_string())));
+    assert_eq!(guess("5 % 2"), Err(ParseError::Unexpected("%".to_string())));
+    assert_eq!(guess("1 - 2"), Err(ParseError::OutOfRange));
+    assert_eq!(guess("4294967296"), Err(ParseError::OutOfRange));
+    assert_eq!(guess("99999999999999999999"), Err(ParseError::OutOfRange));
+    assert_eq!(guess("4294967295 * 4294967295 * 4294967295"), Err(ParseError::OutOfRange));
+}
+
+#[test]
+fn deep_nesting_is_an_error_not_a_crash() {
+    let deep = 100_000;
+    assert_eq!(guess(&format!("{}1", "-".repeat(deep))), Err(ParseError::TooDeep));
+    assert_eq!(guess(&format!("{}1", "(".repeat(deep))), Err(ParseError::TooDeep));
+    assert_eq!(guess(&format!("{}1{}", "(".repeat(deep), ")".repeat(deep))), Err(ParseError::TooDeep));
+    assert_eq!(guess(&format!("{}1", "-(".repeat(deep))), Err(ParseError::TooDeep));
+
+    // well short of the limit still reads
+    assert_eq!(guess(&format!("{}7{}", "(".repeat(50), ")".repeat(50))), Ok(7));
+    assert_eq!(guess(&format!("{}7", "--".repeat(25))), Ok(7));
+    // and a long flat expression is not deep at all
+    assert_eq!(guess(&vec!["1"; deep].join("+")), Ok(deep as u32));
+}
+
+#[test]
+fn english_words() {
+    assert_eq!(guess("zero"), Ok(0));
+    assert_eq!(guess("seven"), Ok(7));
+    assert_eq!(guess("forty two"), Ok(42));
+    assert_eq!(guess("Forty-Two"), Ok(42));
+    assert_eq!(guess("nineteen"), Ok(19));
+    assert_eq!(guess("one hundred"), Ok(100));
+    assert_eq!(guess("hundred"), Ok(100));
+    assert_eq!(guess("one hundred and one"), Ok(101));
+    assert_eq!(guess("twelve hundred"), Ok(1200));
+    assert_eq!(guess("two thousand and six"), Ok(2006));
+    assert_eq!(guess("three million four hundred thousand"), Ok(3_400_000));
+    assert_eq!(guess("four billion"), Ok(4_000_000_000));
+}
+
+#[test]
+fn french_words() {
+    assert_eq!(guess("zéro"), Ok(0));
+    assert_eq!(guess("quarante-deux"), Ok(42));
+    assert_eq!(guess("vingt et un"), Ok(21));
+    assert_eq!(guess("soixante et onze"), Ok(71));
+    assert_eq!(guess("soixante-dix-sept"), Ok(77));
+    assert_eq!(guess("quatre-vingts"), Ok(80));
+    assert_eq!(guess("quatre-vingt-dix-neuf"), Ok(99));
+    assert_eq!(guess("dix-huit"), Ok(18));
+    assert_eq!(guess("cent"), Ok(100));
+    assert_eq!(guess("deux cents"), Ok(200));
+    assert_eq!(guess("mille"), Ok(1000));
+    assert_eq!(guess("deux mille trois cent quarante-deux"), Ok(2342));
+    assert_eq!(guess("septante-cinq"), Ok(75));
+}
+
+#[test]
+fn words_in_expressions() {
+    assert_eq!(guess("forty two + 1"), Ok(43));
+    assert_eq!(guess("ten - two"), Ok(8));
+    assert_eq!(guess("(cent - 1) / deux"), Ok(49));
+    assert_eq!(guess("six*seven"), Ok(42));
+}
+
+#[test]
+fn bad_words() {
+    assert_eq!(guess("forty tw"), Err(ParseError::UnknownWord("tw".to_string())));
+    assert_eq!(guess("lots"), Err(ParseError::UnknownWord("lots".to_string())));
+    for input in [
+        "two five",
+        "twenty thirty",
+        "twelve three",
+        "ten nine",
+        "zero one",
+        "one hundred two hundred",
+        "thousand thousand",
+        "thousand million",
+        "and one",
+        "one and",
+        "one and and two",
+    ] {
+        assert_eq!(guess(input), Err(ParseError::BadWords(input.to_string())), "{input}");
+    }
+}
+
+// Spells n the way the parser expects English numbers to look.
+fn english(n: u32) -> String {
+    const SMALL: [&str; 20] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+    fn below_thousand(n: u32) -> Vec<String> {
+        let mut words = Vec::new();
+        if n >= 100 {
+            words.push(SMALL[(n / 100) as usize].to_string());
+            words.push("hundred".to_string());
+            if !n.is_multiple_of(100) {
+                words.push("and".to_string());
+            }
+        }
+        let rest = n % 100;
+        if rest >= 20 {
+            let tens = TENS[(rest / 10) as usize];
+            if rest.is_multiple_of(10) {
+                words.push(tens.to_string());
+            } else {
+                words.push(format!("{tens}-{}", SMALL[(rest % 10) as usize]));
+            }
+        } else if rest > 0 {
+            words.push(SMALL[rest as usize].to_string());
+        }
+        words
+    }
+
+    if n == 0 {
+        return "zero".to_string();
+    }
+    let mut words = Vec::new();
+    for (scale, name) in [(1_000_000_000, "billion"), (1_000_000, "million"), (1_000, "thousand")] {
+        if !(n / scale).is_multiple_of(1000) {
+            words.extend(below_thousand(n / scale % 1000));
+            words.push(name.to_string());
+        }
+    }
+    words.extend(below_thousand(n % 1000));
+    words.join(" ")
+}
+
+// Spells 0..=99 in French, with the usual "et" and quatre-vingt forms.
+fn french(n: u32) -> String {
+    const SMALL: [&str; 17] = [
+        "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix",
+        "onze", "douze", "treize", "quatorze", "quinze", "seize",
+    ];
+    const TENS: [&str; 7] = ["", "", "vingt", "trente", "quarante", "cinquante", "soixante"];
+
+    match n {
+        0..=16 => SMALL[n as usize].to_string(),
+        17..=19 => format!("dix-{}", SMALL[(n - 10) as usize]),
+        80 => "quatre-vingts".to_string(),
+        61..=79 | 81..=99 => {
+            let (base, rest) = if n < 80 { ("soixante", n - 60) } else { ("quatre-vingt", n - 80) };
+            match rest {
+                1 if n < 80 => "soixante et un".to_string(),
+                11 if n < 80 => "soixante et onze".to_string(),
+                _ => format!("{base}-{}", french(rest)),
+            }
+        }
+        _ => {
+            let tens = TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => tens.to_string(),
+                1 => format!("{tens} et un"),
+                unit => format!("{tens}-{}", SMALL[unit as usize]),
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn decimal_round_trips(n: u32) {
+        prop_assert_eq!(guess(&n.to_string()), Ok(n));
+    }
+
+    #[test]
+    fn prefixed_round_trips(n: u32) {
+        prop_assert_eq!(guess(&format!("{n:#x}")), Ok(n));
+        prop_assert_eq!(guess(&format!("{n:#X}")), Ok(n));
+        prop_assert_eq!(guess(&format!("{n:#o}")), Ok(n));
+        prop_assert_eq!(guess(&format!("{n:#b}")), Ok(n));
+    }
+
+    #[test]
+    fn underscores_between_digits_are_ignored(n: u32, every in 1usize..4) {
+        let digits = n.to_string();
+        let grouped: String = digits
+            .chars()
+            .enumerate()
+            .flat_map(|(i, c)| {
+                let separator = (i > 0 && i % every == 0).then_some('_');
+                separator.into_iter().chain(Some(c))
+            })
+            .collect();
+        prop_assert_eq!(guess(&grouped), Ok(n));
+    }
+
+    #[test]
+    fn sums_and_products_agree_with_rust(a in 0u32..65536, b in 0u32..65536) {
+        prop_assert_eq!(guess(&format!("{a} + {b}")), Ok(a + b));
+        prop_assert_eq!(guess(&format!("{a}*{b}")), u32::try_from(a as u64 * b as u64).map_err(|_| ParseError::OutOfRange));
+        prop_assert_eq!(guess(&format!("({a} + {b}) - {b}")), Ok(a));
+        if b > 0 {
+            prop_assert_eq!(guess(&format!("{a} / {b}")), Ok(a / b));
+        }
+        if a >= b {
+            prop_assert_eq!(guess(&format!("{a} - {b}")), Ok(a - b));
+        } else {
+            prop_assert_eq!(guess(&format!("{a} - {b}")), Err(ParseError::OutOfRange));
+        }
+    }
+
+    #[test]
+    fn english_words_round_trip(n: u32) {
+        prop_assert_eq!(guess(&english(n)), Ok(n), "{}", english(n));
+    }
+
+    #[test]
+    fn french_words_round_trip(n in 0u32..100) {
+        prop_assert_eq!(guess(&french(n)), Ok(n), "{}", french(n));
+    }
+
+    #[test]
+    fn never_panics(input in "\\PC{0,40}") {
+        let _ = guess(&input);
+    }
+
+    #[test]
+    fn never_panics_on_expression_characters(input in "[0-9a-fxob_+*/() -]{0,40}") {
+        let _ = guess(&input);
+    }
+}
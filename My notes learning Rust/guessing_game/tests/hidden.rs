@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use guessing_game::bits;
+use guessing_game::hidden::{Answer, EXACT_LIMIT, Knowledge, Solver, answer, solve};
+use proptest::prelude::*;
+use proptest::sample::subsequence;
+
+#[test]
+fn answers_count_both_sides() {
+    let secrets = [10, 20, 30];
+    assert_eq!(answer(&secrets, 5), Answer { below: 0, equal: false, above: 3 });
+    assert_eq!(answer(&secrets, 20), Answer { below: 1, equal: true, above: 1 });
+    assert_eq!(answer(&secrets, 25), Answer { below: 2, equal: false, above: 1 });
+    assert_eq!(answer(&secrets, 99), Answer { below: 3, equal: false, above: 0 });
+}
+
+#[test]
+fn intervals_start_apart() {
+    let knowledge = Knowledge::new(3, 1, 10);
+    assert_eq!(knowledge.bounds, [(1, 8), (2, 9), (3, 10)]);
+}
+
+#[test]
+fn answers_narrow_the_intervals() {
+    let mut knowledge = Knowledge::new(3, 1, 100);
+
+    knowledge.record(50, &Answer { below: 1, equal: true, above: 1 });
+    assert_eq!(knowledge.bounds, [(1, 49), (50, 50), (51, 100)]);
+    assert_eq!(knowledge.found, [false, true, false]);
+
+    knowledge.record(20, &Answer { below: 0, equal: false, above: 3 });
+    assert_eq!(knowledge.bounds, [(21, 49), (50, 50), (51, 100)]);
+}
+
+#[test]
+fn order_pushes_neighbours_apart() {
+    let mut knowledge = Knowledge::new(3, 1, 100);
+
+    // two secrets below 3 can only be 1 and 2
+    knowledge.record(3, &Answer { below: 2, equal: false, above: 1 });
+    assert_eq!(knowledge.bounds, [(1, 1), (2, 2), (4, 100)]);
+    assert!(!knowledge.is_solved());
+}
+
+#[test]
+fn every_number_a_secret() {
+    let secrets: Vec<u32> = (1..=10).collect();
+    assert_eq!(solve(&secrets, 1, 10), 10);
+}
+
+#[test]
+fn one_secret_is_bisection() {
+    for secret in 1..=100 {
+        assert!(solve(&[secret], 1, 100) <= 7, "{secret}");
+    }
+}
+
+// Every way to place count distinct secrets in 1..=size, sorted.
+fn placements(count: usize, size: u32) -> Vec<Vec<u32>> {
+    if count == 0 {
+        return vec![Vec::new()];
+    }
+    (count as u32..=size)
+        .flat_map(|last| {
+            placements(count - 1, last - 1).into_iter().map(move |mut secrets| {
+                secrets.push(last);
+                secrets
+            })
+        })
+        .collect()
+}
+
+// The optimum worked out without Knowledge: the placements still possible
+// and which secrets were hit, split by the answer of every guess.
+fn brute_force(possible: Vec<&Vec<u32>>, found: u32, done: &mut HashMap<(Vec<Vec<u32>>, u32), u32>, size: u32) -> u32 {
+    let count = possible[0].len();
+    if found == (1 << count) - 1 {
+        return 0;
+    }
+    let key = (possible.iter().map(|&secrets| secrets.clone()).collect(), found);
+    if let Some(&worst) = done.get(&key) {
+        return worst;
+    }
+
+    let mut best = u32::MAX;
+    for guess in 1..=size {
+        let mut split: HashMap<(usize, bool), Vec<&Vec<u32>>> = HashMap::new();
+        for &secrets in &possible {
+            let answer = answer(secrets, guess);
+            split.entry((answer.below, answer.equal)).or_default().push(secrets);
+        }
+        let learns = |&(below, equal): &(usize, bool)| equal && found & (1 << below) == 0;
+        if split.len() == 1 && !split.keys().any(learns) {
+            continue;
+        }
+
+        let worst = split
+            .into_iter()
+            .map(|((below, equal), rest)| {
+                let found = if equal { found | (1 << below) } else { found };
+                brute_force(rest, found, done, size)
+            })
+            .max()
+            .unwrap();
+        best = best.min(worst + 1);
+    }
+
+    done.insert(key, best);
+    best
+}
+
+#[test]
+fn the_exact_solver_matches_brute_force() {
+    for size in 1..=7 {
+        for count in 1..=size as usize {
+            let all = placements(count, size);
+            let optimum = brute_force(all.iter().collect(), 0, &mut HashMap::new(), size);
+
+            let mut solver = Solver::new(1, size);
+            assert_eq!(solver.worst(&Knowledge::new(count, 1, size)), Some(optimum), "{count} in 1..={size}");
+            let played = all.iter().map(|secrets| solve(secrets, 1, size)).max().unwrap();
+            assert_eq!(played, optimum, "{count} in 1..={size}");
+        }
+    }
+}
+
+// Guesses solver needs to find secrets, one solver for many games so it
+// keeps what it has worked out.
+fn play(solver: &mut Solver, secrets: &[u32], size: u32) -> u32 {
+    let mut knowledge = Knowledge::new(secrets.len(), 1, size);
+    let mut guesses = 0;
+    while !knowledge.is_solved() {
+        let guess = solver.next_guess(&knowledge);
+        knowledge.record(guess, &answer(secrets, guess));
+        guesses += 1;
+    }
+    guesses
+}
+
+#[test]
+fn the_exact_solver_keeps_its_worst_case_up_to_ten_secrets() {
+    let size = EXACT_LIMIT;
+    for count in 1..=10 {
+        let mut solver = Solver::new(1, size);
+        let worst = solver.worst(&Knowledge::new(count, 1, size)).unwrap();
+        let played = placements(count, size).iter().map(|secrets| play(&mut solver, secrets, size)).max().unwrap();
+        assert_eq!(played, worst, "{count} in 1..={size}");
+        assert!(worst >= count as u32);
+    }
+    // one secret is bisection, ten numbers take four guesses
+    assert_eq!(Solver::new(1, size).worst(&Knowledge::new(1, 1, size)), Some(4));
+    assert_eq!(Solver::new(1, 100).worst(&Knowledge::new(1, 1, 100)), None);
+}
+
+// Guesses it takes to find each secret on its own by bisection, an upper
+// bound the solver should stay under for every K.
+fn separate_bisections(count: usize, size: u32) -> u32 {
+    count as u32 * bits(size + 1)
+}
+
+proptest! {
+    #[test]
+    fn the_solver_finds_up_to_ten_secrets(secrets in (1usize..=10).prop_flat_map(|k| subsequence((1..=100u32).collect::<Vec<_>>(), k))) {
+        let guesses = solve(&secrets, 1, 100);
+        prop_assert!(guesses >= secrets.len() as u32);
+        prop_assert!(guesses <= separate_bisections(secrets.len(), 100), "{} guesses for {:?}", guesses, secrets);
+    }
+
+    #[test]
+    fn intervals_always_hold_the_secrets(
+        secrets in (1usize..=10).prop_flat_map(|k| subsequence((1..=100u32).collect::<Vec<_>>(), k)),
+        guesses in proptest::collection::vec(0u32..=110, 0..30),
+    ) {
+        let mut knowledge = Knowledge::new(secrets.len(), 1, 100);
+        for guess in guesses {
+            knowledge.record(guess, &answer(&secrets, guess));
+            for (secret, &(low, high)) in secrets.iter().zip(&knowledge.bounds) {
+                prop_assert!((low..=high).contains(secret), "{} outside {}..={}", secret, low, high);
+            }
+            for (i, &found) in knowledge.found.iter().enumerate() {
+                if found {
+                    prop_assert_eq!(knowledge.bounds[i], (secrets[i], secrets[i]));
+                }
+            }
+        }
+    }
+}
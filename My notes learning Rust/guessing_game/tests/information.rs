@@ -0,0 +1,63 @@
+use std::cmp::Ordering;
+
+use guessing_game::information::{analyse, summarise};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn every_guess_narrows_the_interval_it_was_made_in() {
+    // 30 hidden in 1..=100; 60 and 10 fall outside what is left
+    let reports = analyse(1, 100, 30, &[50, 25, 60, 10, 28, 30]);
+
+    let intervals: Vec<(u32, u32)> = reports.iter().map(|report| (report.low, report.high)).collect();
+    assert_eq!(intervals, [(1, 100), (1, 49), (26, 49), (26, 49), (26, 49), (29, 49)]);
+
+    let outcomes: Vec<Ordering> = reports.iter().map(|report| report.outcome).collect();
+    use Ordering::{Equal, Greater, Less};
+    assert_eq!(outcomes, [Greater, Less, Greater, Less, Less, Equal]);
+
+    let wasted: Vec<bool> = reports.iter().map(|report| report.wasted).collect();
+    assert_eq!(wasted, [false, false, true, true, false, false]);
+
+    let bits: Vec<f64> = reports.iter().map(|report| report.bits).collect();
+    let expected = [(100.0f64 / 49.0).log2(), (49.0f64 / 24.0).log2(), 0.0, 0.0, (24.0f64 / 21.0).log2(), 21f64.log2()];
+    for (i, (&bits, &expected)) in bits.iter().zip(&expected).enumerate() {
+        assert!(close(bits, expected), "guess {}: {bits} bits, not {expected}", i + 1);
+    }
+}
+
+#[test]
+fn a_win_is_graded_against_binary_search() {
+    let summary = summarise(1, 100, 30, &[50, 25, 60, 10, 28, 30]);
+
+    // a win learns everything there was to know
+    assert!(close(summary.total_bits, 100f64.log2()));
+    assert!(close(summary.needed_bits, 100f64.log2()));
+    assert_eq!(summary.binary_search, 7);
+    assert_eq!(summary.wasted, 2);
+    // six guesses where binary search may need seven, as good as it gets
+    assert!(close(summary.efficiency, 1.0));
+    assert!(close(summarise(1, 100, 50, &[50]).efficiency, 1.0));
+    // twice as many guesses as binary search is half as good
+    let slow: Vec<u32> = (1..=14).collect();
+    assert!(close(summarise(1, 100, 14, &slow).efficiency, 0.5));
+}
+
+#[test]
+fn a_loss_is_graded_by_the_bits_it_found() {
+    let summary = summarise(1, 100, 30, &[50, 75]);
+
+    assert!(close(summary.total_bits, (100.0f64 / 49.0).log2()));
+    assert_eq!(summary.wasted, 1);
+    let expected = (100.0f64 / 49.0).log2() / 100f64.log2() * 7.0 / 2.0;
+    assert!(close(summary.efficiency, expected), "{}", summary.efficiency);
+}
+
+#[test]
+fn binary_search_counts_the_right_guess() {
+    assert_eq!(summarise(1, 64, 1, &[1]).binary_search, 7);
+    assert_eq!(summarise(1, 63, 1, &[1]).binary_search, 6);
+    assert_eq!(summarise(0, u32::MAX - 1, 1, &[1]).binary_search, 32);
+}
@@ -0,0 +1,106 @@
+use guessing_game::prior::{self, Prior, Shape, Tree};
+use proptest::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+fn bisection(low: u32, high: u32) -> u32 {
+    low + (high - low) / 2
+}
+
+// Expected guesses of the best tree, trying every root of every part.
+fn brute_force(chances: &[f64], first: usize, last: usize) -> f64 {
+    if first > last || last == usize::MAX {
+        return 0.0;
+    }
+    let mass: f64 = chances[first..=last].iter().sum();
+    let best = (first..=last)
+        .map(|k| brute_force(chances, first, k.wrapping_sub(1)) + brute_force(chances, k + 1, last))
+        .fold(f64::INFINITY, f64::min);
+    mass + best
+}
+
+#[test]
+fn uniform_tree_is_bisection() {
+    let prior = Prior::new(Shape::Uniform, 1, 7);
+    let tree = Tree::new(&prior);
+    // 1 found on the first guess, 2 on the second and 4 on the third
+    assert!((tree.expected() - 17.0 / 7.0).abs() < 1e-9);
+    assert_eq!(tree.next_guess(1, 7), 4);
+    assert!((prior::expected_guesses(&prior, &bisection) - 17.0 / 7.0).abs() < 1e-9);
+}
+
+#[test]
+fn a_skewed_prior_is_cheaper_with_the_tree() {
+    for shape in [Shape::Normal, Shape::Geometric, Shape::Zipf] {
+        let prior = Prior::new(shape, 1, 1000);
+        let tree = Tree::new(&prior);
+        let plain = prior::expected_guesses(&prior, &bisection);
+        let median = prior::expected_guesses(&prior, &|low, high| prior.median(low, high));
+        let best = prior::expected_guesses(&prior, &|low, high| tree.next_guess(low, high));
+
+        assert!((best - tree.expected()).abs() < 1e-6, "{shape:?}");
+        assert!(best <= median + 1e-9 && best < plain, "{shape:?}: {best} {median} {plain}");
+    }
+}
+
+#[test]
+fn names_and_entropy() {
+    for shape in Shape::ALL {
+        assert_eq!(Shape::from_name(shape.name()), Some(shape));
+    }
+    assert_eq!(Shape::from_name("poisson"), None);
+
+    assert!((Prior::new(Shape::Uniform, 1, 8).entropy() - 3.0).abs() < 1e-9);
+    assert!(Prior::new(Shape::Zipf, 1, 8).entropy() < 3.0);
+}
+
+#[test]
+fn weights_file() {
+    let prior = Prior::parse("# loaded die\n1 1\n6 3\n\n2 0 # never\n", 1, 6).unwrap();
+    assert_eq!(prior.chance(6), 0.75);
+    assert_eq!(prior.chance(2), 0.0);
+    assert_eq!(prior.median(1, 6), 6);
+
+    assert!(matches!(Prior::parse("7 1\n", 1, 6), Err(error) if error.contains("outside")));
+    assert!(matches!(Prior::parse("1\n", 1, 6), Err(error) if error.contains("line 1")));
+    assert!(Prior::parse("1 -2\n", 1, 6).is_err());
+    assert!(Prior::parse("1 0\n", 1, 6).is_err());
+}
+
+#[test]
+fn draws_follow_the_prior() {
+    let prior = Prior::parse("1 1\n3 2\n5 1\n", 1, 5).unwrap();
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let mut counts = [0u32; 6];
+    for _ in 0..4000 {
+        counts[prior.sample(&mut rng) as usize] += 1;
+    }
+    assert_eq!((counts[2], counts[4]), (0, 0));
+    assert!((1800..2200).contains(&counts[3]), "{counts:?}");
+}
+
+#[test]
+fn enough_draws_give_the_shape_away() {
+    let mut rng = ChaCha8Rng::seed_from_u64(2);
+    for shape in Shape::ALL {
+        let prior = Prior::new(shape, 1, 100);
+        let draws: Vec<u32> = (0..200).map(|_| prior.sample(&mut rng)).collect();
+        let (likeliest, chance) =
+            prior::posterior(1, 100, &draws).into_iter().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        assert_eq!(likeliest, shape);
+        assert!(chance > 0.99, "{shape:?} {chance}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn tree_matches_the_brute_force(weights in prop::collection::vec(0u32..10, 1..9)) {
+        prop_assume!(weights.iter().any(|&weight| weight > 0));
+        let text: String = weights.iter().enumerate().map(|(i, weight)| format!("{} {weight}\n", i + 1)).collect();
+        let prior = Prior::parse(&text, 1, weights.len() as u32).unwrap();
+        let chances: Vec<f64> = (1..=weights.len() as u32).map(|n| prior.chance(n)).collect();
+
+        let tree = Tree::new(&prior);
+        prop_assert!((tree.expected() - brute_force(&chances, 0, chances.len() - 1)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,171 @@
+use guessing_game::query::{Comparison, Measure, Needed, Query, QueryError, best_question, question};
+use proptest::prelude::*;
+
+fn yes(input: &str) -> Vec<u32> {
+    let query = question(input).unwrap_or_else(|error| panic!("{input}: {error:?}"));
+    (0..=100).filter(|&n| query.eval(n)).collect()
+}
+
+#[test]
+fn the_examples_read() {
+    assert_eq!(question("is it in 20..40?"), Ok(Query::In(20, 40)));
+    assert_eq!(question("is it even?"), Ok(Query::Even));
+    assert_eq!(question("is it prime?"), Ok(Query::Prime));
+    assert_eq!(question("is it divisible by 7?"), Ok(Query::DivisibleBy(7)));
+    assert_eq!(question("is its digit sum > 10?"), Ok(Query::Compare(Measure::DigitSum, Comparison::Greater, 10)));
+    assert_eq!(question("is it 42?"), Ok(Query::Compare(Measure::Number, Comparison::Equal, 42)));
+}
+
+#[test]
+fn other_ways_to_ask() {
+    assert_eq!(question("Is it between 20 and 40?"), Ok(Query::In(20, 40)));
+    assert_eq!(question("20-40"), Ok(Query::In(20, 40)));
+    assert_eq!(question("is it a multiple of 3"), Ok(Query::DivisibleBy(3)));
+    assert_eq!(question("is it less than 50?"), Ok(Query::Compare(Measure::Number, Comparison::Less, 50)));
+    assert_eq!(question("at least 50"), Ok(Query::Compare(Measure::Number, Comparison::GreaterOrEqual, 50)));
+    assert_eq!(question("is the last digit = 7"), Ok(Query::Compare(Measure::LastDigit, Comparison::Equal, 7)));
+    assert_eq!(question("digits >= 2"), Ok(Query::Compare(Measure::Digits, Comparison::GreaterOrEqual, 2)));
+}
+
+#[test]
+fn answers() {
+    assert_eq!(yes("is it in 20..25"), [20, 21, 22, 23, 24, 25]);
+    assert_eq!(yes("is it prime and < 20"), [2, 3, 5, 7, 11, 13, 17, 19]);
+    assert_eq!(yes("square"), [0, 1, 4, 9, 16, 25, 36, 49, 64, 81, 100]);
+    assert_eq!(yes("is its digit sum > 17"), [99]);
+    assert_eq!(yes("digits = 3"), [100]);
+    assert_eq!(yes("divisible by 30 or = 7"), [0, 7, 30, 60, 90]);
+    assert_eq!(yes("not (> 2 and odd) and < 5"), [0, 1, 2, 4]);
+    assert_eq!(yes("even and not divisible by 4 and < 15"), [2, 6, 10, 14]);
+}
+
+#[test]
+fn and_binds_tighter_than_or() {
+    assert_eq!(yes("= 1 or = 2 and = 3"), [1]);
+    assert_eq!(yes("(= 1 or = 2) and = 3"), Vec::<u32>::new());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(question(" ? "), Err(QueryError::Empty));
+    assert_eq!(question("is it blue?"), Err(QueryError::UnknownWord("blue".to_string())));
+    assert_eq!(question("is it in 40..20"), Err(QueryError::EmptyRange(40, 20)));
+    assert_eq!(question("divisible by 0"), Err(QueryError::DivisionByZero));
+    assert_eq!(question("is it in 20"), Err(QueryError::Missing(Needed::RangeEnd)));
+    assert_eq!(question("(even"), Err(QueryError::Missing(Needed::Word(")"))));
+    assert_eq!(question("even odd"), Err(QueryError::Unexpected("odd".to_string())));
+    assert_eq!(question("digit sum"), Err(QueryError::Missing(Needed::Comparison)));
+    assert_eq!(question("> 99999999999"), Err(QueryError::BadNumber("99999999999".to_string())));
+    assert_eq!(question("is it 5 % 2"), Err(QueryError::Unexpected("%".to_string())));
+}
+
+#[test]
+fn deep_questions_are_an_error_not_a_crash() {
+    let deep = 100_000;
+    assert_eq!(question(&format!("{}even", "not ".repeat(deep))), Err(QueryError::TooDeep));
+    assert_eq!(question(&format!("{}even", "(".repeat(deep))), Err(QueryError::TooDeep));
+    assert_eq!(question(&format!("{}even", "not (".repeat(deep))), Err(QueryError::TooDeep));
+    assert_eq!(question(&vec!["even"; deep].join(" and ")), Err(QueryError::TooDeep));
+    assert_eq!(question(&vec!["even"; deep].join(" or ")), Err(QueryError::TooDeep));
+
+    // a few levels are fine
+    let nested = format!("{}even{}", "not (".repeat(20), ")".repeat(20));
+    assert_eq!(question(&nested).map(|query| query.eval(4)), Ok(true));
+    assert_eq!(question(&vec!["> 3"; 50].join(" and ")).map(|query| query.eval(4)), Ok(true));
+}
+
+#[test]
+fn guesses() {
+    assert_eq!(question("is it 42").unwrap().guessed(), Some(42));
+    assert_eq!(question("in 42..42").unwrap().guessed(), Some(42));
+    assert_eq!(question("< 42").unwrap().guessed(), None);
+}
+
+// Plays the questioner against secret and counts its questions.
+fn questioner(secret: u32, low: u32, high: u32) -> u32 {
+    let mut candidates: Vec<u32> = (low..=high).collect();
+    for asked in 1.. {
+        let query = best_question(&candidates);
+        if query.guessed() == Some(secret) {
+            return asked;
+        }
+        let answer = query.eval(secret);
+        candidates.retain(|&n| query.eval(n) == answer);
+        assert!(candidates.contains(&secret));
+    }
+    unreachable!()
+}
+
+#[test]
+fn the_questioner_needs_no_more_than_bisection() {
+    // seven questions split 100 numbers down to one, the eighth names it
+    for secret in 1..=100 {
+        assert!(questioner(secret, 1, 100) <= 8, "{secret}");
+    }
+    for secret in [1, 500, 777, 1000] {
+        assert!(questioner(secret, 1, 1000) <= 11, "{secret}");
+    }
+}
+
+#[test]
+fn the_first_question_halves_the_range() {
+    let candidates: Vec<u32> = (1..=100).collect();
+    let query = best_question(&candidates);
+    assert_eq!(candidates.iter().filter(|&&n| query.eval(n)).count(), 50);
+}
+
+fn queries() -> impl Strategy<Value = Query> {
+    let measure = prop_oneof![
+        Just(Measure::Number),
+        Just(Measure::DigitSum),
+        Just(Measure::Digits),
+        Just(Measure::LastDigit)
+    ];
+    let comparison = prop_oneof![
+        Just(Comparison::Less),
+        Just(Comparison::LessOrEqual),
+        Just(Comparison::Greater),
+        Just(Comparison::GreaterOrEqual),
+        Just(Comparison::Equal),
+        Just(Comparison::NotEqual)
+    ];
+    let atom = prop_oneof![
+        (0u32..200, 0u32..200).prop_map(|(a, b)| Query::In(a.min(b), a.max(b))),
+        Just(Query::Even),
+        Just(Query::Odd),
+        Just(Query::Prime),
+        Just(Query::Square),
+        (1u32..20).prop_map(Query::DivisibleBy),
+        (measure, comparison, 0u32..200).prop_map(|(m, c, v)| Query::Compare(m, c, v)),
+    ];
+    atom.prop_recursive(4, 16, 2, |inner| {
+        prop_oneof![
+            inner.clone().prop_map(|query| Query::Not(Box::new(query))),
+            (inner.clone(), inner.clone()).prop_map(|(a, b)| Query::And(Box::new(a), Box::new(b))),
+            (inner.clone(), inner).prop_map(|(a, b)| Query::Or(Box::new(a), Box::new(b))),
+        ]
+    })
+}
+
+proptest! {
+    #[test]
+    fn written_questions_read_back_the_same(query in queries()) {
+        let text = query.to_string();
+        let read = question(&format!("is it {text}?"));
+        prop_assert!(read.is_ok(), "{}: {:?}", text, read);
+        let read = read.unwrap();
+        for n in 0..250 {
+            prop_assert_eq!(read.eval(n), query.eval(n), "{} at {}", text, n);
+        }
+    }
+
+    #[test]
+    fn never_panics(input in "\\PC{0,40}") {
+        let _ = question(&input);
+    }
+
+    #[test]
+    fn never_panics_on_question_words(input in "(is|it|in|not|and|or|even|digit|sum|by|[0-9]{1,3}|\\.\\.|<|>=|\\(|\\)| ){0,12}") {
+        let _ = question(&input);
+    }
+}
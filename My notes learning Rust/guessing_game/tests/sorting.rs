@@ -0,0 +1,85 @@
+use guessing_game::sorting::{Bot, Hidden, lower_bound};
+use proptest::prelude::*;
+
+// Every order of 0..n.
+fn orders(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![Vec::new()];
+    }
+    let mut all = Vec::new();
+    for shorter in orders(n - 1) {
+        for at in 0..n {
+            let mut order = shorter.clone();
+            order.insert(at, n - 1);
+            all.push(order);
+        }
+    }
+    all
+}
+
+// Sorts every order of n items and returns the most comparisons any took.
+fn worst(bot: Bot, n: usize) -> u32 {
+    let mut worst = 0;
+    for order in orders(n) {
+        let mut hidden = Hidden::new(&order);
+        let sorted = bot.sort(n, &mut |a, b| hidden.compare(a, b));
+        assert_eq!(sorted, order, "{} on {order:?}", bot.name());
+        worst = worst.max(hidden.comparisons);
+    }
+    worst
+}
+
+#[test]
+fn lower_bounds() {
+    let expected = [0, 0, 1, 3, 5, 7, 10, 13, 16, 19, 22, 26, 29, 33];
+    for (n, &bound) in expected.iter().enumerate() {
+        assert_eq!(lower_bound(n), bound, "{n} items");
+    }
+    // 26! has 89 bits
+    assert_eq!(lower_bound(26), 89);
+}
+
+#[test]
+fn merge_insertion_meets_its_known_worst_cases() {
+    // Ford-Johnson reaches the lower bound for every n up to 11
+    for n in 0..=8 {
+        assert_eq!(worst(Bot::MergeInsertion, n), lower_bound(n), "{n} items");
+    }
+}
+
+#[test]
+fn merge_and_insertion_worst_cases() {
+    for (n, expected) in [(1, 0), (2, 1), (3, 3), (4, 5), (5, 8), (6, 11), (7, 14), (8, 17)] {
+        assert_eq!(worst(Bot::Merge, n), expected, "{n} items");
+    }
+    for n in 1..=7 {
+        assert_eq!(worst(Bot::Insertion, n) as usize, n * (n - 1) / 2, "{n} items");
+    }
+}
+
+#[test]
+fn hidden_checks_an_answer() {
+    let hidden = Hidden::new(&[2, 0, 1]);
+    assert!(hidden.is_sorted(&[2, 0, 1]));
+    assert!(!hidden.is_sorted(&[0, 1, 2]));
+    assert!(!hidden.is_sorted(&[2, 0]));
+    assert_eq!(hidden.order(), vec![2, 0, 1]);
+}
+
+proptest! {
+    #[test]
+    fn every_bot_sorts(order in (1usize..=26).prop_flat_map(|n| Just((0..n).collect::<Vec<_>>()).prop_shuffle())) {
+        let n = order.len();
+        for bot in Bot::ALL {
+            let mut hidden = Hidden::new(&order);
+            let sorted = bot.sort(n, &mut |a, b| hidden.compare(a, b));
+            prop_assert_eq!(&sorted, &order, "{}", bot.name());
+        }
+
+        // Ford-Johnson's worst case for n items (OEIS A001768)
+        let mut hidden = Hidden::new(&order);
+        Bot::MergeInsertion.sort(n, &mut |a, b| hidden.compare(a, b));
+        let known = [0, 0, 1, 3, 5, 7, 10, 13, 16, 19, 22, 26, 30, 34, 38, 42, 46, 50, 54, 58, 62, 66, 71, 76, 81, 86, 91];
+        prop_assert!(hidden.comparisons <= known[n]);
+    }
+}
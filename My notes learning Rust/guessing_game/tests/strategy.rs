@@ -0,0 +1,87 @@
+use std::cmp::Ordering;
+
+use guessing_game::strategy::{Cost, Strategy};
+use proptest::prelude::*;
+// the solver is also called Strategy, the proptest trait only needs its methods
+use proptest::strategy::Strategy as _;
+
+// The same minimax, trying every guess of every part, to check the faster
+// search against.
+fn brute_force(low: u32, high: u32, cost: Cost) -> u64 {
+    fn worst(low: u32, high: u32, cost: Cost, memo: &mut std::collections::HashMap<(u32, u32), u64>) -> u64 {
+        if low > high {
+            return 0;
+        }
+        if let Some(&known) = memo.get(&(low, high)) {
+            return known;
+        }
+        let best = (low..=high)
+            .map(|guess| {
+                let hit = cost.of(guess, Ordering::Equal);
+                let below = if guess > low { cost.of(guess, Ordering::Greater) + worst(low, guess - 1, cost, memo) } else { 0 };
+                let above = if guess < high { cost.of(guess, Ordering::Less) + worst(guess + 1, high, cost, memo) } else { 0 };
+                hit.max(below).max(above)
+            })
+            .min()
+            .unwrap();
+        memo.insert((low, high), best);
+        best
+    }
+    worst(low, high, cost, &mut Default::default())
+}
+
+fn costs() -> impl proptest::strategy::Strategy<Value = Cost> {
+    prop_oneof![Just(Cost::Unit), Just(Cost::Value), (0u32..20).prop_map(Cost::Over)]
+}
+
+#[test]
+fn unit_cost_is_bisection() {
+    // n guesses tell apart 2^n - 1 numbers
+    for (size, guesses) in [(1, 1), (2, 2), (3, 2), (7, 3), (8, 4), (100, 7), (1000, 10)] {
+        assert_eq!(Strategy::new(1, size, Cost::Unit).worst(), guesses, "1..={size}");
+    }
+}
+
+#[test]
+fn value_cost_small_ranges() {
+    // 1..=3: guess 2, then 1 or 3
+    assert_eq!(Strategy::new(1, 3, Cost::Value).worst(), 5);
+    // 1..=4: guess 3, then 4, or 1 and 2
+    assert_eq!(Strategy::new(1, 4, Cost::Value).worst(), 7);
+    assert_eq!(Strategy::new(1, 4, Cost::Value).next_guess(1, 4), 3);
+}
+
+#[test]
+fn a_high_penalty_makes_guesses_climb() {
+    let strategy = Strategy::new(1, 10, Cost::Over(100));
+    // guessing too high once costs more than walking up one by one
+    assert_eq!(strategy.worst(), 10);
+    assert_eq!(strategy.play(7), (vec![1, 2, 3, 4, 5, 6, 7], 7));
+}
+
+#[test]
+fn a_few_thousand_numbers() {
+    for cost in [Cost::Unit, Cost::Value, Cost::Over(3)] {
+        let strategy = Strategy::new(1, 3000, cost);
+        for secret in [1, 1500, 2999, 3000] {
+            assert!(strategy.play(secret).1 <= strategy.worst(), "{cost:?} {secret}");
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn matches_the_brute_force(low in 0u32..50, size in 1u32..40, cost in costs()) {
+        let strategy = Strategy::new(low, low + size - 1, cost);
+        prop_assert_eq!(strategy.worst(), brute_force(low, low + size - 1, cost));
+    }
+
+    #[test]
+    fn no_secret_costs_more_than_the_worst(low in 0u32..50, size in 1u32..80, cost in costs()) {
+        let high = low + size - 1;
+        let strategy = Strategy::new(low, high, cost);
+        let totals: Vec<u64> = (low..=high).map(|secret| strategy.play(secret).1).collect();
+        prop_assert!(totals.iter().all(|&total| total <= strategy.worst()));
+        prop_assert_eq!(totals.iter().max(), Some(&strategy.worst()));
+    }
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use guessing_game::timing::{Clock, Lap, Limits, SystemClock, TickClock};
+
+// Reads the clock once for the start of the round, then once per guess,
+// the way a timed round does.
+fn laps(clock: &dyn Clock, limits: &Limits, guesses: usize) -> Vec<Lap> {
+    let mut last = clock.now();
+    (0..guesses)
+        .map(|_| {
+            let now = clock.now();
+            let lap = limits.judge(last, now);
+            last = now;
+            lap
+        })
+        .collect()
+}
+
+fn secs(secs: f64) -> Duration {
+    Duration::from_secs_f64(secs)
+}
+
+#[test]
+fn a_tick_clock_moves_the_same_step_every_read() {
+    let clock = TickClock::new(secs(1.5));
+
+    assert_eq!(clock.now(), Duration::ZERO);
+    assert_eq!(clock.now(), secs(1.5));
+    assert_eq!(clock.now(), secs(3.0));
+}
+
+#[test]
+fn guesses_within_the_limits_count() {
+    // every guess takes 1.5s, well within the minute
+    let limits = Limits { per_guess: None, deadline: Some(secs(60.0)) };
+    let laps = laps(&TickClock::new(secs(1.5)), &limits, 2);
+
+    assert_eq!(laps, [Lap::InTime(secs(1.5)), Lap::InTime(secs(1.5))]);
+}
+
+#[test]
+fn the_deadline_ends_the_round() {
+    // the third guess comes 75s in, after the minute is up
+    let limits = Limits { per_guess: None, deadline: Some(secs(60.0)) };
+    let laps = laps(&TickClock::new(secs(25.0)), &limits, 3);
+
+    assert_eq!(laps, [Lap::InTime(secs(25.0)), Lap::InTime(secs(25.0)), Lap::TimeUp]);
+}
+
+#[test]
+fn a_slow_guess_loses_its_turn() {
+    // 20s a guess is too slow for a 10s countdown, even for the right one
+    let limits = Limits { per_guess: Some(secs(10.0)), deadline: None };
+    let laps = laps(&TickClock::new(secs(20.0)), &limits, 2);
+
+    assert_eq!(laps, [Lap::TooSlow(secs(20.0)), Lap::TooSlow(secs(20.0))]);
+
+    // right on the limit is still in time
+    let laps = self::laps(&TickClock::new(secs(10.0)), &limits, 1);
+    assert_eq!(laps, [Lap::InTime(secs(10.0))]);
+}
+
+#[test]
+fn the_system_clock_starts_at_zero_and_moves_forward() {
+    let clock = SystemClock::new();
+    let first = clock.now();
+
+    assert!(first < secs(1.0));
+    assert!(clock.now() >= first);
+}
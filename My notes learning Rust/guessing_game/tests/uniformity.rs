@@ -0,0 +1,86 @@
+use guessing_game::uniformity::{self, Check, check, chi_square, chi_square_p, ks, ks_p};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+fn fair(low: u32, high: u32, samples: usize, seed: u64) -> Check {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let draws: Vec<u32> = (0..samples).map(|_| rng.gen_range(low..=high)).collect();
+    check(low, high, &draws)
+}
+
+#[test]
+fn chi_square_p_values_match_the_tables() {
+    // the 5% and 1% critical values
+    assert!(close(chi_square_p(3.841, 1), 0.05));
+    assert!(close(chi_square_p(6.635, 1), 0.01));
+    assert!(close(chi_square_p(11.070, 5), 0.05));
+    assert!(close(chi_square_p(18.307, 10), 0.05));
+    assert!(close(chi_square_p(123.225, 99), 0.05));
+    assert!(close(chi_square_p(1073.643, 999), 0.05));
+    assert_eq!(chi_square_p(0.0, 4), 1.0);
+    assert_eq!(chi_square(&[10, 10], &[10.0, 10.0]), 0.0);
+    assert_eq!(chi_square(&[15, 5], &[10.0, 10.0]), 5.0);
+}
+
+#[test]
+fn ks_p_values_match_the_tables() {
+    // D√n of 1.358 is the 5% critical value, 1.628 the 1% one
+    let n = 10_000;
+    assert!((ks_p(1.358 / 100.0, n) - 0.05).abs() < 0.005);
+    assert!((ks_p(1.628 / 100.0, n) - 0.01).abs() < 0.002);
+    assert_eq!(ks_p(0.0, n), 1.0);
+
+    // 1, 1, 2, 4 against 1..=4: half are at most 1 where a quarter should be
+    let cdf = |x: u32| x as f64 / 4.0;
+    assert_eq!(ks(&[1, 1, 2, 4], 1, &cdf), 0.25);
+}
+
+#[test]
+fn fair_draws_pass() {
+    for (low, high) in [(1, 2), (1, 6), (1, 100), (1, 1000), (0, u32::MAX)] {
+        let check = fair(low, high, 50_000, 7);
+        assert_eq!((check.low, check.high, check.samples), (low, high, 50_000));
+        assert!(check.passed(0.001), "{low}..={high}: p {} and {}", check.chi_square_p, check.ks_p);
+    }
+}
+
+#[test]
+fn endpoints_are_checked_when_they_should_show() {
+    assert_eq!(fair(1, 100, 50_000, 1).endpoints, Some(true));
+    assert_eq!(fair(1, 1_000_000, 50_000, 1).endpoints, None);
+
+    // the exclusive range 1..100 never draws 100
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let draws: Vec<u32> = (0..50_000).map(|_| rng.gen_range(1..100)).collect();
+    let check = check(1, 100, &draws);
+    assert_eq!(check.endpoints, Some(false));
+    assert!(!check.passed(0.001));
+}
+
+#[test]
+fn a_bias_is_caught() {
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    // the modulo bias of u32 % 100 is too small to see, so make one: every
+    // tenth draw rerolls anything above 90
+    let draws: Vec<u32> = (0..50_000)
+        .map(|i| {
+            let draw = rng.gen_range(1..=100);
+            if i % 10 == 0 && draw > 90 { rng.gen_range(1..=100) } else { draw }
+        })
+        .collect();
+    let check = check(1, 100, &draws);
+    assert!(check.chi_square > 150.0 && check.chi_square_p < 0.001, "{}", check.chi_square);
+    assert!(!check.passed(0.001));
+    assert_eq!(check.freedom, 99);
+
+    // wide ranges are counted in slices
+    let mut rng = ChaCha8Rng::seed_from_u64(4);
+    let draws: Vec<u32> = (0..50_000).map(|_| rng.gen_range(0..=u32::MAX / 2)).collect();
+    let check = uniformity::check(0, u32::MAX, &draws);
+    assert_eq!(check.freedom, 999);
+    assert!(check.ks > 0.4 && check.ks_p < 1e-9);
+}
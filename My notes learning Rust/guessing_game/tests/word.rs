@@ -0,0 +1,37 @@
+use guessing_game::word::{HardModeError, Mark, hard_mode_error, score};
+use Mark::{Absent as A, Correct as C, Present as P};
+
+#[test]
+fn repeated_letters_are_marked_once_per_letter_of_the_secret() {
+    assert_eq!(score("crane", "crane"), [C, C, C, C, C]);
+    assert_eq!(score("moist", "abcde"), [A, A, A, A, A]);
+    // one e in the secret: the green one takes it, the other stays grey
+    assert_eq!(score("geese", "those"), [A, A, A, C, C]);
+    assert_eq!(score("eerie", "crane"), [A, A, P, A, C]);
+    // two e's in the secret, three in the guess
+    assert_eq!(score("eerie", "sheep"), [P, P, A, A, A]);
+    // the greens use up both l's, none is left for the first one
+    assert_eq!(score("lolly", "hello"), [A, P, C, C, A]);
+    assert_eq!(score("hello", "lolly"), [A, A, C, C, P]);
+}
+
+#[test]
+fn hard_mode_keeps_greens_and_reuses_yellows() {
+    let marks = score("crane", "cigar");
+    assert_eq!(marks, [C, P, P, A, A]);
+
+    assert_eq!(hard_mode_error("cigar", "crane", &marks), None);
+    assert_eq!(hard_mode_error("carry", "crane", &marks), None);
+    assert_eq!(hard_mode_error("track", "crane", &marks), Some(HardModeError::MustBe { position: 1, letter: 'c' }));
+    assert_eq!(hard_mode_error("cloud", "crane", &marks), Some(HardModeError::MustContain('r')));
+    assert_eq!(hard_mode_error("coral", "crane", &marks), None);
+
+    // a letter revealed twice has to come back twice
+    let marks = score("eerie", "sheep");
+    assert_eq!(hard_mode_error("spelt", "eerie", &marks), Some(HardModeError::MustContain('e')));
+    assert_eq!(hard_mode_error("every", "eerie", &marks), None);
+    // the green letter is not counted again for a yellow one
+    let marks = score("lolly", "hello");
+    assert_eq!(hard_mode_error("hello", "lolly", &marks), None);
+    assert_eq!(hard_mode_error("bally", "lolly", &marks), Some(HardModeError::MustContain('o')));
+}